@@ -18,9 +18,9 @@ type Application = lexa_kernel::Kernel<AnyApplicationAdapter>;
 // Constant //
 // -------- //
 
-const APPLICATION_NAME: &'static str = "lexa-app";
-const APPLICATION_VERSION: &'static str = env!("CARGO_PKG_VERSION");
-const APPLICATION_ROOT_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+const APPLICATION_NAME: &str = "lexa-app";
+const APPLICATION_VERSION: &str = env!("CARGO_PKG_VERSION");
+const APPLICATION_ROOT_DIR: &str = env!("CARGO_MANIFEST_DIR");
 
 #[tokio::main]
 async fn main()
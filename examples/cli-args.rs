@@ -18,9 +18,9 @@ use lexa_kernel::{
 // Constant //
 // -------- //
 
-const APPLICATION_NAME: &'static str = "lexa-app";
-const APPLICATION_VERSION: &'static str = env!("CARGO_PKG_VERSION");
-const APPLICATION_ROOT_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+const APPLICATION_NAME: &str = "lexa-app";
+const APPLICATION_VERSION: &str = env!("CARGO_PKG_VERSION");
+const APPLICATION_ROOT_DIR: &str = env!("CARGO_MANIFEST_DIR");
 
 // ---- //
 // Type //
@@ -51,7 +51,9 @@ impl ApplicationCLIInterface for ApplicationCLI
 	fn arguments() -> Self
 	{
 		use clap::Parser;
-		Self::parse()
+		// NOTE: les arguments réservés au kernel (ex: `--process-mode`) sont
+		//       retirés des arguments de la CLI.
+		Self::parse_from(lexa_kernel::process::application_args())
 	}
 }
 
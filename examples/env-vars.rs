@@ -24,9 +24,9 @@ type Application =
 // Constant //
 // -------- //
 
-const APPLICATION_NAME: &'static str = "lexa-app";
-const APPLICATION_VERSION: &'static str = env!("CARGO_PKG_VERSION");
-const APPLICATION_ROOT_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+const APPLICATION_NAME: &str = "lexa-app";
+const APPLICATION_VERSION: &str = env!("CARGO_PKG_VERSION");
+const APPLICATION_ROOT_DIR: &str = env!("CARGO_MANIFEST_DIR");
 
 // --------- //
 // Structure //
@@ -43,17 +43,10 @@ pub struct ApplicationEnv
 
 impl ApplicationEnvInterface for ApplicationEnv
 {
-	const FILENAME: &'static str = ".env";
-
-	//fn with_suffix(settings: &lexa_kernel::settings::KernelSettings) -> impl ToString
-	//{
-	//	match settings.process_mode {
-	//		| lexa_kernel::process::ProcessMode::LOCAL => "",
-	//		| lexa_kernel::process::ProcessMode::DEVELOPMENT => "dev",
-	//		| lexa_kernel::process::ProcessMode::PRODUCTION => "prod",
-	//		| lexa_kernel::process::ProcessMode::TEST => "test",
-	//	}
-	//}
+	// NOTE: Par défaut, le suffixe de mode des fichiers `.env.<mode>` et
+	//       `.env.<mode>.local` est le nom court du mode d'exécution (`dev`,
+	//       `prod`, `test`), voir `ApplicationEnvInterface::with_suffix`.
+	const FILENAME: &str = ".env";
 }
 
 impl ApplicationAdapterEnvInterface for AnyApplicationAdapter<ApplicationEnv>
@@ -86,9 +79,18 @@ fn main()
 		//       d'environnement est configuré sur
 		// 		 APPLICATION_ROOT_DIR + "/env"
 		.define_env_directory("examples/env")
-		// NOTE: Récupère les variables d'environnement depuis le fichier
-		//       `examples/env/.env`.
+		// NOTE: Les variables d'environnement du processus préfixées par
+		//       `LEXA_APP_` peuvent surcharger les fichiers d'environnement,
+		//       voire les remplacer.
+		// .define_env_strategy(EnvLoadingStrategy::FileOverlaidByProcess)
+		// NOTE: Récupère les variables d'environnement depuis les fichiers
+		//       `examples/env/.env`, `.env.<mode>`, `.env.local` et
+		//       `.env.<mode>.local`, lorsqu'ils existent.
 		.include_env_vars()
+		// NOTE: Récupère les variables d'environnement d'un espace de noms
+		//       (ex: `DatabaseEnv` depuis les fichiers `.env.db`), voir
+		//       `Kernel::namespaced_env`.
+		// .include_namespaced_env_vars::<DatabaseEnv>()
 		// NOTE: Récupère les variables d'environnement depuis le fichier
 		//       `/path/to/env-file`.
 		// .with_env_vars("/path/to/env-file")
@@ -96,5 +98,11 @@ fn main()
 
 	dbg!(application.env());
 
+	// NOTE: Génère les fichiers `examples/env/.env.example` et
+	//       `.env.<mode>.example` à partir de la structure `ApplicationEnv`
+	//       (voir `ApplicationEnvInterface::describe_env`). La fonction
+	//       `check_env_examples` échoue lorsqu'ils ne sont plus à jour.
+	// application.write_env_examples().unwrap();
+
 	application.run();
 }
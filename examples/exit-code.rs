@@ -0,0 +1,37 @@
+/*
+ * Any copyright is dedicated to the Public Domain.
+ * https://creativecommons.org/publicdomain/zero/1.0/
+ */
+
+mod external_crate;
+
+use std::process::ExitCode;
+
+use external_crate::AnyApplicationAdapter;
+use lexa_kernel::{ApplicationFallibleStartupExtension, ApplicationShutdownExtension};
+
+// ---- //
+// Type //
+// ---- //
+
+type Application = lexa_kernel::Kernel<AnyApplicationAdapter>;
+
+// -------- //
+// Constant //
+// -------- //
+
+const APPLICATION_NAME: &str = "lexa-app";
+const APPLICATION_VERSION: &str = env!("CARGO_PKG_VERSION");
+const APPLICATION_ROOT_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+fn main() -> ExitCode
+{
+	let application = Application::new(
+		APPLICATION_NAME,
+		APPLICATION_VERSION,
+		APPLICATION_ROOT_DIR,
+	)
+	.on_shutdown(|| println!("Arrêt de l'application"));
+
+	application.run_with_exit_code()
+}
@@ -5,10 +5,13 @@
 
 use lexa_kernel::{
 	ApplicationAdapterInterface,
+	ApplicationFallibleStartupExtension,
 	ApplicationStartupExtension,
 	AsyncApplicationStartupExtension,
+	HookError,
 };
 
+#[allow(dead_code)]
 pub struct AnyApplicationAdapter<E = (), C = ()>
 {
 	pub env: Option<E>,
@@ -40,3 +43,14 @@ impl<E, C> AsyncApplicationStartupExtension for AnyApplicationAdapter<E, C>
 		println!("Async AnyApplicationAdapter");
 	}
 }
+
+impl<E, C> ApplicationFallibleStartupExtension for AnyApplicationAdapter<E, C>
+{
+	type Output = Result<(), HookError>;
+
+	fn try_run(self) -> Self::Output
+	{
+		println!("Fallible AnyApplicationAdapter");
+		Err("l'application n'a rien à faire".into())
+	}
+}
@@ -6,15 +6,15 @@
 mod external_crate;
 
 use external_crate::AnyApplicationAdapter;
-use lexa_kernel::{ApplicationStartupExtension, LoaderExtension};
+use lexa_kernel::{ApplicationStartupExtension, ConfigViolations, LoaderExtension, ValidateConfig};
 
 // -------- //
 // Constant //
 // -------- //
 
-const APPLICATION_NAME: &'static str = "lexa-app";
-const APPLICATION_VERSION: &'static str = env!("CARGO_PKG_VERSION");
-const APPLICATION_ROOT_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+const APPLICATION_NAME: &str = "lexa-app";
+const APPLICATION_VERSION: &str = env!("CARGO_PKG_VERSION");
+const APPLICATION_ROOT_DIR: &str = env!("CARGO_MANIFEST_DIR");
 
 // --------- //
 // Structure //
@@ -27,6 +27,14 @@ pub struct MyConfig
 	pub my_field: String,
 }
 
+impl ValidateConfig for MyConfig
+{
+	fn validate(&self, violations: &mut ConfigViolations)
+	{
+		violations.check("my_field", !self.my_field.is_empty(), "ne peut pas être vide");
+	}
+}
+
 // ---- //
 // Main //
 // ---- //
@@ -43,13 +51,13 @@ fn main()
 		// NOTE: Par défaut, le répertoire de configuration est configuré sur
 		//       APPLICATION_ROOT_DIR + "/config"
 		.define_config_directory("examples/config")
-		// NOTE: Par défaut, l'extension d'un fichier de configuration est
-		//       configurée sur l'extension YAML (LoaderExtension::YAML).
-		.define_loader_extension(LoaderExtension::YAML)
+		// NOTE: Par défaut, le format d'un fichier de configuration est
+		//       détecté parmi YAML, JSON puis TOML.
+		.define_loader_extensions([LoaderExtension::YAML, LoaderExtension::JSON])
 	;
 
 	let my_config: MyConfig = application
-		.fetch_config("my-config")
+		.fetch_validated_config("my-config")
 		.expect("Impossible de récupérer la configuration de MyConfig");
 	dbg!(&my_config);
 
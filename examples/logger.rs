@@ -12,9 +12,9 @@ use lexa_kernel::{ApplicationLoggerExtension, ApplicationStartupExtension};
 // Constant //
 // -------- //
 
-const APPLICATION_NAME: &'static str = "lexa-app";
-const APPLICATION_VERSION: &'static str = env!("CARGO_PKG_VERSION");
-const APPLICATION_ROOT_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+const APPLICATION_NAME: &str = "lexa-app";
+const APPLICATION_VERSION: &str = env!("CARGO_PKG_VERSION");
+const APPLICATION_ROOT_DIR: &str = env!("CARGO_MANIFEST_DIR");
 
 // ---- //
 // Main //
@@ -8,17 +8,48 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+pub(super) mod command;
+pub(super) mod config;
 pub(super) mod error;
 pub(super) mod extension;
+pub(super) mod hooks;
 pub(super) mod interface;
+pub(super) mod module;
+pub(super) mod secret;
+pub(super) mod service;
 pub(super) mod settings;
+pub(super) mod shutdown;
 
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
 use std::path;
+use std::time::Duration;
 
+use self::command::{KernelCommand, KernelCommandError};
+use self::config::{
+	config_provenance,
+	fetch_config,
+	fetch_config_or_prompt,
+	is_missing_config,
+	validate_config,
+	validate_config_with,
+	ConfigDump,
+	ConfigExplanation,
+	ConfigProvenance,
+	ConfigSchemas,
+	ConfigSource,
+	LoadedConfigs,
+	ValidateConfig,
+	WatchedConfig,
+};
+use self::hooks::KernelHooks;
+use self::module::KernelModules;
+use self::service::ServiceContainer;
 use self::settings::KernelSettings;
+use self::shutdown::KernelShutdown;
 use crate::logger::signal::LoggerSignal;
 use crate::process::ProcessMode;
-use crate::{ApplicationAdapterInterface, ApplicationAdapterSettingsInterface};
+use crate::{ApplicationAdapterInterface, ApplicationAdapterSettingsInterface, KernelError};
 
 // --------- //
 // Structure //
@@ -36,10 +67,39 @@ pub struct Kernel<ApplicationAdapter, UserEnv = (), UserCLI = ()>
 	pub settings: KernelSettings,
 	/// Les variables d'environnent.
 	env_vars: Option<UserEnv>,
+	/// Les variables d'environnement des espaces de noms, indexées par type.
+	namespaced_env_vars: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
 	/// Arguments de la CLI.
 	cli_args: Option<UserCLI>,
 	/// Logger Signal.
 	logger_signal: LoggerSignal,
+	/// Gestion de l'arrêt de l'application.
+	shutdown: KernelShutdown,
+	/// Fonctions de cycle de vie.
+	hooks: KernelHooks,
+	/// Modules du kernel.
+	modules: KernelModules,
+	/// Conteneur de services.
+	services: ServiceContainer,
+	/// Schémas JSON des configurations et des variables d'environnement.
+	schemas: ConfigSchemas,
+	/// Configurations et variables d'environnement chargées.
+	loaded_configs: LoadedConfigs,
+	/// Commande du kernel à exécuter à la place de l'application, ou erreur
+	/// d'analyse des arguments de la CLI, retournée lors du démarrage.
+	command: Option<Result<KernelCommand, KernelCommandError>>,
+	/// Erreurs survenues lors de l'initialisation du kernel, retournées par
+	/// [Kernel::try_build()].
+	errors: Vec<KernelError>,
+	/// Erreur du dernier chargement des paramètres de l'application adapter,
+	/// remplacée à chaque fois que l'application adapter est recréée.
+	/// Retournée par [Kernel::try_build()].
+	adapter_error: Option<KernelError>,
+	/// Erreur de résolution du mode d'exécution (voir [ProcessMode::resolve()]),
+	/// effacée lorsque le mode est redéfinit (voir
+	/// [Kernel::define_process_mode()]). Retournée par [Kernel::try_build()]
+	/// et lors du démarrage.
+	process_mode_error: Option<KernelError>,
 }
 
 pub type ApplicationName = String;
@@ -59,33 +119,152 @@ where
 		application_root_directory: impl Into<path::PathBuf>,
 	) -> Self
 	{
-		let settings = KernelSettings::new(application_root_directory);
-
 		let application_name = application_name.into();
 		let application_version = application_version.into();
 
 		let logger_signal = LoggerSignal::create(&application_name, &application_version);
 
+		let mut process_mode_error = None;
+		let process_mode = ProcessMode::resolve().unwrap_or_else(|err| {
+			process_mode_error.replace(KernelError::from(err));
+			ProcessMode::from_rustcfg()
+		});
+
+		let mut settings = KernelSettings::new(application_root_directory, process_mode);
+		settings.config.env_prefix = self::config::env_segment(&application_name);
+		settings.env.prefix = format!("{}_", settings.config.env_prefix);
+
+		let shutdown = KernelShutdown::new();
+		let services = ServiceContainer::default();
+
+		let errors = Vec::new();
+
+		let loaded_configs = LoadedConfigs::default();
+
+		let mut adapter_error = None;
+		let application_adapter_settings =
+			Self::fetch_adapter_settings(&settings, &loaded_configs).unwrap_or_else(|err| {
+				logger_signal.send_warning(&err);
+				adapter_error.replace(err);
+				Default::default()
+			});
+		let application_adapter = Self::make_adapter(application_adapter_settings, &shutdown, &services);
+
 		Self {
 			application_name,
 			application_version,
-			application_adapter: Self::make_adapter(&settings),
+			application_adapter,
 			settings,
 			env_vars: Default::default(),
+			namespaced_env_vars: Default::default(),
 			cli_args: Default::default(),
 			logger_signal,
+			shutdown,
+			hooks: Default::default(),
+			modules: Default::default(),
+			services,
+			schemas: Self::default_schemas(),
+			loaded_configs,
+			command: None,
+			errors,
+			adapter_error,
+			process_mode_error,
+		}
+	}
+
+	/// Schémas JSON enregistrés par défaut.
+	#[cfg(not(feature = "schema"))]
+	fn default_schemas() -> ConfigSchemas
+	{
+		ConfigSchemas::default()
+	}
+
+	/// Schémas JSON enregistrés par défaut : les paramètres du logger.
+	#[cfg(feature = "schema")]
+	fn default_schemas() -> ConfigSchemas
+	{
+		use crate::settings::LoggerSettings;
+
+		let mut schemas = ConfigSchemas::default();
+		schemas.insert_config(LoggerSettings::FILENAME, schemars::schema_for!(LoggerSettings).to_value());
+		schemas
+	}
+
+	/// Récupère les paramètres de l'application adapter et enregistre leur
+	/// configuration. Lorsqu'aucune couche de cette configuration n'existe,
+	/// les paramètres par défaut sont utilisés ; toute autre erreur (fichier
+	/// inclus manquant, ...) est retournée.
+	fn fetch_adapter_settings(
+		settings: &KernelSettings,
+		loaded_configs: &LoadedConfigs,
+	) -> Result<ApplicationAdapter::Settings, KernelError>
+	{
+		let filename = <ApplicationAdapter::Settings as ApplicationAdapterSettingsInterface>::FILENAME;
+
+		if filename.is_empty() {
+			return Ok(Default::default());
+		}
+
+		loaded_configs.remove_config(filename);
+
+		match fetch_config::<ApplicationAdapter::Settings>(filename, settings) {
+			| Ok((application_adapter_settings, value)) => {
+				validate_config_with(filename, |violations| application_adapter_settings.validate_settings(violations))?;
+				loaded_configs.insert_config(filename, value);
+				Ok(application_adapter_settings)
+			}
+			| Err(err) if is_missing_config(&err) => Ok(Default::default()),
+			| Err(reason) => Err(KernelError::Adapter { filename, reason }),
 		}
 	}
 
-	fn make_adapter(settings: &KernelSettings) -> ApplicationAdapter
+	fn make_adapter(
+		application_adapter_settings: ApplicationAdapter::Settings,
+		shutdown: &KernelShutdown,
+		services: &ServiceContainer,
+	) -> ApplicationAdapter
 	{
-		let application_adapter_settings_filename =
-			<ApplicationAdapter::Settings as ApplicationAdapterSettingsInterface>::FILENAME;
+		let mut application_adapter = ApplicationAdapter::new(application_adapter_settings);
+		application_adapter.set_shutdown_token(shutdown.token.clone());
+		application_adapter.set_services(services.clone());
+		application_adapter
+	}
 
+	/// Recrée l'application adapter. Lorsque ses paramètres ne peuvent pas
+	/// être chargés, l'erreur est signalée et les paramètres par défaut sont
+	/// utilisés. L'erreur remplace celle d'un chargement précédent.
+	fn rebuild_adapter(&mut self)
+	{
+		self.adapter_error = None;
 		let application_adapter_settings =
-			fetch_config(application_adapter_settings_filename, settings).unwrap_or_default();
+			Self::fetch_adapter_settings(&self.settings, &self.loaded_configs).unwrap_or_else(|err| {
+				self.logger_signal.send_warning(&err);
+				self.adapter_error.replace(err);
+				Default::default()
+			});
+		self.application_adapter = Self::make_adapter(application_adapter_settings, &self.shutdown, &self.services);
+	}
 
-		ApplicationAdapter::new(application_adapter_settings)
+	/// Recrée l'application adapter. Voir [Kernel::rebuild_adapter()].
+	fn try_rebuild_adapter(&mut self) -> Result<(), KernelError>
+	{
+		let application_adapter_settings = Self::fetch_adapter_settings(&self.settings, &self.loaded_configs)?;
+		self.adapter_error = None;
+		self.application_adapter = Self::make_adapter(application_adapter_settings, &self.shutdown, &self.services);
+		Ok(())
+	}
+
+	/// Définit un répertoire de configuration. Retourne une erreur si le
+	/// répertoire n'existe pas ou si les paramètres de l'application adapter
+	/// ne peuvent pas être chargés depuis ce répertoire.
+	///
+	/// NOTE: lorsque le répertoire de configuration est redéfinit,
+	/// l'application adapter est recrée.
+	pub fn try_define_config_directory(mut self, dir: impl Into<path::PathBuf>) -> Result<Self, KernelError>
+	{
+		self.settings.directory.try_set_config_directory(dir)?;
+		self.try_rebuild_adapter()?;
+		Ok(self)
 	}
 }
 
@@ -107,7 +286,86 @@ impl<A, E, C> Kernel<A, E, C>
 		A: ApplicationAdapterInterface,
 	{
 		self.settings.directory.set_config_directory(dir);
-		self.application_adapter = Self::make_adapter(&self.settings);
+		if let Err(err) = self.settings.directory.try_config() {
+			self.logger_signal.send_warning(&err);
+			self.errors.push(err);
+		}
+		self.rebuild_adapter();
+		self
+	}
+
+	/// Force le mode d'exécution de l'application. Ce mode a la priorité sur
+	/// l'argument de la CLI `--process-mode` et sur la variable
+	/// d'environnement `PROCESS_ENV`.
+	///
+	/// NOTE: lorsque le mode d'exécution est redéfinit, l'application adapter
+	/// est recrée.
+	pub fn define_process_mode(mut self, process_mode: ProcessMode) -> Self
+	where
+		A: ApplicationAdapterInterface,
+	{
+		self.settings.process_mode = process_mode;
+		self.process_mode_error = None;
+		self.rebuild_adapter();
+		self
+	}
+
+	/// Définit le préfixe des variables d'environnement qui surchargent les
+	/// clés des configurations. Par défaut, ce préfixe est dérivé du nom de
+	/// l'application (`lexa-app` donne `LEXA_APP`).
+	///
+	/// NOTE: lorsque le préfixe est redéfinit, l'application adapter est
+	/// recrée.
+	pub fn define_config_env_prefix(mut self, prefix: impl Into<String>) -> Self
+	where
+		A: ApplicationAdapterInterface,
+	{
+		self.settings.config.env_prefix = prefix.into();
+		self.rebuild_adapter();
+		self
+	}
+
+	/// Définit l'intervalle de vérification des fichiers des configurations
+	/// surveillées. Voir [Kernel::watch_config()].
+	pub fn define_config_watch_interval(mut self, interval: Duration) -> Self
+	{
+		self.settings.config.watch_interval = interval;
+		self
+	}
+
+	/// Désactive les surcharges par les variables d'environnement pour une
+	/// configuration donnée.
+	///
+	/// NOTE: lorsque la configuration est celle de l'application adapter,
+	/// l'application adapter est recrée.
+	pub fn without_config_env_overrides(mut self, config_name: impl Into<String>) -> Self
+	where
+		A: ApplicationAdapterInterface,
+	{
+		let config_name = config_name.into();
+		let is_adapter_config = config_name == <A::Settings as ApplicationAdapterSettingsInterface>::FILENAME;
+		self.settings.config.disable_env_overrides(config_name);
+		if is_adapter_config {
+			self.rebuild_adapter();
+		}
+		self
+	}
+
+	/// Désactive l'interpolation des références `${...}` pour une
+	/// configuration donnée. Voir [fetch_config()].
+	///
+	/// NOTE: lorsque la configuration est celle de l'application adapter,
+	/// l'application adapter est recrée.
+	pub fn without_config_interpolation(mut self, config_name: impl Into<String>) -> Self
+	where
+		A: ApplicationAdapterInterface,
+	{
+		let config_name = config_name.into();
+		let is_adapter_config = config_name == <A::Settings as ApplicationAdapterSettingsInterface>::FILENAME;
+		self.settings.config.disable_interpolation(config_name);
+		if is_adapter_config {
+			self.rebuild_adapter();
+		}
 		self
 	}
 
@@ -116,25 +374,265 @@ impl<A, E, C> Kernel<A, E, C>
 	pub fn define_loader_extension(mut self, loader_extension: impl Into<lexa_fs::Extension>) -> Self
 	{
 		self.settings.loader_extension = loader_extension.into();
+		self.settings.loader_extensions = vec![self.settings.loader_extension];
 		self
 	}
 
-	/// Voir [fetch_config()]
-	pub fn fetch_config<O>(&self, config_name: impl AsRef<str> + 'static) -> std::io::Result<O>
+	/// Définit les formats des fichiers de configurations de l'application,
+	/// par ordre de priorité. Le premier format est utilisé lors de la
+	/// création d'un fichier de configuration.
+	pub fn define_loader_extensions(
+		mut self,
+		loader_extensions: impl IntoIterator<Item = impl Into<lexa_fs::Extension>>,
+	) -> Self
+	{
+		let loader_extensions: Vec<_> = loader_extensions.into_iter().map(Into::into).collect();
+		if let Some(loader_extension) = loader_extensions.first() {
+			self.settings.loader_extension = *loader_extension;
+			self.settings.loader_extensions = loader_extensions;
+		}
+		self
+	}
+
+	/// Ajoute une source de configuration (voir [ConfigSource]). Les sources
+	/// sont prioritaires sur les fichiers du répertoire de configuration et
+	/// sont interrogées dans leur ordre d'ajout : la dernière source ajoutée
+	/// est la plus prioritaire.
+	///
+	/// NOTE: lorsqu'une source est ajoutée, l'application adapter est
+	/// recrée.
+	pub fn with_config_source(mut self, source: impl ConfigSource + 'static) -> Self
+	where
+		A: ApplicationAdapterInterface,
+	{
+		self.settings.config.add_source(source);
+		self.rebuild_adapter();
+		self
+	}
+
+	/// Définit la commande du kernel à exécuter à la place de l'application
+	/// (voir [KernelCommand]) ; [None] désactive la commande.
+	pub fn define_command(mut self, command: impl Into<Option<KernelCommand>>) -> Self
+	{
+		self.command = command.into().map(Ok);
+		self
+	}
+
+	/// Active les commandes du kernel : la commande est résolue à partir des
+	/// arguments de la CLI réservés au kernel (voir
+	/// [KernelCommand::from_cli_args()]). Lorsque les arguments ne sont pas
+	/// valides, le démarrage de l'application échoue.
+	pub fn with_cli_commands(mut self) -> Self
+	{
+		self.command = KernelCommand::from_cli_args();
+		if let Some(Err(err)) = &self.command {
+			self.logger_signal.send_warning(err);
+		}
+		self
+	}
+
+	/// Voir [config_provenance()]. Les valeurs secrètes sont masquées (voir
+	/// [Kernel::dump_config()]) : les valeurs [Secret](crate::Secret) sont
+	/// connues lorsque la configuration a été chargée par le kernel.
+	pub fn config_provenance(&self, config_name: impl AsRef<str>) -> Result<ConfigProvenance, KernelError>
+	{
+		let mut provenance = config_provenance(config_name.as_ref(), &self.settings).map_err(|reason| {
+			KernelError::Config {
+				name: config_name.as_ref().to_owned(),
+				reason,
+			}
+		})?;
+		let mut secret_paths = self.loaded_configs.secret_paths(config_name.as_ref());
+		secret_paths.extend(
+			self.modules
+				.configs()
+				.filter(|(module_name, _)| *module_name == config_name.as_ref())
+				.flat_map(|(_, value)| value.secret_paths.iter().cloned()),
+		);
+		provenance.redact(
+			&secret_paths,
+			self.schemas.config_schema(config_name.as_ref()),
+			&self.settings.config.secret_patterns,
+		);
+		Ok(provenance)
+	}
+
+	/// Explique la provenance de la valeur effective d'une clé
+	/// `<config_name>.<key>` (ex: `database.host`) : la source qui l'emporte
+	/// et chacune des valeurs qu'elle remplace. Lorsque la clé désigne un
+	/// objet (ex: `database` ou `database.tls`), chacune de ses clés est
+	/// expliquée. Voir [ConfigProvenance].
+	pub fn explain_config(&self, key: impl AsRef<str>) -> Result<Vec<ConfigExplanation>, KernelError>
+	{
+		let (config_name, key) = key.as_ref().split_once('.').unwrap_or((key.as_ref(), ""));
+
+		let explanations = self.config_provenance(config_name)?.explain(key);
+		if explanations.is_empty() {
+			return Err(KernelError::Config {
+				name: config_name.to_owned(),
+				reason: std::io::Error::new(
+					std::io::ErrorKind::NotFound,
+					format!("la clé « {key} » n'existe pas."),
+				),
+			});
+		}
+		Ok(explanations)
+	}
+
+	/// Configuration effective de l'application : les valeurs dont ont été
+	/// dé-sérialisées les configurations chargées (dont les paramètres de
+	/// l'application adapter, du logger et des modules enregistrés) et les
+	/// variables d'environnement. Les configurations surveillées (voir
+	/// [Kernel::watch_config()]) sont à jour. Les valeurs secrètes sont
+	/// masquées : les valeurs des champs [Secret](crate::Secret), voir
+	/// également [KernelSettingsConfig::secret_patterns](crate::settings::KernelSettingsConfig::secret_patterns)
+	/// et l'attribut `writeOnly` des schémas JSON.
+	pub fn dump_config(&self) -> ConfigDump
+	{
+		self.loaded_configs
+			.dump(self.modules.configs(), &self.settings, &self.schemas)
+	}
+
+	/// Ajoute un motif de clés dont les valeurs sont secrètes (ex:
+	/// `*password*`). Voir [Kernel::dump_config()].
+	pub fn with_secret_pattern(mut self, pattern: impl Into<String>) -> Self
+	{
+		self.settings.config.secret_patterns.push(pattern.into());
+		self
+	}
+
+	/// Voir [fetch_config()].
+	pub fn fetch_config<O>(&self, config_name: impl AsRef<str>) -> std::io::Result<O>
+	where
+		O: serde::de::DeserializeOwned,
+	{
+		let (config, value) = fetch_config(config_name.as_ref(), &self.settings)?;
+		self.loaded_configs.insert_config(config_name.as_ref(), value);
+		Ok(config)
+	}
+
+	/// Voir [Kernel::fetch_config()]. L'erreur est une [KernelError::Config].
+	pub fn try_fetch_config<O>(&self, config_name: impl AsRef<str>) -> Result<O, KernelError>
 	where
 		O: serde::de::DeserializeOwned,
 	{
-		fetch_config(config_name, &self.settings)
+		self.fetch_config(config_name.as_ref()).map_err(|reason| {
+			KernelError::Config {
+				name: config_name.as_ref().to_owned(),
+				reason,
+			}
+		})
+	}
+
+	/// Voir [Kernel::try_fetch_config()]. La configuration est validée (voir
+	/// [ValidateConfig]).
+	pub fn fetch_validated_config<O>(&self, config_name: impl AsRef<str>) -> Result<O, KernelError>
+	where
+		O: serde::de::DeserializeOwned,
+		O: ValidateConfig,
+	{
+		let config = self.try_fetch_config(config_name.as_ref())?;
+		validate_config(config_name.as_ref(), &config)?;
+		Ok(config)
+	}
+
+	/// Voir [fetch_config_or_prompt()].
+	pub fn fetch_config_or_prompt<O>(&self, config_name: impl AsRef<str>) -> std::io::Result<O>
+	where
+		O: std::fmt::Debug,
+		O: lexa_prompt::Prompt,
+		O: serde::ser::Serialize + serde::de::DeserializeOwned,
+	{
+		let (config, value) = fetch_config_or_prompt(config_name.as_ref(), &self.settings)?;
+		self.loaded_configs.insert_config(config_name.as_ref(), value);
+		Ok(config)
 	}
 
-	/// Voir [fetch_config_or_prompt()]
-	pub fn fetch_config_or_prompt<O>(&self, config_name: impl AsRef<str> + 'static) -> std::io::Result<O>
+	/// Voir [Kernel::fetch_config_or_prompt()]. L'erreur est une
+	/// [KernelError::Config].
+	pub fn try_fetch_config_or_prompt<O>(&self, config_name: impl AsRef<str>) -> Result<O, KernelError>
 	where
 		O: std::fmt::Debug,
 		O: lexa_prompt::Prompt,
 		O: serde::ser::Serialize + serde::de::DeserializeOwned,
 	{
-		fetch_config_or_prompt(config_name, &self.settings)
+		self.fetch_config_or_prompt(config_name.as_ref()).map_err(|reason| {
+			KernelError::Config {
+				name: config_name.as_ref().to_owned(),
+				reason,
+			}
+		})
+	}
+
+	/// Voir [Kernel::try_fetch_config_or_prompt()]. La configuration est
+	/// validée (voir [ValidateConfig]).
+	pub fn fetch_validated_config_or_prompt<O>(&self, config_name: impl AsRef<str>) -> Result<O, KernelError>
+	where
+		O: std::fmt::Debug,
+		O: lexa_prompt::Prompt,
+		O: serde::ser::Serialize + serde::de::DeserializeOwned,
+		O: ValidateConfig,
+	{
+		let config = self.try_fetch_config_or_prompt(config_name.as_ref())?;
+		validate_config(config_name.as_ref(), &config)?;
+		Ok(config)
+	}
+
+	/// Surveille une configuration : elle est rechargée lorsque l'un de ses
+	/// fichiers est modifié ou lorsque le processus reçoit le signal SIGHUP.
+	/// Les erreurs de rechargement sont signalées via le [LoggerSignal].
+	/// Voir [WatchedConfig] et [WatchedConfig::validated()].
+	pub fn watch_config<O>(&self, config_name: impl AsRef<str>) -> Result<WatchedConfig<O>, KernelError>
+	where
+		O: serde::de::DeserializeOwned,
+		O: Send + Sync + 'static,
+	{
+		let watched_config = WatchedConfig::new(config_name.as_ref(), self.settings.clone())?;
+		self.loaded_configs
+			.insert_shared_config(config_name.as_ref(), watched_config.shared_value());
+		watched_config
+			.watch(
+				self.logger_signal.writer(),
+				self.shutdown.token.clone(),
+				self.settings.config.watch_interval,
+			)
+			.map_err(|reason| {
+				KernelError::Config {
+					name: config_name.as_ref().to_owned(),
+					reason,
+				}
+			})?;
+		Ok(watched_config)
+	}
+
+	/// Termine l'initialisation du kernel. Retourne les erreurs survenues
+	/// lors de l'initialisation (répertoires, configurations, application
+	/// adapter, variables d'environnement, logger) : l'erreur elle-même
+	/// lorsqu'il n'y en a qu'une, [KernelError::Multiple] sinon. Seule
+	/// l'erreur du dernier chargement des paramètres de l'application adapter
+	/// est retournée. Un mode d'exécution invalide n'est une erreur que
+	/// lorsqu'il n'a pas été redéfinit (voir [Kernel::define_process_mode()]).
+	pub fn try_build(mut self) -> Result<Self, KernelError>
+	{
+		let mut errors: Vec<_> = self
+			.process_mode_error
+			.take()
+			.into_iter()
+			.chain(self.adapter_error.take())
+			.chain(self.errors.drain(..))
+			.collect();
+
+		match errors.len() {
+			| 0 => Ok(self),
+			| 1 => Err(errors.remove(0)),
+			| _ => Err(KernelError::Multiple(errors)),
+		}
+	}
+
+	/// Conteneur de services.
+	pub fn services(&self) -> &ServiceContainer
+	{
+		&self.services
 	}
 
 	pub fn signal(&self) -> &LoggerSignal
@@ -143,89 +641,91 @@ impl<A, E, C> Kernel<A, E, C>
 	}
 }
 
-// -------- //
-// Fonction //
-// -------- //
-
-/// Désérialise un fichier de configuration situé dans son répertoire de
-/// configuration en une structure de données en fonction du mode d'exécution.
-///
-/// À savoir que **par défaut** :
-///
-///     1) Le répertoire de configuration se trouve à la racine du
-///     projet `config/`
-///
-///     2) Le fichier de configuration du logger se trouve dans :
-///
-///         2.1) En local : `config/<config_name>.<EXT>`.
-///         2.2) En dev   : `config/dev/<config_name>.<EXT>`.
-///         2.3) En prod  : `config/prod/<config_name>.<EXT>`.
-///         2.4) En test  : `config/test/<config_name>.<EXT>`.
-///
-///     3) L'extension <EXT> utilisée pour ce fichier de configuration est le
-///        `yml`. Cette extension peut être modifiée dans les paramètres de la
-///        configuration.
-pub fn fetch_config<O>(config_name: impl AsRef<str> + 'static, settings: &KernelSettings) -> std::io::Result<O>
-where
-	O: serde::de::DeserializeOwned,
+#[cfg(test)]
+mod tests
 {
-	let filepath = match settings.process_mode {
-		| ProcessMode::LOCAL => String::from(config_name.as_ref()),
-		| ProcessMode::DEVELOPMENT => format!("dev/{}", config_name.as_ref()),
-		| ProcessMode::PRODUCTION => format!("prod/{}", config_name.as_ref()),
-		| ProcessMode::TEST => format!("test/{}", config_name.as_ref()),
-	};
-
-	if let Some(config_directory) = settings.directory.config() {
-		lexa_fs::load(config_directory, filepath, settings.loader_extension)
-	} else {
-		Err(std::io::Error::new(
-			std::io::ErrorKind::NotFound,
-			"Le répertoire de configuration n'existe pas.",
-		))
+	use std::fs;
+
+	use super::*;
+
+	#[derive(Default)]
+	#[derive(serde::Deserialize)]
+	struct AdapterSettings
+	{
+		#[allow(dead_code)]
+		port: u16,
 	}
-}
 
-/// Désérialise un fichier de configuration situé dans son répertoire de
-/// configuration en une structure de données en fonction du mode d'exécution.
-///
-/// À savoir que **par défaut** :
-///
-///     1) Le répertoire de configuration se trouve à la racine du
-///     projet `config/`
-///
-///     2) Le fichier de configuration du logger se trouve dans :
-///
-///         2.1) En local : `config/<config_name>.<EXT>`.
-///         2.2) En dev   : `config/dev/<config_name>.<EXT>`.
-///         2.3) En prod  : `config/prod/<config_name>.<EXT>`.
-///         2.4) En test  : `config/test/<config_name>.<EXT>`.
-///
-///     3) L'extension <EXT> utilisée pour ce fichier de configuration est le
-///        `yml`. Cette extension peut être modifiée dans les paramètres de la
-///        configuration.
-pub fn fetch_config_or_prompt<O>(
-	config_name: impl AsRef<str> + 'static,
-	settings: &KernelSettings,
-) -> std::io::Result<O>
-where
-	O: std::fmt::Debug,
-	O: lexa_prompt::Prompt,
-	O: serde::ser::Serialize + serde::de::DeserializeOwned,
-{
-	let filepath = match settings.process_mode {
-		| ProcessMode::LOCAL => String::from(config_name.as_ref()),
-		| ProcessMode::DEVELOPMENT => format!("dev/{}", config_name.as_ref()),
-		| ProcessMode::PRODUCTION => format!("prod/{}", config_name.as_ref()),
-		| ProcessMode::TEST => format!("test/{}", config_name.as_ref()),
-	};
-
-	if let Some(config_directory) = settings.directory.config() {
-		lexa_fs::load_or_prompt(config_directory, filepath, settings.loader_extension)
-	} else {
-		Err(std::io::Error::new(
-			std::io::ErrorKind::NotFound,
-			"Le répertoire de configuration n'existe pas.",
-		))
+	impl ApplicationAdapterSettingsInterface for AdapterSettings
+	{
+		const FILENAME: &'static str = "adapter";
+	}
+
+	struct Adapter;
+
+	impl ApplicationAdapterInterface for Adapter
+	{
+		type Settings = AdapterSettings;
+
+		fn new(_: Self::Settings) -> Self
+		{
+			Self
+		}
+	}
+
+	fn config_directory(adapter: &str) -> tempfile::TempDir
+	{
+		let dir = tempfile::tempdir().unwrap();
+		fs::write(dir.path().join("adapter.yml"), adapter).unwrap();
+		dir
+	}
+
+	#[test]
+	fn test_try_build_replaces_adapter_error_on_rebuild()
+	{
+		let invalid = config_directory("port: invalid\n");
+		let valid = config_directory("port: 80\n");
+
+		let kernel = Kernel::<Adapter>::new("test", "0.0.0", invalid.path()).define_config_directory(invalid.path());
+		assert!(matches!(kernel.try_build(), Err(KernelError::Adapter { .. })));
+
+		let kernel = Kernel::<Adapter>::new("test", "0.0.0", invalid.path())
+			.define_config_directory(invalid.path())
+			.define_config_directory(valid.path());
+		assert!(kernel.try_build().is_ok());
+	}
+
+	#[test]
+	fn test_try_build_returns_invalid_process_mode_unless_redefined()
+	{
+		let valid = config_directory("port: 80\n");
+		let invalid_mode = || KernelError::from("invalid".parse::<ProcessMode>().unwrap_err());
+
+		let mut kernel = Kernel::<Adapter>::new("test", "0.0.0", valid.path()).define_config_directory(valid.path());
+		kernel.process_mode_error.replace(invalid_mode());
+		assert!(matches!(kernel.try_build(), Err(KernelError::ProcessMode(_))));
+
+		let mut kernel = Kernel::<Adapter>::new("test", "0.0.0", valid.path()).define_config_directory(valid.path());
+		kernel.process_mode_error.replace(invalid_mode());
+		let kernel = kernel.define_process_mode(ProcessMode::TEST);
+		assert!(kernel.try_build().is_ok());
+	}
+
+	#[test]
+	fn test_try_build_returns_every_error()
+	{
+		let invalid = config_directory("port: invalid\n");
+		let missing = invalid.path().join("missing");
+
+		let kernel = Kernel::<Adapter>::new("test", "0.0.0", invalid.path())
+			.define_config_directory(&missing)
+			.define_config_directory(invalid.path());
+		let Err(KernelError::Multiple(errors)) = kernel.try_build() else {
+			panic!("plusieurs erreurs sont attendues");
+		};
+		assert!(matches!(
+			errors.as_slice(),
+			[KernelError::Adapter { .. }, KernelError::DirectoryNotFound { .. }]
+		));
 	}
 }
@@ -0,0 +1,281 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::ffi::OsString;
+
+use crate::process::PROCESS_MODE_CLI_FLAG;
+use crate::ConfigDumpFormat;
+
+// -------- //
+// Constant //
+// -------- //
+
+/// Nom de l'argument de la CLI, réservé au kernel, qui regroupe les
+/// commandes de configuration du kernel.
+pub const CONFIG_CLI_FLAG: &str = "--kernel-config";
+
+/// Nom de l'argument de la CLI, réservé au kernel, qui démarre le kernel
+/// sans exécuter l'application.
+pub const DRY_RUN_CLI_FLAG: &str = "--kernel-dry-run";
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Commandes intégrées au kernel, exécutées à la place de l'application.
+///
+/// Les commandes sont désactivées par défaut : elles sont résolues à partir
+/// des arguments de la CLI (voir [KernelCommand::from_cli_args()]) lorsque
+/// `Kernel#with_cli_commands` est appelée. La commande PEUT également être
+/// définie via `Kernel#define_command`, par exemple lorsque l'application
+/// analyse elle-même ses arguments.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub enum KernelCommand
+{
+	/// `--kernel-config explain <KEY>` : explique la provenance de la valeur
+	/// effective d'une clé (ex: `database.host`).
+	ConfigExplain(String),
+	/// `--kernel-config dump [yaml|json] [--kernel-dry-run]` : affiche la
+	/// configuration effective, dont les valeurs secrètes sont masquées.
+	/// Avec `--kernel-dry-run`, le kernel est démarré au préalable. Une
+	/// valeur qui n'est pas un format reste un argument de l'application.
+	ConfigDump
+	{
+		format: ConfigDumpFormat,
+		dry_run: bool,
+	},
+	/// `--kernel-dry-run` : démarre le kernel (modules, fonctions de cycle de
+	/// vie), puis l'arrête sans exécuter l'application.
+	DryRun,
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+#[derive(thiserror::Error)]
+pub enum KernelCommandError
+{
+	/// La sous-commande ne correspond à aucune commande du kernel.
+	#[error(
+		"la commande « {} {0} » n'existe pas, les commandes acceptées sont: explain, dump.",
+		CONFIG_CLI_FLAG
+	)]
+	Unknown(String),
+	/// La commande a été donnée sans valeur.
+	#[error("la commande « {0} » attend une valeur.")]
+	MissingCliValue(&'static str),
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl KernelCommand
+{
+	/// Construit la commande à partir des arguments de la CLI, si elle est
+	/// présente. Les arguments qui ne sont pas valides en UTF-8 sont
+	/// convertis avec perte.
+	pub fn from_cli_args() -> Option<Result<Self, KernelCommandError>>
+	{
+		Self::from_args(std::env::args_os().skip(1).map(|arg| arg.to_string_lossy().into_owned()))
+	}
+
+	/// Construit la commande à partir d'une liste d'arguments. Seuls les
+	/// arguments réservés au kernel sont analysés : [CONFIG_CLI_FLAG] suivi
+	/// de sa sous-commande et de ses valeurs, et [DRY_RUN_CLI_FLAG]. Les
+	/// autres arguments, ainsi que ceux qui suivent `--`, sont ignorés.
+	pub fn from_args(args: impl IntoIterator<Item = impl AsRef<str>>) -> Option<Result<Self, KernelCommandError>>
+	{
+		let args: Vec<_> = args.into_iter().collect();
+		let args: Vec<&str> = args.iter().map(AsRef::as_ref).collect();
+		Self::parse(&args).0
+	}
+
+	/// Retire les arguments réservés au kernel (voir
+	/// [KernelCommand::from_args()]) d'une liste d'arguments, afin que ces
+	/// arguments puissent être analysés par la CLI de l'application. Les
+	/// arguments qui suivent `--` sont conservés tels quels.
+	pub fn strip_args(args: impl IntoIterator<Item = impl Into<OsString>>) -> Vec<OsString>
+	{
+		let args: Vec<OsString> = args.into_iter().map(Into::into).collect();
+		let lossy_args: Vec<_> = args.iter().map(|arg| arg.to_string_lossy()).collect();
+		let lossy_args: Vec<&str> = lossy_args.iter().map(AsRef::as_ref).collect();
+
+		let (_, reserved) = Self::parse(&lossy_args);
+
+		args.into_iter()
+			.enumerate()
+			.filter_map(|(index, arg)| (!reserved.contains(&index)).then_some(arg))
+			.collect()
+	}
+
+	/// Analyse les arguments réservés au kernel. Retourne la commande, si
+	/// elle est présente, ainsi que les positions des arguments réservés.
+	fn parse(args: &[&str]) -> (Option<Result<Self, KernelCommandError>>, Vec<usize>)
+	{
+		let mut reserved = Vec::new();
+		let mut config_command = None;
+		let mut dry_run = false;
+
+		let mut index = 0;
+		while let Some(&arg) = args.get(index) {
+			match arg {
+				| "--" => break,
+				| PROCESS_MODE_CLI_FLAG => index += 1,
+				| DRY_RUN_CLI_FLAG => {
+					reserved.push(index);
+					dry_run = true;
+				}
+				| CONFIG_CLI_FLAG => {
+					reserved.push(index);
+					let (command, consumed) = Self::parse_config_command(&args[index + 1..]);
+					reserved.extend(index + 1..=index + consumed);
+					index += consumed;
+					config_command.get_or_insert(command);
+				}
+				| _ => {}
+			}
+			index += 1;
+		}
+
+		let command = match config_command {
+			| Some(Ok(Self::ConfigDump { format, .. })) => Some(Ok(Self::ConfigDump { format, dry_run })),
+			| Some(command) => Some(command),
+			| None => dry_run.then_some(Ok(Self::DryRun)),
+		};
+
+		(command, reserved)
+	}
+
+	/// Analyse la sous-commande de [CONFIG_CLI_FLAG] et ses valeurs. Retourne
+	/// la commande ainsi que le nombre d'arguments utilisés. Une valeur NE
+	/// PEUT PAS commencer par `-`. Le format de `dump` n'est utilisé que
+	/// lorsqu'il est connu.
+	fn parse_config_command(args: &[&str]) -> (Result<Self, KernelCommandError>, usize)
+	{
+		let value = |index: usize| args.get(index).copied().filter(|arg| !arg.starts_with('-'));
+
+		match value(0) {
+			| Some("explain") => {
+				match value(1) {
+					| Some(key) => (Ok(Self::ConfigExplain(key.to_owned())), 2),
+					| None => {
+						(
+							Err(KernelCommandError::MissingCliValue("--kernel-config explain")),
+							1,
+						)
+					}
+				}
+			}
+			| Some("dump") => {
+				let (format, consumed) = match value(1) {
+					| Some("yaml" | "yml") => (ConfigDumpFormat::YAML, 2),
+					| Some("json") => (ConfigDumpFormat::JSON, 2),
+					| _ => (ConfigDumpFormat::YAML, 1),
+				};
+				(Ok(Self::ConfigDump { format, dry_run: false }), consumed)
+			}
+			| Some(command) => (Err(KernelCommandError::Unknown(command.to_owned())), 1),
+			| None => (Err(KernelCommandError::MissingCliValue(CONFIG_CLI_FLAG)), 0),
+		}
+	}
+
+	/// Est-ce que le kernel doit être démarré avant l'exécution de la
+	/// commande?
+	pub fn boots_kernel(&self) -> bool
+	{
+		matches!(self, Self::DryRun | Self::ConfigDump { dry_run: true, .. })
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	type Parsed = Option<Result<KernelCommand, KernelCommandError>>;
+
+	#[test]
+	fn test_from_args()
+	{
+		let cases: &[(&[&str], Parsed)] = &[
+			(&[], None),
+			(&["config", "dump"], None),
+			(&["--output", "config", "serve"], None),
+			(&["--process-mode", "--kernel-config"], None),
+			(&["--", "--kernel-config", "dump"], None),
+			(&["serve", "--dry-run"], None),
+			(&["--kernel-dry-run"], Some(Ok(KernelCommand::DryRun))),
+			(
+				&["--kernel-config", "explain", "database.host"],
+				Some(Ok(KernelCommand::ConfigExplain(String::from("database.host")))),
+			),
+			(
+				&["serve", "--kernel-config", "dump", "--verbose"],
+				Some(Ok(KernelCommand::ConfigDump {
+					format: ConfigDumpFormat::YAML,
+					dry_run: false,
+				})),
+			),
+			(
+				&["--kernel-config", "dump", "json", "--kernel-dry-run"],
+				Some(Ok(KernelCommand::ConfigDump {
+					format: ConfigDumpFormat::JSON,
+					dry_run: true,
+				})),
+			),
+			(
+				&["--kernel-config", "explain", "--verbose"],
+				Some(Err(KernelCommandError::MissingCliValue("--kernel-config explain"))),
+			),
+			(
+				&["--kernel-config", "dump", "serve"],
+				Some(Ok(KernelCommand::ConfigDump {
+					format: ConfigDumpFormat::YAML,
+					dry_run: false,
+				})),
+			),
+			(
+				&["--kernel-config", "check"],
+				Some(Err(KernelCommandError::Unknown(String::from("check")))),
+			),
+			(
+				&["--kernel-config"],
+				Some(Err(KernelCommandError::MissingCliValue(CONFIG_CLI_FLAG))),
+			),
+		];
+
+		for (args, expected) in cases {
+			assert_eq!(&KernelCommand::from_args(args.iter()), expected, "{args:?}");
+		}
+	}
+
+	#[test]
+	fn test_strip_args()
+	{
+		let cases: &[(&[&str], &[&str])] = &[
+			(&["app", "config", "dump"], &["app", "config", "dump"]),
+			(&["app", "serve", "--dry-run"], &["app", "serve", "--dry-run"]),
+			(&["app", "--kernel-config", "explain", "database.host", "-v"], &["app", "-v"]),
+			(&["app", "--kernel-config", "dump", "--kernel-dry-run", "serve"], &["app", "serve"]),
+			(&["app", "--kernel-config", "dump", "json", "serve"], &["app", "serve"]),
+			(&["app", "--kernel-config", "dump", "serve"], &["app", "serve"]),
+			(&["app", "--process-mode", "--kernel-dry-run"], &["app", "--process-mode", "--kernel-dry-run"]),
+			(&["app", "--", "--kernel-dry-run"], &["app", "--", "--kernel-dry-run"]),
+		];
+
+		for (args, expected) in cases {
+			let expected: Vec<OsString> = expected.iter().map(OsString::from).collect();
+			assert_eq!(KernelCommand::strip_args(args.iter().copied()), expected, "{args:?}");
+		}
+	}
+}
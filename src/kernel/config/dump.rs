@@ -0,0 +1,385 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use lexa_wildcard_matching::WildcardMatching;
+
+use super::ConfigSchemas;
+use crate::kernel::settings::KernelSettings;
+
+// -------- //
+// Constant //
+// -------- //
+
+/// Valeur de remplacement des valeurs secrètes.
+pub const REDACTED_VALUE: &str = "********";
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Configuration effective de l'application, dont les valeurs secrètes sont
+/// masquées (voir [Kernel::dump_config()](crate::Kernel::dump_config)).
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Default)]
+#[derive(PartialEq)]
+#[derive(serde::Serialize)]
+pub struct ConfigDump
+{
+	/// Configurations chargées, par nom.
+	pub config: BTreeMap<String, serde_json::Value>,
+	/// Variables d'environnement chargées, fichiers d'environnement,
+	/// variables du processus et fichiers secrets confondus, par nom du
+	/// fichier d'environnement de leur structure (ex: `.env`).
+	#[serde(skip_serializing_if = "BTreeMap::is_empty")]
+	pub env: BTreeMap<String, serde_json::Value>,
+}
+
+/// Valeur dont a été dé-sérialisée une configuration (ou une structure de
+/// variables d'environnement).
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Default)]
+#[derive(PartialEq)]
+pub(crate) struct ConfigValue
+{
+	pub(crate) value: serde_json::Value,
+	/// Chemins des valeurs [Secret](crate::Secret) dé-sérialisées (ex:
+	/// `["database", "password"]`, `["tokens", "0"]`).
+	pub(crate) secret_paths: Vec<Vec<String>>,
+}
+
+/// Les configurations et les variables d'environnement chargées par le
+/// kernel, telles qu'elles ont été dé-sérialisées.
+#[derive(Default)]
+pub(crate) struct LoadedConfigs
+{
+	/// Valeurs des configurations, par nom. La valeur d'une configuration
+	/// surveillée est partagée avec sa surveillance (voir
+	/// [WatchedConfig](super::WatchedConfig)).
+	configs: Mutex<BTreeMap<String, Arc<Mutex<ConfigValue>>>>,
+	/// Variables d'environnement, par nom du fichier d'environnement de leur
+	/// structure.
+	env: Mutex<BTreeMap<String, ConfigValue>>,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Format de sérialisation de la configuration effective.
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum ConfigDumpFormat
+{
+	#[default]
+	YAML,
+	JSON,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ConfigDump
+{
+	/// Sérialise la configuration effective.
+	pub fn render(&self, format: ConfigDumpFormat) -> String
+	{
+		match format {
+			| ConfigDumpFormat::YAML => serde_yaml::to_string(self).unwrap_or_default(),
+			| ConfigDumpFormat::JSON => format!("{}\n", serde_json::to_string_pretty(self).unwrap_or_default()),
+		}
+	}
+}
+
+impl LoadedConfigs
+{
+	/// Enregistre la valeur d'une configuration chargée.
+	pub(crate) fn insert_config(&self, config_name: impl Into<String>, value: ConfigValue)
+	{
+		self.insert_shared_config(config_name, Arc::new(Mutex::new(value)));
+	}
+
+	/// Enregistre la valeur d'une configuration, partagée avec sa
+	/// surveillance (voir [WatchedConfig](super::WatchedConfig)).
+	pub(crate) fn insert_shared_config(&self, config_name: impl Into<String>, value: Arc<Mutex<ConfigValue>>)
+	{
+		self.configs.lock().unwrap().insert(config_name.into(), value);
+	}
+
+	/// Chemins des valeurs secrètes d'une configuration chargée.
+	pub(crate) fn secret_paths(&self, config_name: &str) -> Vec<Vec<String>>
+	{
+		self.configs
+			.lock()
+			.unwrap()
+			.get(config_name)
+			.map(|value| value.lock().unwrap_or_else(|err| err.into_inner()).secret_paths.clone())
+			.unwrap_or_default()
+	}
+
+	/// Retire une configuration, lorsque sa valeur par défaut la remplace.
+	pub(crate) fn remove_config(&self, config_name: &str)
+	{
+		self.configs.lock().unwrap().remove(config_name);
+	}
+
+	/// Enregistre les variables d'environnement d'une structure, par nom de
+	/// son fichier d'environnement. Voir [record_env_vars()](super::record_env_vars).
+	pub(crate) fn insert_env(&self, env_filename: impl Into<String>, env_vars: ConfigValue)
+	{
+		self.env.lock().unwrap().insert(env_filename.into(), env_vars);
+	}
+
+	/// Configuration effective, dont les valeurs secrètes sont masquées (voir
+	/// [redact()]) : les configurations enregistrées et les configurations
+	/// données (ex: celles des modules).
+	pub(crate) fn dump<'a, 'v>(
+		&self,
+		configs: impl IntoIterator<Item = (&'a str, &'v ConfigValue)>,
+		settings: &KernelSettings,
+		schemas: &ConfigSchemas,
+	) -> ConfigDump
+	{
+		let mut values: BTreeMap<_, _> = self
+			.configs
+			.lock()
+			.unwrap()
+			.iter()
+			.map(|(config_name, value)| {
+				let value = value.lock().unwrap_or_else(|err| err.into_inner()).clone();
+				(config_name.clone(), value)
+			})
+			.collect();
+		values.extend(configs.into_iter().map(|(config_name, value)| (config_name.to_owned(), value.clone())));
+
+		let mut dump = ConfigDump::default();
+
+		for (config_name, ConfigValue { mut value, secret_paths }) in values {
+			redact(
+				&mut value,
+				&secret_paths,
+				schemas.config_schema(&config_name),
+				&settings.config.secret_patterns,
+			);
+			dump.config.insert(config_name, value);
+		}
+
+		for (env_filename, ConfigValue { value, secret_paths }) in self.env.lock().unwrap().iter() {
+			let mut value = value.clone();
+			redact(
+				&mut value,
+				secret_paths,
+				schemas.env_schema(env_filename),
+				&settings.config.secret_patterns,
+			);
+			dump.env.insert(env_filename.clone(), value);
+		}
+
+		dump
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Masque les valeurs secrètes d'une configuration :
+///
+///   - les valeurs des chemins donnés, ceux des valeurs
+///     [Secret](crate::Secret) dé-sérialisées (voir [ConfigValue]), quel que
+///     soit le nom de la clé ;
+///   - les clés dont le nom correspond à l'un des motifs donnés (ex:
+///     `*password*`), sans tenir compte de la casse ;
+///   - les clés marquées `"writeOnly": true` dans le schéma JSON de la
+///     configuration. Avec la fonctionnalité `schema`, un champ PEUT être
+///     marqué via l'attribut `#[schemars(extend("writeOnly" = true))]`.
+pub(crate) fn redact(
+	value: &mut serde_json::Value,
+	secret_paths: &[Vec<String>],
+	schema: Option<&serde_json::Value>,
+	patterns: &[String],
+)
+{
+	if let Some(schema) = schema {
+		redact_write_only(schema, schema, value);
+	}
+	redact_patterns(value, patterns);
+	redact_secret_paths(value, secret_paths);
+}
+
+/// Masque la valeur d'une clé `path` d'une configuration, selon les chemins
+/// des valeurs secrètes, le schéma de la configuration et les motifs des
+/// clés secrètes (voir [redact()]).
+pub(crate) fn redact_at(
+	path: &[String],
+	value: &mut serde_json::Value,
+	secret_paths: &[Vec<String>],
+	schema: Option<&serde_json::Value>,
+	patterns: &[String],
+)
+{
+	let mut wrapped = path.iter().rev().fold(std::mem::take(value), |value, key| {
+		serde_json::Value::Object(serde_json::Map::from_iter([(key.clone(), value)]))
+	});
+	redact(&mut wrapped, secret_paths, schema, patterns);
+	*value = path
+		.iter()
+		.try_fold(&mut wrapped, |value, key| value.get_mut(key))
+		.map(std::mem::take)
+		.unwrap_or_else(|| serde_json::Value::from(REDACTED_VALUE));
+}
+
+fn redact_patterns(value: &mut serde_json::Value, patterns: &[String])
+{
+	match value {
+		| serde_json::Value::Object(map) => {
+			for (key, value) in map.iter_mut() {
+				if patterns.iter().any(|pattern| key.iswm(pattern)) {
+					*value = serde_json::Value::from(REDACTED_VALUE);
+				} else {
+					redact_patterns(value, patterns);
+				}
+			}
+		}
+		| serde_json::Value::Array(items) => {
+			for item in items {
+				redact_patterns(item, patterns);
+			}
+		}
+		| _ => {}
+	}
+}
+
+fn redact_secret_paths(value: &mut serde_json::Value, secret_paths: &[Vec<String>])
+{
+	for path in secret_paths {
+		let secret_value = path.iter().try_fold(&mut *value, |value, key| {
+			match value {
+				| serde_json::Value::Object(map) => map.get_mut(key),
+				| serde_json::Value::Array(items) => key.parse().ok().and_then(|index: usize| items.get_mut(index)),
+				| _ => None,
+			}
+		});
+		if let Some(secret_value) = secret_value {
+			*secret_value = serde_json::Value::from(REDACTED_VALUE);
+		}
+	}
+}
+
+fn redact_write_only(root: &serde_json::Value, schema: &serde_json::Value, value: &mut serde_json::Value)
+{
+	let Some(schema) = schema.as_object() else {
+		return;
+	};
+
+	if schema.get("writeOnly").and_then(serde_json::Value::as_bool) == Some(true) {
+		*value = serde_json::Value::from(REDACTED_VALUE);
+		return;
+	}
+
+	if let Some(reference) = schema.get("$ref").and_then(serde_json::Value::as_str) {
+		if let Some(referenced_schema) = reference.strip_prefix('#').and_then(|pointer| root.pointer(pointer)) {
+			redact_write_only(root, referenced_schema, value);
+		}
+	}
+
+	for keyword in ["allOf", "anyOf", "oneOf"] {
+		for schema in schema.get(keyword).and_then(serde_json::Value::as_array).into_iter().flatten() {
+			redact_write_only(root, schema, value);
+		}
+	}
+
+	match value {
+		| serde_json::Value::Object(map) => {
+			let properties = schema.get("properties").and_then(serde_json::Value::as_object);
+			for (key, value) in map.iter_mut() {
+				// NOTE: les clés des fichiers d'environnement sont en
+				//       majuscules, celles des schémas en minuscules.
+				let property = properties.and_then(|properties| {
+					properties
+						.get(key)
+						.or_else(|| properties.get(&key.to_lowercase()))
+				});
+				if let Some(schema) = property.or_else(|| schema.get("additionalProperties")) {
+					redact_write_only(root, schema, value);
+				}
+			}
+		}
+		| serde_json::Value::Array(items) => {
+			if let Some(schema) = schema.get("items") {
+				for item in items {
+					redact_write_only(root, schema, item);
+				}
+			}
+		}
+		| _ => {}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::super::fetch_config;
+	use super::*;
+	use crate::process::ProcessMode;
+	use crate::Secret;
+
+	#[derive(serde::Deserialize)]
+	struct Database
+	{
+		dsn: Secret<String>,
+		port: Secret<u16>,
+		name: String,
+		replica_port: u16,
+		tls: bool,
+	}
+
+	#[test]
+	fn test_dump_redacts_secret_fields()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		let mut settings = KernelSettings::new(dir.path(), ProcessMode::LOCAL);
+		settings.directory.set_config_directory(dir.path());
+		settings.config.secret_patterns.clear();
+		std::fs::write(
+			dir.path().join("database.yml"),
+			"dsn: postgres://u:hunter2@h/db\nport: 6543\nname: app\nreplica_port: 6543\ntls: true\n",
+		)
+		.unwrap();
+
+		let (database, value): (Database, _) = fetch_config("database", &settings).unwrap();
+		assert_eq!(database.dsn.expose(), "postgres://u:hunter2@h/db");
+		assert_eq!((*database.port.expose(), database.name.as_str()), (6543, "app"));
+		assert_eq!((database.replica_port, database.tls), (6543, true));
+
+		let loaded_configs = LoadedConfigs::default();
+		loaded_configs.insert_config("database", value);
+		std::fs::remove_file(dir.path().join("database.yml")).unwrap();
+		let dump = loaded_configs.dump([], &settings, &ConfigSchemas::default());
+		assert_eq!(
+			dump.config["database"],
+			serde_json::json!({
+				"dsn": REDACTED_VALUE,
+				"port": REDACTED_VALUE,
+				"name": "app",
+				"replica_port": 6543,
+				"tls": true,
+			})
+		);
+		assert!(!dump.render(ConfigDumpFormat::YAML).contains("hunter2"));
+	}
+}
@@ -0,0 +1,396 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::path;
+
+use serde_json::Value;
+
+use super::secret_file::SECRET_FILE_SUFFIX;
+use crate::process::ProcessMode;
+
+// -------- //
+// Constant //
+// -------- //
+
+/// Séparateur des segments d'une variable d'environnement de surcharge.
+pub const ENV_OVERRIDE_SEPARATOR: &str = "__";
+
+/// Suffixe des fichiers d'environnement locaux, qui NE DOIVENT PAS être
+/// versionnés (ex: `.env.local`).
+pub const ENV_LOCAL_SUFFIX: &str = ".local";
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Transforme un nom (d'application, de configuration) en un segment de
+/// variable d'environnement: `lexa-app` devient `LEXA_APP`.
+pub fn env_segment(name: &str) -> String
+{
+	name.chars()
+		.map(|ch| if ch.is_ascii_alphanumeric() { ch.to_ascii_uppercase() } else { '_' })
+		.collect()
+}
+
+/// Fichiers d'environnement existants d'un répertoire, du moins prioritaire
+/// au plus prioritaire :
+///
+///   1. `<FILENAME>` (ex: `.env`) ;
+///   2. `<FILENAME>.<mode>` (ex: `.env.dev`) ;
+///   3. `<FILENAME>.local` ;
+///   4. `<FILENAME>.<mode>.local`.
+///
+/// Les fichiers `.local` sont ignorés en mode [ProcessMode::TEST], afin que
+/// les tests ne dépendent pas de la machine sur laquelle ils sont lancés. Un
+/// suffixe de mode vide ignore les fichiers 2. et 4.
+pub fn env_file_cascade(
+	env_directory: &path::Path,
+	filename: &str,
+	mode_suffix: &str,
+	process_mode: ProcessMode,
+) -> Vec<path::PathBuf>
+{
+	let mode_suffix = mode_suffix.trim_start_matches('.');
+	let mode_filename = (!mode_suffix.is_empty()).then(|| format!("{filename}.{mode_suffix}"));
+
+	let mut filenames = vec![filename.to_owned()];
+	filenames.extend(mode_filename.clone());
+	if process_mode != ProcessMode::TEST {
+		filenames.push(format!("{filename}{ENV_LOCAL_SUFFIX}"));
+		filenames.extend(mode_filename.map(|mode_filename| format!("{mode_filename}{ENV_LOCAL_SUFFIX}")));
+	}
+
+	filenames
+		.into_iter()
+		.map(|filename| env_directory.join(filename))
+		.filter(|filepath| filepath.is_file())
+		.collect()
+}
+
+/// Préfixe des variables d'environnement qui surchargent une configuration.
+///
+/// Exemple: pour le préfixe `LEXA_APP` et la configuration `database`, le
+/// préfixe est `LEXA_APP__DATABASE__`.
+pub fn env_override_prefix(prefix: &str, config_name: &str) -> String
+{
+	format!(
+		"{prefix}{ENV_OVERRIDE_SEPARATOR}{}{ENV_OVERRIDE_SEPARATOR}",
+		env_segment(config_name)
+	)
+}
+
+/// Les variables d'environnement de surcharge d'une configuration.
+///
+/// La variable `LEXA_APP__DATABASE__POOL_SIZE=20` définit la clé `pool_size`
+/// de la configuration `database`, `LEXA_APP__DATABASE__TLS__CERT_PATH` la
+/// clé `tls.cert_path`, etc.
+///
+/// La variable `LEXA_APP__DATABASE__PASSWORD_FILE=/run/secrets/db` définit la
+/// clé `password` à partir du contenu du fichier `/run/secrets/db` (voir
+/// [read_secret_file()](super::read_secret_file)), sauf si la clé
+/// `password_file` existe dans la configuration.
+///
+/// Retourne, triés par nom, le nom de chaque variable, le chemin de la clé
+/// qu'elle définit et sa valeur brute.
+pub(super) fn env_overrides(
+	prefix: &str,
+	config_name: &str,
+	vars: impl IntoIterator<Item = (String, String)>,
+) -> Vec<(String, Vec<String>, String)>
+{
+	let var_prefix = env_override_prefix(prefix, config_name);
+
+	let mut overrides: Vec<_> = vars
+		.into_iter()
+		.filter_map(|(name, value)| {
+			let path = name.strip_prefix(&var_prefix)?;
+			let path: Vec<_> = path
+				.split(ENV_OVERRIDE_SEPARATOR)
+				.map(str::to_ascii_lowercase)
+				.collect();
+			(!path.iter().any(String::is_empty)).then_some((name, path, value))
+		})
+		.collect();
+
+	// NOTE: l'ordre des variables d'environnement n'est pas garanti.
+	overrides.sort();
+	overrides
+}
+
+/// Les variables d'environnement du processus. Les variables dont le nom ou
+/// la valeur n'est pas valide en Unicode sont ignorées.
+pub(super) fn process_env_vars() -> impl Iterator<Item = (String, String)>
+{
+	std::env::vars_os().filter_map(|(name, value)| Some((name.into_string().ok()?, value.into_string().ok()?)))
+}
+
+/// Définit une valeur à un chemin donné en créant les objets intermédiaires.
+/// Les segments du chemin correspondent aux clés existantes sans tenir compte
+/// de la casse ; retourne le chemin des clés effectivement définies.
+///
+/// Dans une liste, un segment est l'index d'un élément existant, ou l'index
+/// qui suit le dernier élément afin d'en ajouter un. Tout autre segment est
+/// une erreur.
+pub(super) fn set_path(config: &mut Value, path: &[String], raw_value: &str) -> Result<Vec<String>, String>
+{
+	let Some((segment, rest)) = path.split_first() else {
+		*config = coerce(raw_value, Some(config));
+		return Ok(Vec::new());
+	};
+
+	if let Value::Array(items) = config {
+		let index = segment
+			.parse::<usize>()
+			.ok()
+			.filter(|index| *index <= items.len())
+			.ok_or_else(|| {
+				format!(
+					"le segment « {segment} » n'est pas un index valide d'une liste de {} élément(s)",
+					items.len()
+				)
+			})?;
+		if index == items.len() {
+			items.push(Value::Null);
+		}
+		let rest = set_path(&mut items[index], rest, raw_value)?;
+		return Ok(std::iter::once(segment.to_owned()).chain(rest).collect());
+	}
+
+	if !config.is_object() {
+		*config = Value::Object(Default::default());
+	}
+
+	let Value::Object(map) = config else {
+		unreachable!()
+	};
+
+	let key = find_key(map, segment)
+		.cloned()
+		.unwrap_or_else(|| segment.to_owned());
+
+	if rest.is_empty() {
+		let value = coerce(raw_value, map.get(&key));
+		map.insert(key.clone(), value);
+		Ok(vec![key])
+	} else {
+		let rest = set_path(map.entry(key.clone()).or_insert(Value::Null), rest, raw_value)?;
+		Ok(std::iter::once(key).chain(rest).collect())
+	}
+}
+
+/// Chemin de la clé définie par une variable de surcharge `<...>_FILE`, dont
+/// la valeur est le chemin d'un fichier secret (voir [SECRET_FILE_SUFFIX]).
+///
+/// Retourne [None] lorsque le dernier segment ne se termine pas par `_file`
+/// ou lorsque la clé `<...>_file` existe dans la configuration : la variable
+/// est alors une surcharge ordinaire.
+pub(super) fn secret_file_path(config: &Value, path: &[String]) -> Option<Vec<String>>
+{
+	let (last, parents) = path.split_last()?;
+	let key = last.strip_suffix(&SECRET_FILE_SUFFIX.to_ascii_lowercase())?;
+	if key.is_empty() {
+		return None;
+	}
+
+	let exists = path.iter().try_fold(config, |value, segment| {
+		find_key(value.as_object()?, segment).and_then(|key| value.get(key))
+	});
+	if exists.is_some() {
+		return None;
+	}
+
+	Some(parents.iter().cloned().chain([key.to_owned()]).collect())
+}
+
+/// Clé d'un objet correspondant à un segment, sans tenir compte de la casse
+/// ni des tirets.
+fn find_key<'a>(map: &'a serde_json::Map<String, Value>, segment: &str) -> Option<&'a String>
+{
+	map.keys()
+		.find(|key| key.replace('-', "_").eq_ignore_ascii_case(segment))
+}
+
+/// Convertit la valeur brute d'une variable d'environnement dans le type de
+/// la valeur qu'elle remplace. Lorsqu'aucune valeur typée n'est remplacée, ou
+/// que la conversion échoue, la valeur reste une chaîne de caractères : elle
+/// est convertie dans le type attendu lors de la dé-sérialisation (voir
+/// [fetch_config()](super::fetch_config)).
+fn coerce(raw_value: &str, existing: Option<&Value>) -> Value
+{
+	let coerced = match existing {
+		| Some(Value::String(_)) => Some(Value::String(raw_value.to_owned())),
+		| Some(Value::Bool(_)) => {
+			match raw_value.trim().to_ascii_lowercase().as_str() {
+				| "true" | "1" | "yes" | "on" => Some(Value::Bool(true)),
+				| "false" | "0" | "no" | "off" => Some(Value::Bool(false)),
+				| _ => None,
+			}
+		}
+		| Some(Value::Number(_)) => {
+			let raw_value = raw_value.trim();
+			raw_value
+				.parse::<i64>()
+				.map(Value::from)
+				.or_else(|_| raw_value.parse::<u64>().map(Value::from))
+				.ok()
+				.or_else(|| {
+					raw_value
+						.parse::<f64>()
+						.ok()
+						.and_then(serde_json::Number::from_f64)
+						.map(Value::Number)
+				})
+		}
+		| _ => None,
+	};
+
+	coerced.unwrap_or_else(|| Value::String(raw_value.to_owned()))
+}
+
+#[cfg(test)]
+mod tests
+{
+	use serde_json::json;
+
+	use super::*;
+
+	fn vars(vars: &[(&str, &str)]) -> Vec<(String, String)>
+	{
+		vars.iter()
+			.map(|(name, value)| (name.to_string(), value.to_string()))
+			.collect()
+	}
+
+	#[test]
+	fn test_env_segment()
+	{
+		assert_eq!(env_segment("lexa-app"), "LEXA_APP");
+		assert_eq!(env_override_prefix("LEXA_APP", "my-db"), "LEXA_APP__MY_DB__");
+	}
+
+	#[test]
+	fn test_env_overrides()
+	{
+		let overrides = env_overrides(
+			"APP",
+			"db",
+			vars(&[
+				("APP__DB__TLS__CERT_PATH", "/cert"),
+				("APP__DB__PASS", "12345"),
+				("APP__DB__", "ignored"),
+				("APP__DB__A____B", "ignored"),
+				("APP__OTHER__PASS", "ignored"),
+			]),
+		);
+		assert_eq!(
+			overrides,
+			[
+				("APP__DB__PASS".to_owned(), vec!["pass".to_owned()], "12345".to_owned()),
+				(
+					"APP__DB__TLS__CERT_PATH".to_owned(),
+					vec!["tls".to_owned(), "cert_path".to_owned()],
+					"/cert".to_owned()
+				),
+			]
+		);
+	}
+
+	#[test]
+	fn test_set_path_keeps_raw_strings()
+	{
+		let mut config = json!({ "port": 80, "debug": false, "name": "x" });
+		for (key, raw_value) in [
+			("port", "8080"),
+			("debug", "yes"),
+			("name", "007"),
+			("pass", "12345"),
+			("flag", "no"),
+			("nothing", "null"),
+		] {
+			set_path(&mut config, &[key.to_owned()], raw_value).unwrap();
+		}
+		assert_eq!(
+			config,
+			json!({
+				"port": 8080,
+				"debug": true,
+				"name": "007",
+				"pass": "12345",
+				"flag": "no",
+				"nothing": "null",
+			})
+		);
+
+		let mut config = json!({ "port": 80 });
+		set_path(&mut config, &["port".to_owned()], "abc").unwrap();
+		assert_eq!(config, json!({ "port": "abc" }));
+	}
+
+	#[test]
+	fn test_set_path_matches_existing_keys()
+	{
+		let mut config = json!({ "Pool-Size": 1 });
+		let path = set_path(&mut config, &["pool_size".to_owned()], "5").unwrap();
+		assert_eq!(path, ["Pool-Size"]);
+		assert_eq!(config, json!({ "Pool-Size": 5 }));
+
+		let mut config = serde_json::Value::Null;
+		set_path(&mut config, &["tls".to_owned(), "cert".to_owned()], "/cert").unwrap();
+		assert_eq!(config, json!({ "tls": { "cert": "/cert" } }));
+	}
+
+	#[test]
+	fn test_set_path_array_index()
+	{
+		let mut config = json!({ "hosts": [{ "port": 1 }, { "port": 2 }] });
+		let path = set_path(&mut config, &["hosts".to_owned(), "1".to_owned(), "port".to_owned()], "3").unwrap();
+		assert_eq!(path, ["hosts", "1", "port"]);
+		set_path(&mut config, &["hosts".to_owned(), "2".to_owned()], "x").unwrap();
+		assert_eq!(config, json!({ "hosts": [{ "port": 1 }, { "port": 3 }, "x"] }));
+
+		assert!(set_path(&mut config, &["hosts".to_owned(), "5".to_owned()], "x").is_err());
+		assert!(set_path(&mut config, &["hosts".to_owned(), "name".to_owned()], "x").is_err());
+		assert_eq!(config["hosts"].as_array().map(Vec::len), Some(3));
+	}
+
+	#[test]
+	fn test_secret_file_path()
+	{
+		let config = json!({ "password_file": "/x" });
+		assert_eq!(secret_file_path(&config, &["password_file".to_owned()]), None);
+		assert_eq!(
+			secret_file_path(&json!({}), &["db".to_owned(), "password_file".to_owned()]),
+			Some(vec!["db".to_owned(), "password".to_owned()])
+		);
+		assert_eq!(secret_file_path(&json!({}), &["_file".to_owned()]), None);
+		assert_eq!(secret_file_path(&json!({}), &["password".to_owned()]), None);
+	}
+
+	#[test]
+	fn test_env_file_cascade()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		for filename in [".env", ".env.dev", ".env.local", ".env.dev.local", ".env.prod"] {
+			std::fs::write(dir.path().join(filename), "").unwrap();
+		}
+		let filenames = |mode_suffix, process_mode| {
+			env_file_cascade(dir.path(), ".env", mode_suffix, process_mode)
+				.into_iter()
+				.map(|filepath| filepath.file_name().unwrap().to_string_lossy().into_owned())
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(
+			filenames("dev", ProcessMode::DEVELOPMENT),
+			[".env", ".env.dev", ".env.local", ".env.dev.local"]
+		);
+		assert_eq!(filenames("", ProcessMode::LOCAL), [".env", ".env.local"]);
+		assert_eq!(filenames("prod", ProcessMode::TEST), [".env", ".env.prod"]);
+	}
+}
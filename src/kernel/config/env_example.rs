@@ -0,0 +1,361 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::fmt::Write as _;
+use std::{fs, path};
+
+use super::secret_file::SECRET_FILE_SUFFIX;
+use crate::process::ProcessMode;
+use crate::settings::KernelSettings;
+use crate::{ApplicationEnvInterface, KernelError};
+
+// -------- //
+// Constant //
+// -------- //
+
+/// Suffixe des fichiers d'exemple des variables d'environnement (ex:
+/// `.env.example`).
+pub const ENV_EXAMPLE_SUFFIX: &str = ".example";
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Description d'un champ des variables d'environnement de l'application,
+/// utilisée pour générer les fichiers d'exemple (voir
+/// [ApplicationEnvInterface::describe_env()](crate::ApplicationEnvInterface::describe_env)).
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct EnvField
+{
+	/// Nom de la variable (ex: `DATABASE_URL`).
+	pub name: String,
+	/// Type attendu (ex: `u16`).
+	pub ty: Option<String>,
+	/// Valeur par défaut.
+	pub default: Option<String>,
+	/// Documentation du champ.
+	pub doc: Option<String>,
+	/// Est-ce que la valeur est secrète? Une valeur secrète n'est jamais
+	/// écrite dans les fichiers d'exemple.
+	pub secret: bool,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl EnvField
+{
+	/// Champ d'une variable ; le nom est converti en majuscules.
+	pub fn new(name: impl AsRef<str>) -> Self
+	{
+		Self {
+			name: name.as_ref().to_ascii_uppercase(),
+			..Default::default()
+		}
+	}
+
+	/// Définit le type attendu.
+	pub fn with_type(mut self, ty: impl Into<String>) -> Self
+	{
+		self.ty.replace(ty.into());
+		self
+	}
+
+	/// Définit la valeur par défaut.
+	pub fn with_default(mut self, default: impl ToString) -> Self
+	{
+		self.default.replace(default.to_string());
+		self
+	}
+
+	/// Définit la documentation.
+	pub fn with_doc(mut self, doc: impl Into<String>) -> Self
+	{
+		self.doc.replace(doc.into());
+		self
+	}
+
+	/// Marque la valeur comme secrète.
+	pub fn secret(mut self) -> Self
+	{
+		self.secret = true;
+		self
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Contenu d'un fichier d'exemple des variables d'environnement. Chaque
+/// variable est précédée de sa documentation, de son type et, pour une valeur
+/// secrète, de la variable `<NAME>_FILE` ; les valeurs secrètes sont vides.
+pub fn render_env_example(fields: &[EnvField], mode_suffix: Option<&str>) -> String
+{
+	let mut content = String::from("# Fichier généré à partir des variables d'environnement de l'application");
+	if let Some(mode_suffix) = mode_suffix {
+		_ = write!(content, " (mode « {mode_suffix} »)");
+	}
+	content.push_str(".\n# NE PAS modifier ce fichier manuellement.\n");
+
+	for field in fields {
+		content.push('\n');
+		for line in field.doc.iter().flat_map(|doc| doc.lines()) {
+			match line.trim() {
+				| "" => content.push_str("#\n"),
+				| line => _ = writeln!(content, "# {line}"),
+			}
+		}
+		if let Some(ty) = &field.ty {
+			_ = writeln!(content, "# Type : {ty}");
+		}
+		if field.secret {
+			_ = writeln!(content, "# Secret : peut être défini via « {}{SECRET_FILE_SUFFIX} ».", field.name);
+		}
+		let value = field.default.as_deref().filter(|_| !field.secret).unwrap_or_default();
+		_ = writeln!(content, "{}={value}", field.name);
+	}
+
+	content
+}
+
+/// Fichiers d'exemple attendus dans le répertoire des variables
+/// d'environnement, avec leur contenu :
+///
+///   - `<FILENAME>.example`, décrit avec les paramètres du mode
+///     [LOCAL](ProcessMode::LOCAL) ;
+///   - `<FILENAME>.<suffix>.example`, pour chaque mode d'exécution dont le
+///     suffixe n'est pas vide (voir
+///     [ApplicationEnvInterface::with_suffix()]) et dont les champs diffèrent
+///     de ceux du fichier `<FILENAME>.example`.
+pub(crate) fn env_examples<E>(
+	env_directory: &path::Path,
+	settings: &KernelSettings,
+) -> Vec<(path::PathBuf, String)>
+where
+	E: ApplicationEnvInterface,
+{
+	let settings_for = |process_mode| {
+		let mut settings = settings.clone();
+		settings.process_mode = process_mode;
+		settings
+	};
+
+	let base_fields = E::describe_env(&settings_for(ProcessMode::LOCAL));
+	let mut examples = vec![(
+		env_directory.join(format!("{}{ENV_EXAMPLE_SUFFIX}", E::FILENAME)),
+		render_env_example(&base_fields, None),
+	)];
+
+	let process_modes = [
+		ProcessMode::LOCAL,
+		ProcessMode::DEVELOPMENT,
+		ProcessMode::PRODUCTION,
+		ProcessMode::TEST,
+	];
+	for process_mode in process_modes {
+		let settings = settings_for(process_mode);
+		let mode_suffix = E::with_suffix(&settings).to_string();
+		if mode_suffix.is_empty() {
+			continue;
+		}
+		let filepath = env_directory.join(format!("{}.{mode_suffix}{ENV_EXAMPLE_SUFFIX}", E::FILENAME));
+		let fields = E::describe_env(&settings);
+		if fields == base_fields || examples.iter().any(|(path, _)| *path == filepath) {
+			continue;
+		}
+		examples.push((filepath, render_env_example(&fields, Some(&mode_suffix))));
+	}
+
+	examples
+}
+
+/// Fichiers d'exemple `<FILENAME>.<suffix>.example` du répertoire des
+/// variables d'environnement qui ne sont plus attendus.
+pub(crate) fn stale_env_examples(
+	env_directory: &path::Path,
+	filename: &str,
+	examples: &[(path::PathBuf, String)],
+) -> Vec<path::PathBuf>
+{
+	let prefix = format!("{filename}.");
+	let Ok(entries) = fs::read_dir(env_directory) else {
+		return Vec::new();
+	};
+
+	let mut stale_examples: Vec<_> = entries
+		.filter_map(Result::ok)
+		.map(|entry| entry.path())
+		.filter(|filepath| {
+			filepath
+				.file_name()
+				.and_then(|name| name.to_str())
+				.and_then(|name| name.strip_prefix(&prefix))
+				.and_then(|name| name.strip_suffix(ENV_EXAMPLE_SUFFIX))
+				.is_some_and(|mode_suffix| !mode_suffix.is_empty() && !mode_suffix.contains('.'))
+		})
+		.filter(|filepath| !examples.iter().any(|(path, _)| path == filepath))
+		.collect();
+	stale_examples.sort();
+	stale_examples
+}
+
+/// Écrit les fichiers d'exemple attendus et supprime ceux qui ne le sont
+/// plus. Retourne les chemins des fichiers écrits.
+pub(crate) fn write_env_examples<E>(
+	env_directory: &path::Path,
+	settings: &KernelSettings,
+) -> Result<Vec<path::PathBuf>, KernelError>
+where
+	E: ApplicationEnvInterface,
+{
+	let examples = env_examples::<E>(env_directory, settings);
+
+	for filepath in stale_env_examples(env_directory, E::FILENAME, &examples) {
+		fs::remove_file(&filepath).map_err(|reason| KernelError::Write { path: filepath, reason })?;
+	}
+
+	examples
+		.into_iter()
+		.map(|(filepath, content)| {
+			fs::write(&filepath, content).map_err(|reason| {
+				KernelError::Write {
+					path: filepath.clone(),
+					reason,
+				}
+			})?;
+			Ok(filepath)
+		})
+		.collect()
+}
+
+/// Vérifie que les fichiers d'exemple versionnés correspondent aux fichiers
+/// attendus. Les fichiers manquants, différents ou qui ne sont plus attendus
+/// sont retournés ensemble dans une [KernelError::EnvExampleOutdated].
+pub(crate) fn check_env_examples<E>(env_directory: &path::Path, settings: &KernelSettings) -> Result<(), KernelError>
+where
+	E: ApplicationEnvInterface,
+{
+	let examples = env_examples::<E>(env_directory, settings);
+
+	let mut outdated: Vec<_> = examples
+		.iter()
+		.filter(|(filepath, content)| fs::read_to_string(filepath).ok().as_ref() != Some(content))
+		.map(|(filepath, _)| filepath.clone())
+		.collect();
+	outdated.extend(stale_env_examples(env_directory, E::FILENAME, &examples));
+
+	if outdated.is_empty() {
+		return Ok(());
+	}
+	Err(KernelError::EnvExampleOutdated(outdated))
+}
+
+/// Champs d'une structure de champs nommés, sans type, valeur par défaut ni
+/// documentation.
+pub(crate) fn default_env_fields<T>() -> Vec<EnvField>
+where
+	T: serde::de::DeserializeOwned,
+{
+	super::env_file::field_names::<T>()
+		.iter()
+		.map(EnvField::new)
+		.collect()
+}
+
+/// Champs d'une structure de champs nommés à partir de son schéma JSON : le
+/// type (ou le format), la valeur par défaut et la documentation de chaque
+/// propriété. Les propriétés `"writeOnly": true` (voir
+/// [Secret](crate::Secret)) sont secrètes.
+#[cfg(feature = "schema")]
+pub fn env_fields_from_schema<T>() -> Vec<EnvField>
+where
+	T: schemars::JsonSchema,
+{
+	let schema = schemars::schema_for!(T).to_value();
+
+	let resolve = |property: &serde_json::Value| -> serde_json::Value {
+		let reference = property
+			.get("$ref")
+			.and_then(serde_json::Value::as_str)
+			.and_then(|reference| reference.strip_prefix('#'))
+			.and_then(|pointer| schema.pointer(pointer));
+		let Some(reference) = reference else {
+			return property.clone();
+		};
+		let mut property = property.clone();
+		if let (Some(object), Some(reference)) = (property.as_object_mut(), reference.as_object()) {
+			for (key, value) in reference {
+				object.entry(key.clone()).or_insert_with(|| value.clone());
+			}
+		}
+		property
+	};
+
+	let properties = schema
+		.get("properties")
+		.and_then(serde_json::Value::as_object)
+		.into_iter()
+		.flatten();
+
+	properties
+		.map(|(name, property)| {
+			let property = resolve(property);
+			let mut field = EnvField::new(name);
+			field.ty = property
+				.get("format")
+				.or_else(|| property.get("type"))
+				.map(|ty| {
+					match ty {
+						| serde_json::Value::Array(types) => {
+							types
+								.iter()
+								.filter_map(serde_json::Value::as_str)
+								.collect::<Vec<_>>()
+								.join(" | ")
+						}
+						| ty => ty.as_str().map(ToOwned::to_owned).unwrap_or_else(|| ty.to_string()),
+					}
+				})
+				.or_else(|| {
+					property.get("enum").and_then(serde_json::Value::as_array).map(|variants| {
+						variants
+							.iter()
+							.map(|variant| variant.as_str().map(ToOwned::to_owned).unwrap_or_else(|| variant.to_string()))
+							.collect::<Vec<_>>()
+							.join(" | ")
+					})
+				});
+			field.default = property.get("default").map(|default| {
+				match default {
+					| serde_json::Value::String(default) => default.clone(),
+					| serde_json::Value::Array(items) => {
+						items
+							.iter()
+							.map(|item| item.as_str().map(ToOwned::to_owned).unwrap_or_else(|| item.to_string()))
+							.collect::<Vec<_>>()
+							.join(",")
+					}
+					| default => default.to_string(),
+				}
+			});
+			field.doc = property
+				.get("description")
+				.and_then(serde_json::Value::as_str)
+				.map(ToOwned::to_owned);
+			field.secret = property.get("writeOnly").and_then(serde_json::Value::as_bool) == Some(true);
+			field
+		})
+		.collect()
+}
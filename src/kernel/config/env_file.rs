@@ -0,0 +1,316 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path;
+
+use serde::de::Error as _;
+
+use super::env::process_env_vars;
+use super::env_report::{unknown_variables, EnvReport};
+use super::secret_file::{read_secret_file, SecretFilePermissions, SECRET_FILE_SUFFIX};
+use super::{loader, ConfigValue};
+use crate::KernelError;
+
+// -------- //
+// Constant //
+// -------- //
+
+thread_local! {
+	/// Variables d'environnement résolues par [fetch_env_file()], lorsqu'elles
+	/// sont enregistrées (voir [record_env_vars()]).
+	static RECORDED_ENV_VARS: RefCell<Option<Option<ConfigValue>>> = const { RefCell::new(None) };
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Exécute une fonction en enregistrant les variables d'environnement
+/// résolues par [fetch_env_file()] dans le thread courant. Retourne le
+/// résultat de la fonction et les variables du dernier appel réussi à
+/// [fetch_env_file()], s'il y en a eu un (ex: aucun lorsque
+/// [ApplicationEnvInterface::fetch()](crate::ApplicationEnvInterface::fetch)
+/// est redéfinie), ainsi que les noms des variables des valeurs
+/// [Secret](crate::Secret).
+pub(crate) fn record_env_vars<R>(f: impl FnOnce() -> R) -> (R, Option<ConfigValue>)
+{
+	let previous = RECORDED_ENV_VARS.replace(Some(None));
+	let output = f();
+	let recorded = RECORDED_ENV_VARS.replace(previous).flatten();
+	(output, recorded)
+}
+
+/// Dé-sérialise une structure de champs nommés à partir des variables des
+/// fichiers d'environnement, fusionnées du moins prioritaire au plus
+/// prioritaire, puis de celles des fichiers secrets. Les variables
+/// d'environnement du processus ne sont jamais modifiées.
+///
+/// Lorsqu'un préfixe `process_prefix` est donné (ex: `LEXA_APP_`), les
+/// variables d'environnement du processus `<PREFIX><FIELD>` surchargent les
+/// variables `<FIELD>` des fichiers d'environnement. Les autres variables du
+/// processus sont ignorées.
+///
+/// Pour chaque champ `<FIELD>` de la structure :
+///
+///   1. la variable `<FIELD>_FILE` désigne un fichier dont le contenu est la
+///      valeur de la variable `<FIELD>`. Les deux variables NE DOIVENT PAS
+///      être définies en même temps ;
+///   2. à défaut, le fichier `<field>` (ou `<FIELD>`) du répertoire des
+///      secrets (ex: `/run/secrets`), s'il existe, est la valeur de la
+///      variable.
+pub(crate) fn fetch_env_file<T>(
+	env_filepaths: &[&path::Path],
+	process_prefix: Option<&str>,
+	secrets_directory: Option<&path::Path>,
+	permissions: SecretFilePermissions,
+) -> Result<T, KernelError>
+where
+	T: serde::de::DeserializeOwned,
+{
+	let mut env_vars = BTreeMap::new();
+	let mut process_names = BTreeSet::new();
+	// NOTE: la source de chaque variable, pour le rapport des variables
+	//       invalides.
+	let mut sources = BTreeMap::new();
+
+	for env_filepath in env_filepaths {
+		log::trace!("Chargement du fichier d'environnement « {} »", env_filepath.display());
+		let values = loader::read(env_filepath, lexa_fs::Extension::ENV).map_err(lexa_env::Error::from)?;
+		for (name, value) in values.as_object().into_iter().flatten() {
+			let value = value.as_str().map_or_else(|| value.to_string(), ToOwned::to_owned);
+			sources.insert(name.clone(), env_filepath.display().to_string());
+			env_vars.insert(name.clone(), value);
+		}
+	}
+	if let Some(process_prefix) = process_prefix {
+		for (name, value) in process_env_vars() {
+			let Some(name) = name.strip_prefix(process_prefix).filter(|name| !name.is_empty()) else {
+				continue;
+			};
+			sources.insert(name.to_owned(), format!("processus ({process_prefix}{name})"));
+			process_names.insert(name.to_owned());
+			env_vars.insert(name.to_owned(), value);
+		}
+	}
+
+	let secret_error = |filepath: &path::Path, reason| {
+		KernelError::SecretFile {
+			path: filepath.to_owned(),
+			reason,
+		}
+	};
+
+	let fields = field_names::<T>();
+
+	for field in fields {
+		let variable = field.to_ascii_uppercase();
+		let file_variable = format!("{variable}{SECRET_FILE_SUFFIX}");
+
+		let filepath = match env_vars.get(&file_variable) {
+			| Some(filepath) => {
+				if env_vars.contains_key(&variable) {
+					return Err(KernelError::SecretFileConflict(variable));
+				}
+				path::PathBuf::from(filepath)
+			}
+			| None if !env_vars.contains_key(&variable) => {
+				let Some(filepath) = secrets_directory.and_then(|dir| {
+					[dir.join(field), dir.join(&variable)]
+						.into_iter()
+						.find(|filepath| filepath.is_file())
+				}) else {
+					continue;
+				};
+				filepath
+			}
+			| None => continue,
+		};
+
+		log::trace!("Lecture de la variable « {variable} » depuis le fichier « {} »", filepath.display());
+		let value = read_secret_file(&filepath, permissions).map_err(|err| secret_error(&filepath, err))?;
+		sources.insert(variable.clone(), format!("fichier secret ({})", filepath.display()));
+		env_vars.insert(variable, value);
+	}
+
+	let file_names = env_vars
+		.keys()
+		.filter(|name| !process_names.contains(*name))
+		.map(String::as_str);
+	let unknown = unknown_variables(fields, file_names, process_names.iter().map(String::as_str), &sources);
+
+	match EnvReport::probe::<T>(&env_vars, &sources) {
+		| Ok((env, secret_variables)) => {
+			for violation in unknown {
+				log::warn!("Variable d'environnement {violation}");
+			}
+			RECORDED_ENV_VARS.with_borrow_mut(|recorded| {
+				if let Some(recorded) = recorded {
+					recorded.replace(ConfigValue {
+						value: serde_json::Value::Object(
+							env_vars
+								.into_iter()
+								.map(|(name, value)| (name, serde_json::Value::from(value)))
+								.collect(),
+						),
+						secret_paths: secret_variables.into_iter().map(|name| vec![name]).collect(),
+					});
+				}
+			});
+			Ok(env)
+		}
+		| Err(report) => Err(KernelError::InvalidEnv(report.with_unknown(unknown))),
+	}
+}
+
+/// Noms des champs d'une structure de champs nommés, obtenus via son
+/// implémentation de [serde::Deserialize].
+pub(crate) fn field_names<T>() -> &'static [&'static str]
+where
+	T: serde::de::DeserializeOwned,
+{
+	struct FieldNames<'a>(&'a mut &'static [&'static str]);
+
+	impl<'de> serde::Deserializer<'de> for FieldNames<'_>
+	{
+		type Error = serde::de::value::Error;
+
+		fn deserialize_any<V>(self, _: V) -> Result<V::Value, Self::Error>
+		where
+			V: serde::de::Visitor<'de>,
+		{
+			Err(Self::Error::custom("structure de champs nommés attendue"))
+		}
+
+		fn deserialize_struct<V>(
+			self,
+			_: &'static str,
+			fields: &'static [&'static str],
+			_: V,
+		) -> Result<V::Value, Self::Error>
+		where
+			V: serde::de::Visitor<'de>,
+		{
+			*self.0 = fields;
+			Err(Self::Error::custom("noms des champs récupérés"))
+		}
+
+		serde::forward_to_deserialize_any! {
+			bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+			bytes byte_buf option unit unit_struct newtype_struct seq tuple
+			tuple_struct map enum identifier ignored_any
+		}
+	}
+
+	let mut fields: &'static [&'static str] = &[];
+	_ = T::deserialize(FieldNames(&mut fields));
+	fields
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	#[derive(Debug)]
+	#[derive(serde::Deserialize)]
+	struct Env
+	{
+		lexa_kernel_test_name: String,
+		path: Option<String>,
+		th: Option<String>,
+	}
+
+	#[test]
+	fn test_fetch_env_file_ignores_unprefixed_process_vars()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		let env_filepath = dir.path().join(".env");
+		std::fs::write(&env_filepath, "LEXA_KERNEL_TEST_NAME=file\n").unwrap();
+
+		let env: Env = fetch_env_file(&[&env_filepath], Some("LEXA_KERNEL_TEST_UNDEFINED_"), None, Default::default())
+			.unwrap();
+		assert_eq!(env.lexa_kernel_test_name, "file");
+		assert_eq!(env.path, None);
+		assert_eq!(env.th, None);
+		assert_eq!(std::env::var_os("LEXA_KERNEL_TEST_NAME"), None);
+	}
+
+	#[derive(Debug)]
+	#[derive(serde::Deserialize)]
+	struct SecretEnv
+	{
+		password: String,
+	}
+
+	#[test]
+	fn test_fetch_env_file_reads_secret_files()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		let secret_filepath = dir.path().join("password");
+		std::fs::write(&secret_filepath, "hunter2\n").unwrap();
+		let env_filepath = dir.path().join(".env");
+		std::fs::write(&env_filepath, format!("PASSWORD_FILE={}\n", secret_filepath.display())).unwrap();
+
+		let env: SecretEnv = fetch_env_file(&[&env_filepath], None, None, Default::default()).unwrap();
+		assert_eq!(env.password, "hunter2");
+
+		let empty_filepath = dir.path().join(".env.empty");
+		std::fs::write(&empty_filepath, "").unwrap();
+		let env: SecretEnv = fetch_env_file(&[&empty_filepath], None, Some(dir.path()), Default::default()).unwrap();
+		assert_eq!(env.password, "hunter2");
+
+		std::fs::write(&env_filepath, format!("PASSWORD=x\nPASSWORD_FILE={}\n", secret_filepath.display())).unwrap();
+		let err = fetch_env_file::<SecretEnv>(&[&env_filepath], None, None, Default::default()).unwrap_err();
+		assert!(matches!(err, KernelError::SecretFileConflict(variable) if variable == "PASSWORD"));
+	}
+
+	#[test]
+	fn test_fetch_env_file_reads_prefixed_process_vars()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		let env_filepath = dir.path().join(".env");
+		std::fs::write(&env_filepath, "LEXA_KERNEL_TEST_NAME=file\nTH=file\n").unwrap();
+
+		// NOTE: la variable `PATH` est la variable `TH` du préfixe `PA`.
+		let env: Env = fetch_env_file(&[&env_filepath], Some("PA"), None, Default::default()).unwrap();
+		assert_eq!(env.th, std::env::var("PATH").ok());
+		assert_eq!(env.path, None);
+		assert_eq!(std::env::var_os("TH"), None);
+	}
+
+	#[test]
+	fn test_record_env_vars_records_secret_variables()
+	{
+		#[derive(serde::Deserialize)]
+		struct TokenEnv
+		{
+			token: crate::Secret<String>,
+			port: u16,
+		}
+
+		let dir = tempfile::tempdir().unwrap();
+		let env_filepath = dir.path().join(".env");
+		std::fs::write(&env_filepath, "TOKEN=8080\nPORT=8080\n").unwrap();
+
+		let (env, recorded) =
+			record_env_vars(|| fetch_env_file::<TokenEnv>(&[&env_filepath], None, None, Default::default()));
+		let env = env.unwrap();
+		assert_eq!((env.token.expose().as_str(), env.port), ("8080", 8080));
+		assert_eq!(
+			recorded,
+			Some(ConfigValue {
+				value: serde_json::json!({ "TOKEN": "8080", "PORT": "8080" }),
+				secret_paths: vec![vec![String::from("TOKEN")]],
+			})
+		);
+	}
+}
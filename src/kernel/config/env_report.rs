@@ -0,0 +1,916 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::{fmt, str};
+
+use serde::de::value::StrDeserializer;
+use serde::de::{DeserializeSeed, Error as _, IntoDeserializer, MapAccess, SeqAccess, Visitor};
+
+use super::secret_file::SECRET_FILE_SUFFIX;
+use crate::kernel::secret::SECRET_STRUCT_NAME;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Rapport des variables d'environnement manquantes, invalides ou inconnues
+/// d'une structure de champs nommés, établi en une seule passe.
+#[derive(Debug)]
+#[derive(Default)]
+pub struct EnvReport
+{
+	violations: Vec<EnvViolation>,
+	/// Erreur de la dé-sérialisation, lorsqu'elle a échoué.
+	error: Option<String>,
+}
+
+/// Problème d'une variable d'environnement.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct EnvViolation
+{
+	/// Nom de la variable (ex: `DATABASE_URL`).
+	pub variable: String,
+	/// Nature du problème.
+	pub kind: EnvViolationKind,
+	/// Type attendu (ex: `u16`), lorsqu'il est connu.
+	pub expected: Option<String>,
+	/// Source de la variable (fichier d'environnement, processus, fichier
+	/// secret), lorsqu'elle est définie.
+	pub source: Option<String>,
+	/// Indication pour corriger le problème.
+	pub hint: String,
+}
+
+/// État partagé d'une analyse de la dé-sérialisation.
+#[derive(Default)]
+struct ProbeState
+{
+	/// Variables définies (non vides), en minuscules.
+	vars: BTreeMap<String, String>,
+	/// Clés manquantes, remplacées par une valeur fictive.
+	placeholders: RefCell<BTreeSet<String>>,
+	/// Une clé manquante a été découverte : l'analyse doit être relancée.
+	retry: RefCell<bool>,
+	/// Clés manquantes ou invalides : (clé, nature, type attendu, indication).
+	violations: RefCell<Vec<(String, EnvViolationKind, String, String)>>,
+	/// Clés des valeurs [Secret](crate::Secret).
+	secret_keys: RefCell<BTreeSet<String>>,
+}
+
+/// Dé-sérialiseur d'une clé (ex: `database_url`), qui enregistre les valeurs
+/// manquantes ou invalides au lieu d'échouer.
+struct ValueProbe<'a>
+{
+	state: &'a ProbeState,
+	key: String,
+	value: Option<String>,
+}
+
+/// Accès aux champs d'une structure (ou aux clés d'une table) situés sous
+/// une clé.
+struct MapProbe<'a>
+{
+	state: &'a ProbeState,
+	keys: std::vec::IntoIter<(String, String)>,
+	value: Option<ValueProbe<'a>>,
+}
+
+/// Accès aux éléments d'une liste `a, b, c`.
+struct SeqProbe<'a>
+{
+	state: &'a ProbeState,
+	key: String,
+	elements: std::vec::IntoIter<String>,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Erreur de l'analyse de la dé-sérialisation.
+#[derive(Debug)]
+enum ProbeError
+{
+	/// Un champ de la structure est manquant : il est remplacé par une valeur
+	/// fictive lors de l'analyse suivante.
+	MissingField(&'static str),
+	/// Toute autre erreur.
+	Custom(String),
+}
+
+/// Nature du problème d'une variable d'environnement.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum EnvViolationKind
+{
+	/// La variable n'est pas définie, ou est vide.
+	Missing,
+	/// La valeur de la variable ne peut pas être convertie dans le type
+	/// attendu.
+	Invalid,
+	/// La variable ne correspond à aucun champ.
+	Unknown,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl EnvReport
+{
+	/// Dé-sérialise une structure de champs nommés à partir de variables
+	/// d'environnement (ex: `DATABASE_URL` pour le champ `database_url`, ou
+	/// pour le champ `url` d'un champ `database`). Une valeur fictive remplace
+	/// chaque valeur manquante ou invalide afin de poursuivre l'analyse :
+	/// lorsqu'il y en a, le rapport de ces valeurs est retourné. Retourne
+	/// également les noms des variables des valeurs [Secret](crate::Secret)
+	/// (ex: `DATABASE_PASSWORD`).
+	pub(crate) fn probe<T>(vars: &BTreeMap<String, String>, sources: &BTreeMap<String, String>)
+		-> Result<(T, Vec<String>), Self>
+	where
+		T: serde::de::DeserializeOwned,
+	{
+		let state = ProbeState {
+			vars: vars
+				.iter()
+				.filter(|(_, value)| !value.is_empty())
+				.map(|(name, value)| (name.to_lowercase(), value.clone()))
+				.collect(),
+			..Default::default()
+		};
+
+		let mut result = T::deserialize(ValueProbe::new(&state, String::new(), None));
+		while state.retry.replace(false) {
+			state.violations.borrow_mut().clear();
+			state.secret_keys.borrow_mut().clear();
+			result = T::deserialize(ValueProbe::new(&state, String::new(), None));
+		}
+
+		let violations: Vec<_> = state
+			.violations
+			.into_inner()
+			.into_iter()
+			.map(|(key, kind, expected, hint)| {
+				let variable = key.to_ascii_uppercase();
+				EnvViolation {
+					source: sources.get(&variable).cloned(),
+					variable,
+					kind,
+					expected: Some(expected),
+					hint,
+				}
+			})
+			.collect();
+
+		match result {
+			| Ok(value) if violations.is_empty() => {
+				let secret_variables = state
+					.secret_keys
+					.into_inner()
+					.iter()
+					.map(|key| key.to_ascii_uppercase())
+					.collect();
+				Ok((value, secret_variables))
+			}
+			| result => {
+				Err(Self {
+					violations,
+					error: result.err().map(|err| err.to_string()),
+				})
+			}
+		}
+	}
+
+	/// Ajoute les variables inconnues au rapport (voir [unknown_variables()]).
+	pub(crate) fn with_unknown(mut self, unknown: Vec<EnvViolation>) -> Self
+	{
+		self.violations.extend(unknown);
+		self
+	}
+
+	/// Est-ce que le rapport ne contient aucun problème de variable? Voir
+	/// également [EnvReport::error()].
+	pub fn is_empty(&self) -> bool
+	{
+		self.violations.is_empty()
+	}
+
+	/// Erreur de la dé-sérialisation, lorsqu'elle a échoué (ex: l'erreur
+	/// d'une implémentation de [serde::Deserialize] de l'application), y
+	/// compris lorsqu'elle ne correspond à aucune variable.
+	pub fn error(&self) -> Option<&str>
+	{
+		self.error.as_deref()
+	}
+
+	/// Nombre de problèmes.
+	pub fn len(&self) -> usize
+	{
+		self.violations.len()
+	}
+
+	/// Les problèmes, dans l'ordre des champs de la structure, puis les
+	/// variables inconnues.
+	pub fn iter(&self) -> impl Iterator<Item = &EnvViolation>
+	{
+		self.violations.iter()
+	}
+}
+
+impl ProbeState
+{
+	fn push(&self, key: &str, kind: EnvViolationKind, expected: impl ToString, hint: impl ToString)
+	{
+		self.violations
+			.borrow_mut()
+			.push((key.to_owned(), kind, expected.to_string(), hint.to_string()));
+	}
+
+	/// Les clés `<prefix>_<field>` définies (ou manquantes et remplacées par
+	/// une valeur fictive), et leur valeur.
+	fn keys(&self, prefix: &str, fields: impl IntoIterator<Item = String>) -> Vec<(String, String)>
+	{
+		fields
+			.into_iter()
+			.filter_map(|field| {
+				let key = if prefix.is_empty() { field.clone() } else { format!("{prefix}_{field}") };
+				let nested = format!("{key}_");
+				let exists = self.vars.contains_key(&key)
+					|| self.vars.keys().any(|name| name.starts_with(&nested))
+					|| self.placeholders.borrow().contains(&key);
+				exists.then_some((field, key))
+			})
+			.collect()
+	}
+}
+
+impl<'a> ValueProbe<'a>
+{
+	fn new(state: &'a ProbeState, key: String, value: Option<String>) -> Self
+	{
+		Self { state, key, value }
+	}
+
+	/// Convertit la valeur ; une valeur manquante ou invalide est
+	/// enregistrée et remplacée par la valeur par défaut.
+	fn parse<T>(&self, expected: &str, hint: &str) -> T
+	where
+		T: str::FromStr + Default,
+		T::Err: fmt::Display,
+	{
+		let Some(value) = self.value.as_deref() else {
+			self.missing(expected);
+			return T::default();
+		};
+
+		value.parse().unwrap_or_else(|err: T::Err| {
+			let hint = if hint.is_empty() { err.to_string() } else { hint.to_owned() };
+			self.state.push(&self.key, EnvViolationKind::Invalid, expected, hint);
+			T::default()
+		})
+	}
+
+	fn missing(&self, expected: &str)
+	{
+		let variable = self.key.to_ascii_uppercase();
+		self.state.push(
+			&self.key,
+			EnvViolationKind::Missing,
+			expected,
+			format!("définir « {variable} » ou « {variable}{SECRET_FILE_SUFFIX} »"),
+		);
+	}
+
+	fn str_value(&self, expected: &str) -> String
+	{
+		if self.value.is_none() {
+			self.missing(expected);
+		}
+		self.value.clone().unwrap_or_default()
+	}
+
+	/// Accès aux clés situées sous la clé courante.
+	fn map(self, fields: Vec<(String, String)>) -> MapProbe<'a>
+	{
+		MapProbe {
+			state: self.state,
+			keys: fields.into_iter(),
+			value: None,
+		}
+	}
+
+	/// Éléments d'une liste `a, b, c`.
+	fn seq(self, expected: &str, keep_empty: bool) -> SeqProbe<'a>
+	{
+		let value = self.str_value(expected);
+		let elements: Vec<_> = value
+			.split(',')
+			.map(|element| element.trim().to_owned())
+			.filter(|element| keep_empty || !element.is_empty())
+			.collect();
+		SeqProbe {
+			state: self.state,
+			key: self.key,
+			elements: if self.value.is_none() { Vec::new() } else { elements }.into_iter(),
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+macro_rules! probe_number {
+	($($method:ident => $visit:ident : $ty:ty),* $(,)?) => {
+		$(
+			fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+			where
+				V: Visitor<'de>,
+			{
+				visitor.$visit(self.parse::<$ty>(stringify!($ty), ""))
+			}
+		)*
+	};
+}
+
+impl<'de> serde::Deserializer<'de> for ValueProbe<'_>
+{
+	type Error = ProbeError;
+
+	probe_number! {
+		deserialize_i8 => visit_i8: i8,
+		deserialize_i16 => visit_i16: i16,
+		deserialize_i32 => visit_i32: i32,
+		deserialize_i64 => visit_i64: i64,
+		deserialize_i128 => visit_i128: i128,
+		deserialize_u8 => visit_u8: u8,
+		deserialize_u16 => visit_u16: u16,
+		deserialize_u32 => visit_u32: u32,
+		deserialize_u64 => visit_u64: u64,
+		deserialize_u128 => visit_u128: u128,
+		deserialize_f32 => visit_f32: f32,
+		deserialize_f64 => visit_f64: f64,
+	}
+
+	fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		self.deserialize_string(visitor)
+	}
+
+	fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		visitor.visit_bool(self.parse("bool", "valeurs acceptées : true, false"))
+	}
+
+	fn deserialize_char<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		visitor.visit_char(self.parse("char", "un seul caractère attendu"))
+	}
+
+	fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		visitor.visit_string(self.str_value("String"))
+	}
+
+	fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		visitor.visit_string(self.str_value("String"))
+	}
+
+	fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		visitor.visit_byte_buf(self.str_value("bytes").into_bytes())
+	}
+
+	fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		visitor.visit_byte_buf(self.str_value("bytes").into_bytes())
+	}
+
+	fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		let nested = format!("{}_", self.key);
+		let exists = self.value.is_some() || self.state.vars.keys().any(|name| name.starts_with(&nested));
+		if exists {
+			visitor.visit_some(self)
+		} else {
+			visitor.visit_none()
+		}
+	}
+
+	fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		visitor.visit_unit()
+	}
+
+	fn deserialize_unit_struct<V>(self, _: &'static str, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		visitor.visit_unit()
+	}
+
+	fn deserialize_newtype_struct<V>(self, name: &'static str, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		if name == SECRET_STRUCT_NAME {
+			self.state.secret_keys.borrow_mut().insert(self.key.clone());
+		}
+		visitor.visit_newtype_struct(self)
+	}
+
+	fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		visitor.visit_seq(self.seq("liste", false))
+	}
+
+	fn deserialize_tuple<V>(self, _: usize, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		visitor.visit_seq(self.seq("tuple", true))
+	}
+
+	fn deserialize_tuple_struct<V>(self, _: &'static str, _: usize, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		visitor.visit_seq(self.seq("tuple", true))
+	}
+
+	fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		let nested = if self.key.is_empty() { String::new() } else { format!("{}_", self.key) };
+		let fields = self
+			.state
+			.vars
+			.keys()
+			.filter_map(|name| name.strip_prefix(&nested))
+			.map(ToOwned::to_owned)
+			.collect::<Vec<_>>();
+		let key = self.key.clone();
+		let fields = self.state.keys(&key, fields);
+		visitor.visit_map(self.map(fields))
+	}
+
+	fn deserialize_struct<V>(
+		self,
+		_: &'static str,
+		fields: &'static [&'static str],
+		visitor: V,
+	) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		let (state, key) = (self.state, self.key.clone());
+		let keys = state.keys(&key, fields.iter().map(|field| field.to_string()));
+
+		visitor.visit_map(self.map(keys)).inspect_err(|err| {
+			// NOTE: un champ manquant est remplacé par une valeur fictive
+			//       lors de l'analyse suivante.
+			let ProbeError::MissingField(field) = err else {
+				return;
+			};
+			let key = if key.is_empty() { (*field).to_owned() } else { format!("{key}_{field}") };
+			if state.placeholders.borrow_mut().insert(key) {
+				state.retry.replace(true);
+			}
+		})
+	}
+
+	fn deserialize_enum<V>(
+		self,
+		name: &'static str,
+		variants: &'static [&'static str],
+		visitor: V,
+	) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		let expected = format!("{name} ({})", variants.join(", "));
+		let variant = match self.value.as_deref() {
+			| Some(value) if variants.contains(&value) => value,
+			| Some(_) => {
+				let hint = format!("valeurs acceptées : {}", variants.join(", "));
+				self.state.push(&self.key, EnvViolationKind::Invalid, expected, hint);
+				variants.first().copied().unwrap_or_default()
+			}
+			| None => {
+				self.missing(&expected);
+				variants.first().copied().unwrap_or_default()
+			}
+		};
+
+		let deserializer: StrDeserializer<'_, ProbeError> = variant.into_deserializer();
+		deserializer.deserialize_enum(name, variants, visitor)
+	}
+
+	fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		self.deserialize_string(visitor)
+	}
+
+	fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		visitor.visit_unit()
+	}
+}
+
+impl<'de> MapAccess<'de> for MapProbe<'_>
+{
+	type Error = ProbeError;
+
+	fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+	where
+		K: DeserializeSeed<'de>,
+	{
+		let Some((field, key)) = self.keys.next() else {
+			return Ok(None);
+		};
+		let value = self.state.vars.get(&key).cloned();
+		self.value.replace(ValueProbe::new(self.state, key, value));
+		seed.deserialize(field.into_deserializer()).map(Some)
+	}
+
+	fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+	where
+		V: DeserializeSeed<'de>,
+	{
+		let value = self
+			.value
+			.take()
+			.ok_or_else(|| ProbeError::custom("valeur sans clé"))?;
+		seed.deserialize(value)
+	}
+}
+
+impl<'de> SeqAccess<'de> for SeqProbe<'_>
+{
+	type Error = ProbeError;
+
+	fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+	where
+		T: DeserializeSeed<'de>,
+	{
+		let Some(element) = self.elements.next() else {
+			return Ok(None);
+		};
+		seed.deserialize(ValueProbe::new(self.state, self.key.clone(), Some(element)))
+			.map(Some)
+	}
+}
+
+impl serde::de::Error for ProbeError
+{
+	fn custom<T>(msg: T) -> Self
+	where
+		T: fmt::Display,
+	{
+		Self::Custom(msg.to_string())
+	}
+
+	fn missing_field(field: &'static str) -> Self
+	{
+		Self::MissingField(field)
+	}
+}
+
+impl std::error::Error for ProbeError {}
+
+impl fmt::Display for ProbeError
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		match self {
+			| Self::MissingField(field) => write!(f, "le champ « {field} » est manquant"),
+			| Self::Custom(reason) => write!(f, "{reason}"),
+		}
+	}
+}
+
+impl fmt::Display for EnvViolationKind
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		let kind = match self {
+			| Self::Missing => "manquante",
+			| Self::Invalid => "invalide",
+			| Self::Unknown => "inconnue",
+		};
+		write!(f, "{kind}")
+	}
+}
+
+impl fmt::Display for EnvViolation
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "« {} » {}", self.variable, self.kind)?;
+		if let Some(expected) = &self.expected {
+			write!(f, " (type attendu : {expected})")?;
+		}
+		if let Some(source) = &self.source {
+			write!(f, " [{source}]")?;
+		}
+		write!(f, " : {}", self.hint)
+	}
+}
+
+impl fmt::Display for EnvReport
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		const HEADER: [&str; 5] = ["Variable", "Problème", "Type attendu", "Source", "Indication"];
+
+		let rows: Vec<[String; 5]> = self
+			.iter()
+			.map(|violation| {
+				[
+					violation.variable.clone(),
+					violation.kind.to_string(),
+					violation.expected.clone().unwrap_or_else(|| String::from("-")),
+					violation.source.clone().unwrap_or_else(|| String::from("-")),
+					violation.hint.clone(),
+				]
+			})
+			.collect();
+
+		let mut widths = HEADER.map(|column| column.chars().count());
+		for row in rows.iter() {
+			for (width, cell) in widths.iter_mut().zip(row) {
+				*width = (*width).max(cell.chars().count());
+			}
+		}
+
+		let header = HEADER.map(String::from);
+		let table = (!rows.is_empty()).then_some(&header).into_iter().chain(rows.iter());
+		for row in table {
+			write!(f, "\n\t\t")?;
+			for (index, (cell, width)) in row.iter().zip(widths).enumerate() {
+				if index + 1 == row.len() {
+					write!(f, "{cell}")?;
+				} else {
+					write!(f, "{cell:<width$} | ")?;
+				}
+			}
+		}
+		if let Some(error) = &self.error {
+			write!(f, "\n\t\t{error}")?;
+		}
+		Ok(())
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Les variables d'environnement qui ne correspondent à aucun champ d'une
+/// structure de champs nommés :
+///
+///   - les variables préfixées du processus (`process_names`), qui ciblent
+///     la structure ;
+///   - les variables des fichiers d'environnement (`file_names`) dont le nom
+///     est proche de celui d'un champ (ex: `DATABSE_URL`). Un fichier
+///     d'environnement PEUT être partagé par plusieurs structures : les
+///     autres variables ne sont pas signalées.
+pub(crate) fn unknown_variables<'a>(
+	fields: &[&str],
+	file_names: impl IntoIterator<Item = &'a str>,
+	process_names: impl IntoIterator<Item = &'a str>,
+	sources: &BTreeMap<String, String>,
+) -> Vec<EnvViolation>
+{
+	if fields.is_empty() {
+		return Vec::new();
+	}
+
+	let fields: Vec<_> = fields.iter().map(|field| field.to_ascii_uppercase()).collect();
+	let is_known = |name: &str| {
+		let name = name.to_ascii_uppercase();
+		let name = name.strip_suffix(SECRET_FILE_SUFFIX).unwrap_or(&name);
+		fields
+			.iter()
+			.any(|field| name == field || name.starts_with(&format!("{field}_")))
+	};
+
+	let suggestion = |name: &str| {
+		fields
+			.iter()
+			.map(|field| (distance(&name.to_ascii_uppercase(), field), field))
+			.filter(|(distance, field)| *distance <= 2.max(field.len() / 3))
+			.min()
+			.map(|(_, field)| field.clone())
+	};
+
+	let file_names = file_names
+		.into_iter()
+		.filter(|name| !is_known(name))
+		.filter_map(|name| suggestion(name).map(|field| (name, Some(field))));
+	let process_names = process_names
+		.into_iter()
+		.filter(|name| !is_known(name))
+		.map(|name| (name, suggestion(name)));
+
+	let mut unknown: Vec<_> = file_names
+		.chain(process_names)
+		.map(|(name, suggestion)| {
+			EnvViolation {
+				variable: name.to_owned(),
+				kind: EnvViolationKind::Unknown,
+				expected: None,
+				source: sources.get(name).cloned(),
+				hint: match suggestion {
+					| Some(field) => format!("vouliez-vous dire « {field} » ?"),
+					| None => String::from("aucun champ ne correspond à cette variable"),
+				},
+			}
+		})
+		.collect();
+	unknown.sort_by(|left, right| left.variable.cmp(&right.variable));
+	unknown
+}
+
+/// Distance d'édition entre deux noms.
+fn distance(left: &str, right: &str) -> usize
+{
+	let right: Vec<char> = right.chars().collect();
+	let mut previous: Vec<usize> = (0..=right.len()).collect();
+
+	for (i, left_ch) in left.chars().enumerate() {
+		let mut current = vec![i + 1];
+		for (j, right_ch) in right.iter().enumerate() {
+			let substitution = previous[j] + usize::from(left_ch != *right_ch);
+			current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+		}
+		previous = current;
+	}
+
+	previous[right.len()]
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	#[derive(Debug)]
+	#[derive(PartialEq, Eq)]
+	#[derive(serde::Deserialize)]
+	struct Env
+	{
+		host: String,
+		port: u16,
+		debug: Option<bool>,
+	}
+
+	#[derive(Debug)]
+	#[derive(serde::Deserialize)]
+	struct EvenEnv
+	{
+		#[allow(dead_code)]
+		count: Even,
+	}
+
+	#[derive(Debug)]
+	struct Even;
+
+	impl<'de> serde::Deserialize<'de> for Even
+	{
+		fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+		where
+			D: serde::Deserializer<'de>,
+		{
+			match u16::deserialize(deserializer)? {
+				| count if count % 2 == 0 => Ok(Self),
+				| _ => Err(serde::de::Error::custom("nombre pair attendu")),
+			}
+		}
+	}
+
+	fn vars(vars: &[(&str, &str)]) -> BTreeMap<String, String>
+	{
+		vars.iter()
+			.map(|(name, value)| (name.to_string(), value.to_string()))
+			.collect()
+	}
+
+	#[test]
+	fn test_probe_ok()
+	{
+		let env = EnvReport::probe::<Env>(&vars(&[("HOST", "localhost"), ("PORT", "80")]), &BTreeMap::new());
+		assert_eq!(
+			env.unwrap().0,
+			Env {
+				host: String::from("localhost"),
+				port: 80,
+				debug: None,
+			}
+		);
+	}
+
+	#[test]
+	fn test_probe_missing()
+	{
+		let report = EnvReport::probe::<Env>(&vars(&[("PORT", "80"), ("DEBUG", "")]), &BTreeMap::new()).unwrap_err();
+		let violations: Vec<_> = report.iter().map(|violation| (violation.variable.as_str(), violation.kind)).collect();
+		assert_eq!(violations, [("HOST", EnvViolationKind::Missing)]);
+		assert_eq!(report.len(), 1);
+		assert_eq!(report.error(), None);
+	}
+
+	#[test]
+	fn test_probe_invalid()
+	{
+		let sources = vars(&[("PORT", ".env")]);
+		let report = EnvReport::probe::<Env>(
+			&vars(&[("HOST", "localhost"), ("PORT", "http"), ("DEBUG", "maybe")]),
+			&sources,
+		)
+		.unwrap_err();
+		assert_eq!(
+			report.iter().next(),
+			Some(&EnvViolation {
+				variable: String::from("PORT"),
+				kind: EnvViolationKind::Invalid,
+				expected: Some(String::from("u16")),
+				source: Some(String::from(".env")),
+				hint: String::from("invalid digit found in string"),
+			})
+		);
+		let variables: Vec<_> = report.iter().map(|violation| violation.variable.as_str()).collect();
+		assert_eq!(variables, ["PORT", "DEBUG"]);
+	}
+
+	#[test]
+	fn test_probe_keeps_the_original_error()
+	{
+		let report = EnvReport::probe::<EvenEnv>(&vars(&[("COUNT", "3")]), &BTreeMap::new()).unwrap_err();
+		assert!(report.is_empty());
+		assert_eq!(report.len(), 0);
+		assert_eq!(report.error(), Some("nombre pair attendu"));
+		assert!(report.to_string().contains("nombre pair attendu"));
+		assert!(EnvReport::probe::<EvenEnv>(&vars(&[("COUNT", "4")]), &BTreeMap::new()).is_ok());
+	}
+
+	#[test]
+	fn test_unknown_variables()
+	{
+		let unknown = unknown_variables(
+			&["database_url", "port"],
+			["DATABSE_URL", "OTHER_APP_KEY", "PORT", "DATABASE_URL_FILE"],
+			["VERBOSE"],
+			&vars(&[("VERBOSE", "processus (APP_VERBOSE)")]),
+		);
+		let unknown: Vec<_> = unknown
+			.iter()
+			.map(|violation| (violation.variable.as_str(), violation.kind, violation.source.as_deref()))
+			.collect();
+		assert_eq!(
+			unknown,
+			[
+				("DATABSE_URL", EnvViolationKind::Unknown, None),
+				("VERBOSE", EnvViolationKind::Unknown, Some("processus (APP_VERBOSE)")),
+			]
+		);
+	}
+}
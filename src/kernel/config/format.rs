@@ -0,0 +1,94 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::{ffi, io, path};
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Extensions de fichiers reconnues pour un format, par ordre de priorité.
+pub(super) fn file_extensions(format: lexa_fs::Extension) -> &'static [&'static str]
+{
+	match format {
+		| lexa_fs::Extension::ENV => &["env"],
+		| lexa_fs::Extension::JSON => &["json"],
+		| lexa_fs::Extension::TOML => &["toml"],
+		| lexa_fs::Extension::YAML => &["yml", "yaml"],
+	}
+}
+
+/// Format d'un fichier, à partir de son extension.
+pub(super) fn file_format(filepath: &path::Path) -> Option<lexa_fs::Extension>
+{
+	let extension = filepath.extension()?.to_str()?;
+	[
+		lexa_fs::Extension::YAML,
+		lexa_fs::Extension::JSON,
+		lexa_fs::Extension::TOML,
+		lexa_fs::Extension::ENV,
+	]
+	.into_iter()
+	.find(|format| file_extensions(*format).contains(&extension))
+}
+
+/// Les fichiers candidats d'un chemin sans extension, dans l'ordre de
+/// priorité des formats.
+pub(super) fn candidates(stem: &path::Path, formats: &[lexa_fs::Extension]) -> Vec<(path::PathBuf, lexa_fs::Extension)>
+{
+	formats
+		.iter()
+		.flat_map(|format| {
+			file_extensions(*format).iter().map(move |extension| {
+				// NOTE: `Path::with_extension` remplacerait l'extension d'un
+				//       chemin tel que `config/logger.local`.
+				let mut filepath = ffi::OsString::from(stem.as_os_str());
+				filepath.push(".");
+				filepath.push(extension);
+				(path::PathBuf::from(filepath), *format)
+			})
+		})
+		.collect()
+}
+
+/// Le fichier existant d'un chemin sans extension, parmi les formats donnés.
+/// Retourne une erreur lorsque plusieurs candidats existent.
+pub(super) fn probe(
+	stem: &path::Path,
+	formats: &[lexa_fs::Extension],
+) -> io::Result<Option<(path::PathBuf, lexa_fs::Extension)>>
+{
+	let mut existing = candidates(stem, formats)
+		.into_iter()
+		.filter(|(filepath, _)| filepath.is_file());
+
+	let Some(found) = existing.next() else {
+		return Ok(None);
+	};
+
+	let conflicts = existing
+		.map(|(filepath, _)| filepath.display().to_string())
+		.collect::<Vec<_>>();
+
+	if !conflicts.is_empty() {
+		return Err(io::Error::new(
+			io::ErrorKind::InvalidData,
+			format!(
+				"plusieurs fichiers de configuration existent pour « {} » : « {} », « {} ». Un seul format DOIT être \
+				 utilisé.",
+				stem.display(),
+				found.0.display(),
+				conflicts.join(" », « ")
+			),
+		));
+	}
+
+	Ok(Some(found))
+}
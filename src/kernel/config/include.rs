@@ -0,0 +1,155 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::{io, path};
+
+use super::{format, loader};
+use crate::kernel::settings::KernelSettings;
+use crate::process::ProcessMode;
+
+// -------- //
+// Constant //
+// -------- //
+
+/// Directive d'inclusion d'autres fichiers de configuration.
+pub const CONFIG_INCLUDE_DIRECTIVE: &str = "$include";
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Lit un fichier de configuration ainsi que les fichiers qu'il inclut via la
+/// directive [CONFIG_INCLUDE_DIRECTIVE] :
+///
+/// ```text
+/// $include: ["common/db.yml", "common/cache"]
+/// ```
+///
+/// Les chemins sont relatifs au répertoire de configuration. Lorsque le mode
+/// d'exécution n'est pas `local`, un fichier inclus est d'abord recherché
+/// dans le répertoire du mode (ex: `config/dev/common/db.yml`). Sans
+/// extension, le format du fichier est détecté (voir
+/// [resolve_config_layers()](super::resolve_config_layers)).
+///
+/// Retourne les fichiers lus (sans la directive), dans leur ordre de fusion :
+/// les fichiers inclus, dans l'ordre de déclaration et récursivement, puis le
+/// fichier lui-même. Les inclusions circulaires et les fichiers inclus
+/// introuvables sont des erreurs [io::ErrorKind::InvalidData] : la
+/// configuration est invalide, elle n'est pas absente.
+pub(super) fn read_with_includes(
+	filepath: &path::Path,
+	settings: &KernelSettings,
+) -> io::Result<Vec<(path::PathBuf, serde_json::Value)>>
+{
+	let mut files = Vec::new();
+	read(filepath, settings, &mut Vec::new(), &mut files)?;
+	Ok(files)
+}
+
+fn read(
+	filepath: &path::Path,
+	settings: &KernelSettings,
+	stack: &mut Vec<path::PathBuf>,
+	files: &mut Vec<(path::PathBuf, serde_json::Value)>,
+) -> io::Result<()>
+{
+	let canonical_filepath = filepath.canonicalize().unwrap_or_else(|_| filepath.to_owned());
+
+	if let Some(position) = stack.iter().position(|included| *included == canonical_filepath) {
+		let cycle = stack[position..]
+			.iter()
+			.chain(Some(&canonical_filepath))
+			.map(|included| included.display().to_string())
+			.collect::<Vec<_>>();
+		return Err(io::Error::new(
+			io::ErrorKind::InvalidData,
+			format!("inclusion circulaire « {} »", cycle.join(" -> ")),
+		));
+	}
+
+	let extension = format::file_format(filepath).unwrap_or(settings.loader_extension);
+	let mut value = loader::read(filepath, extension)?;
+
+	let includes = match value.as_object_mut().and_then(|map| map.remove(CONFIG_INCLUDE_DIRECTIVE)) {
+		| None => Vec::new(),
+		| Some(serde_json::Value::String(include)) => vec![include],
+		| Some(serde_json::Value::Array(includes)) => {
+			includes
+				.into_iter()
+				.map(|include| {
+					match include {
+						| serde_json::Value::String(include) => Ok(include),
+						| include => Err(invalid_directive(filepath, &include)),
+					}
+				})
+				.collect::<io::Result<_>>()?
+		}
+		| Some(include) => return Err(invalid_directive(filepath, &include)),
+	};
+
+	stack.push(canonical_filepath);
+	for include in includes {
+		let include_filepath = resolve(&include, settings)?.ok_or_else(|| {
+			io::Error::new(
+				io::ErrorKind::InvalidData,
+				format!(
+					"« {} » : le fichier inclus « {include} » n'existe pas.",
+					filepath.display()
+				),
+			)
+		})?;
+		read(&include_filepath, settings, stack, files)?;
+	}
+	stack.pop();
+
+	files.push((filepath.to_owned(), value));
+
+	Ok(())
+}
+
+/// Chemin d'un fichier inclus, en fonction du mode d'exécution.
+fn resolve(include: &str, settings: &KernelSettings) -> io::Result<Option<path::PathBuf>>
+{
+	let Some(config_directory) = settings.directory.config() else {
+		return Ok(None);
+	};
+
+	let mode_directory = (settings.process_mode != ProcessMode::LOCAL)
+		.then(|| config_directory.join(settings.process_mode.short_name()));
+
+	for directory in mode_directory.into_iter().chain(Some(config_directory.to_owned())) {
+		let filepath = directory.join(include);
+
+		if format::file_format(&filepath).is_some() {
+			if filepath.is_file() {
+				return Ok(Some(filepath));
+			}
+			continue;
+		}
+
+		if let Some((filepath, _)) = format::probe(&filepath, &settings.loader_extensions)? {
+			return Ok(Some(filepath));
+		}
+	}
+
+	Ok(None)
+}
+
+fn invalid_directive(filepath: &path::Path, include: &serde_json::Value) -> io::Error
+{
+	io::Error::new(
+		io::ErrorKind::InvalidData,
+		format!(
+			"« {} » : la directive « {CONFIG_INCLUDE_DIRECTIVE} » attend un chemin ou une liste de chemins, \
+			 obtenu {include}.",
+			filepath.display()
+		),
+	)
+}
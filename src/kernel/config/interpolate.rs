@@ -0,0 +1,490 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::{fmt, path};
+
+use super::{load_config, lookup, LoadedConfig};
+use crate::kernel::settings::KernelSettings;
+
+// -------- //
+// Constant //
+// -------- //
+
+/// Préfixe des références vers une clé d'une configuration.
+const CONFIG_REFERENCE_PREFIX: &str = "config:";
+
+/// Séparateur de la valeur par défaut d'une variable d'environnement.
+const ENV_DEFAULT_SEPARATOR: &str = ":-";
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Erreur d'interpolation d'une référence `${...}` d'une configuration.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct InterpolationError
+{
+	/// Fichier contenant la référence, lorsqu'il a pu être déterminé.
+	pub file: Option<path::PathBuf>,
+	/// Chemin de la clé contenant la référence (ex: `my-config.server.host`).
+	pub key: String,
+	/// Raison de l'erreur.
+	pub reason: String,
+}
+
+/// Interpole les références `${...}` des configurations.
+pub(super) struct Interpolator<'a>
+{
+	settings: &'a KernelSettings,
+	/// Configurations chargées, par nom.
+	configs: HashMap<String, LoadedConfig>,
+	/// Clés en cours d'interpolation, afin de détecter les cycles.
+	stack: Vec<String>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<'a> Interpolator<'a>
+{
+	pub(super) fn new(settings: &'a KernelSettings) -> Self
+	{
+		Self {
+			settings,
+			configs: Default::default(),
+			stack: Default::default(),
+		}
+	}
+
+	/// Interpole les références de la valeur fusionnée d'une configuration.
+	pub(super) fn interpolate(
+		&mut self,
+		config_name: &str,
+		loaded_config: LoadedConfig,
+	) -> Result<serde_json::Value, InterpolationError>
+	{
+		let mut value = loaded_config.value.clone();
+		self.configs.insert(config_name.to_owned(), loaded_config);
+		self.walk(config_name, &mut value, &mut Vec::new())?;
+		Ok(value)
+	}
+
+	/// Les configurations chargées : la configuration interpolée et celles
+	/// qu'elle référence.
+	pub(super) fn loaded_configs(&self) -> impl Iterator<Item = (&str, &LoadedConfig)>
+	{
+		self.configs.iter().map(|(config_name, loaded_config)| (config_name.as_str(), loaded_config))
+	}
+
+	fn walk(
+		&mut self,
+		config_name: &str,
+		value: &mut serde_json::Value,
+		path: &mut Vec<String>,
+	) -> Result<(), InterpolationError>
+	{
+		match value {
+			| serde_json::Value::Object(map) => {
+				for (key, value) in map.iter_mut() {
+					path.push(key.clone());
+					self.walk(config_name, value, path)?;
+					path.pop();
+				}
+			}
+			| serde_json::Value::Array(items) => {
+				for (index, value) in items.iter_mut().enumerate() {
+					path.push(index.to_string());
+					self.walk(config_name, value, path)?;
+					path.pop();
+				}
+			}
+			| serde_json::Value::String(string) if string.contains('$') && !self.is_literal(config_name, path) => {
+				let string = string.clone();
+				*value = self.expand(config_name, path, &string)?;
+			}
+			| _ => {}
+		}
+
+		Ok(())
+	}
+
+	/// Est-ce que la clé a été définie par une source ou une variable
+	/// d'environnement de surcharge? Voir [LoadedConfig::literal_paths].
+	fn is_literal(&self, config_name: &str, path: &[String]) -> bool
+	{
+		self.configs.get(config_name).is_some_and(|loaded_config| {
+			loaded_config
+				.literal_paths
+				.iter()
+				.any(|literal_path| path.starts_with(literal_path))
+		})
+	}
+
+	/// Interpole une chaîne de caractères située à une clé d'une
+	/// configuration.
+	fn expand(&mut self, config_name: &str, path: &[String], input: &str)
+		-> Result<serde_json::Value, InterpolationError>
+	{
+		let location = Self::location(config_name, path);
+
+		if let Some(position) = self.stack.iter().position(|key| *key == location) {
+			let mut cycle = self.stack[position..].to_vec();
+			cycle.push(location);
+			return Err(self.error(
+				config_name,
+				path,
+				format!("référence circulaire « {} »", cycle.join(" -> ")),
+			));
+		}
+
+		self.stack.push(location);
+		let value = self.expand_str(config_name, path, input);
+		self.stack.pop();
+		value
+	}
+
+	fn expand_str(&mut self, config_name: &str, path: &[String], input: &str)
+		-> Result<serde_json::Value, InterpolationError>
+	{
+		let mut output = String::new();
+		let mut rest = input;
+
+		while let Some(start) = rest.find('$') {
+			output.push_str(&rest[..start]);
+			rest = &rest[start..];
+
+			if let Some(after) = rest.strip_prefix("$${") {
+				output.push_str("${");
+				rest = after;
+				continue;
+			}
+
+			let Some(after) = rest.strip_prefix("${") else {
+				output.push('$');
+				rest = &rest[1..];
+				continue;
+			};
+
+			let Some(end) = closing_brace(after) else {
+				return Err(self.error(config_name, path, format!("référence non terminée « {rest} »")));
+			};
+
+			let reference = &after[..end];
+			let value = self.resolve(config_name, path, reference)?;
+			rest = &after[end + 1..];
+
+			// NOTE: une chaîne ne contenant qu'une seule référence conserve
+			//       le type de la valeur référencée.
+			if output.is_empty() && rest.is_empty() {
+				return Ok(value);
+			}
+
+			match value {
+				| serde_json::Value::Null => {}
+				| serde_json::Value::String(string) => output.push_str(&string),
+				| value => output.push_str(&value.to_string()),
+			}
+		}
+
+		output.push_str(rest);
+		Ok(serde_json::Value::String(output))
+	}
+
+	/// Résout une référence, sans les délimiteurs `${` et `}`.
+	fn resolve(&mut self, config_name: &str, path: &[String], reference: &str)
+		-> Result<serde_json::Value, InterpolationError>
+	{
+		if let Some(target) = reference.strip_prefix(CONFIG_REFERENCE_PREFIX) {
+			return self.resolve_config(config_name, path, target.trim());
+		}
+
+		let (name, default) = match reference.split_once(ENV_DEFAULT_SEPARATOR) {
+			| Some((name, default)) => (name.trim(), Some(default)),
+			| None => (reference.trim(), None),
+		};
+
+		match (std::env::var(name), default) {
+			| (Ok(value), None) => Ok(serde_json::Value::String(value)),
+			| (Ok(value), Some(_)) if !value.is_empty() => Ok(serde_json::Value::String(value)),
+			| (_, Some(default)) => self.expand_str(config_name, path, default),
+			| (Err(_), None) => {
+				Err(self.error(
+					config_name,
+					path,
+					format!("la variable d'environnement « {name} » n'est pas définie"),
+				))
+			}
+		}
+	}
+
+	/// Résout une référence `config:<config_name>.<key>`.
+	fn resolve_config(&mut self, config_name: &str, path: &[String], target: &str)
+		-> Result<serde_json::Value, InterpolationError>
+	{
+		let (target_name, target_key) = target.split_once('.').unwrap_or((target, ""));
+		let target_path = target_key
+			.split('.')
+			.filter(|segment| !segment.is_empty())
+			.map(String::from)
+			.collect::<Vec<_>>();
+
+		if !self.configs.contains_key(target_name) {
+			match load_config(target_name, self.settings) {
+				| Ok(loaded_config) => {
+					self.configs.insert(target_name.to_owned(), loaded_config);
+				}
+				| Err(err) => {
+					return Err(self.error(
+						config_name,
+						path,
+						format!("la référence « {target} » n'a pas pu être résolue : {err}"),
+					));
+				}
+			}
+		}
+
+		let Some(mut value) = lookup(&self.configs[target_name].value, &target_path).cloned() else {
+			return Err(self.error(
+				config_name,
+				path,
+				format!("la clé « {target} » n'existe pas"),
+			));
+		};
+
+		if self.settings.config.has_interpolation(target_name) {
+			self.walk(target_name, &mut value, &mut target_path.clone())?;
+		}
+
+		Ok(value)
+	}
+
+	/// Erreur située à une clé d'une configuration. Le fichier est le plus
+	/// prioritaire des fichiers qui définissent la clé.
+	fn error(&self, config_name: &str, path: &[String], reason: impl ToString) -> InterpolationError
+	{
+		let file = self.configs.get(config_name).and_then(|loaded_config| {
+			loaded_config
+				.layers
+				.iter()
+				.rev()
+				.find(|layer| lookup(&layer.value, path).is_some())
+				.and_then(|layer| layer.file.clone())
+		});
+
+		InterpolationError {
+			file,
+			key: Self::location(config_name, path),
+			reason: reason.to_string(),
+		}
+	}
+
+	fn location(config_name: &str, path: &[String]) -> String
+	{
+		std::iter::once(config_name)
+			.chain(path.iter().map(String::as_str))
+			.collect::<Vec<_>>()
+			.join(".")
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl fmt::Display for InterpolationError
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		if let Some(file) = &self.file {
+			write!(f, "« {} » : ", file.display())?;
+		}
+		write!(f, "{}: {}", self.key, self.reason)
+	}
+}
+
+impl std::error::Error for InterpolationError
+{
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Position de l'accolade fermante d'une référence, en tenant compte des
+/// références imbriquées (ex: `${A:-${B}}`).
+fn closing_brace(input: &str) -> Option<usize>
+{
+	let mut depth = 0;
+
+	for (index, ch) in input.char_indices() {
+		match ch {
+			| '{' => depth += 1,
+			| '}' if depth == 0 => return Some(index),
+			| '}' => depth -= 1,
+			| _ => {}
+		}
+	}
+
+	None
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::fs;
+
+	use serde_json::json;
+
+	use super::*;
+	use crate::kernel::config::fetch_config;
+	use crate::process::ProcessMode;
+	use crate::KeyValueFileConfigSource;
+
+	fn settings(config_directory: &path::Path) -> KernelSettings
+	{
+		let mut settings = KernelSettings::new(config_directory, ProcessMode::LOCAL);
+		settings.directory.set_config_directory(config_directory);
+		settings
+	}
+
+	fn interpolate(
+		settings: &KernelSettings,
+		value: serde_json::Value,
+		literal_paths: &[&[&str]],
+	) -> Result<serde_json::Value, InterpolationError>
+	{
+		let loaded_config = LoadedConfig {
+			layers: Vec::new(),
+			value,
+			lenient_paths: Vec::new(),
+			literal_paths: literal_paths
+				.iter()
+				.map(|path| path.iter().map(ToString::to_string).collect())
+				.collect(),
+			files: Vec::new(),
+		};
+		Interpolator::new(settings).interpolate("app", loaded_config)
+	}
+
+	#[test]
+	fn test_interpolate_env()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		let settings = settings(dir.path());
+		let path = std::env::var("PATH").unwrap();
+
+		assert_eq!(
+			interpolate(
+				&settings,
+				json!({
+					"path": "${PATH}",
+					"prefixed": "bin=${PATH}!",
+					"default": "${LEXA_KERNEL_UNDEFINED_VARIABLE:-fallback}",
+					"nested": "${LEXA_KERNEL_UNDEFINED_VARIABLE:-${PATH}}",
+					"escaped": "$${PATH}",
+					"dollar": "cost: 5$",
+				}),
+				&[]
+			)
+			.unwrap(),
+			json!({
+				"path": path,
+				"prefixed": format!("bin={path}!"),
+				"default": "fallback",
+				"nested": path,
+				"escaped": "${PATH}",
+				"dollar": "cost: 5$",
+			})
+		);
+	}
+
+	#[test]
+	fn test_interpolate_config_references()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		fs::write(dir.path().join("db.yml"), "port: 5432\nhost: ${config:db.name}.local\nname: db\n").unwrap();
+		let settings = settings(dir.path());
+
+		assert_eq!(
+			interpolate(
+				&settings,
+				json!({
+					"port": "${config:db.port}",
+					"url": "postgres://${config:db.host}:${config:db.port}",
+					"self": "${config:app.port}",
+				}),
+				&[]
+			)
+			.unwrap(),
+			json!({
+				"port": 5432,
+				"url": "postgres://db.local:5432",
+				"self": 5432,
+			})
+		);
+	}
+
+	#[test]
+	fn test_interpolate_errors()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		let settings = settings(dir.path());
+
+		let err = interpolate(&settings, json!({ "a": "${config:app.b}", "b": "${config:app.a}" }), &[]).unwrap_err();
+		assert!(err.reason.contains("référence circulaire"), "{err}");
+
+		let err = interpolate(&settings, json!({ "a": { "b": "${LEXA_KERNEL_UNDEFINED_VARIABLE}" } }), &[]).unwrap_err();
+		assert_eq!(err.key, "app.a.b");
+
+		let err = interpolate(&settings, json!({ "a": "${config:app.missing}" }), &[]).unwrap_err();
+		assert_eq!(err.key, "app.a");
+
+		let err = interpolate(&settings, json!({ "a": "${PATH" }), &[]).unwrap_err();
+		assert!(err.reason.contains("non terminée"), "{err}");
+	}
+
+	#[test]
+	fn test_interpolate_skips_literal_values()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		let settings = settings(dir.path());
+
+		assert_eq!(
+			interpolate(
+				&settings,
+				json!({ "password": "pa$${word}", "db": { "url": "${LEXA_KERNEL_UNDEFINED_VARIABLE}" }, "home": "$${}" }),
+				&[&["password"], &["db"]]
+			)
+			.unwrap(),
+			json!({ "password": "pa$${word}", "db": { "url": "${LEXA_KERNEL_UNDEFINED_VARIABLE}" }, "home": "${}" })
+		);
+	}
+
+	#[test]
+	fn test_fetch_config_does_not_interpolate_sources()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		fs::write(dir.path().join("db.yml"), "user: ${LEXA_KERNEL_UNDEFINED_VARIABLE:-admin}\n").unwrap();
+		fs::write(dir.path().join("config.properties"), "db.password = p${ss}word\n").unwrap();
+		let mut settings = settings(dir.path());
+		settings
+			.config
+			.add_source(KeyValueFileConfigSource::new(dir.path().join("config.properties")));
+
+		assert_eq!(
+			fetch_config::<serde_json::Value>("db", &settings).unwrap().0,
+			json!({ "user": "admin", "password": "p${ss}word" })
+		);
+	}
+}
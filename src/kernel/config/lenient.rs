@@ -0,0 +1,502 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::cell::RefCell;
+
+use serde::de::{self, Error as _};
+use serde_json::Value;
+
+use crate::kernel::secret::SECRET_STRUCT_NAME;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Dé-sérialiseur d'une valeur de configuration dont les chaînes de
+/// caractères définies par les variables d'environnement de surcharge sont
+/// converties dans le type attendu (ex: `"20"` pour un `u16`, `"true"` pour
+/// un `bool`, `"a,b"` ou `"[a, b]"` pour une liste). Les autres valeurs sont
+/// dé-sérialisées telles quelles.
+///
+/// Les chemins des valeurs [Secret](crate::Secret) dé-sérialisées sont
+/// enregistrés.
+struct LenientValue<'a>
+{
+	value: Value,
+	/// Chemin de la valeur dans la configuration.
+	path: Vec<String>,
+	/// Chemins des clés définies par les variables d'environnement.
+	lenient_paths: &'a [Vec<String>],
+	/// Chemins des valeurs secrètes dé-sérialisées.
+	secret_paths: &'a RefCell<Vec<Vec<String>>>,
+	/// La valeur est issue d'une chaîne convertie.
+	lenient_subtree: bool,
+}
+
+struct LenientSeq<'a>
+{
+	items: std::iter::Enumerate<std::vec::IntoIter<Value>>,
+	parent: LenientParent<'a>,
+}
+
+struct LenientMap<'a>
+{
+	entries: serde_json::map::IntoIter,
+	value: Option<(String, Value)>,
+	parent: LenientParent<'a>,
+}
+
+struct LenientParent<'a>
+{
+	path: Vec<String>,
+	lenient_paths: &'a [Vec<String>],
+	secret_paths: &'a RefCell<Vec<Vec<String>>>,
+	lenient_subtree: bool,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<'a> LenientValue<'a>
+{
+	fn is_lenient(&self) -> bool
+	{
+		self.lenient_subtree || self.lenient_paths.contains(&self.path)
+	}
+
+	/// La chaîne de caractères à convertir, lorsque la valeur en est une.
+	fn lenient_str(&self) -> Option<&str>
+	{
+		match &self.value {
+			| Value::String(raw_value) if self.is_lenient() => Some(raw_value.trim()),
+			| _ => None,
+		}
+	}
+
+	fn parent(&self) -> LenientParent<'a>
+	{
+		LenientParent {
+			path: self.path.clone(),
+			lenient_paths: self.lenient_paths,
+			secret_paths: self.secret_paths,
+			lenient_subtree: self.lenient_subtree,
+		}
+	}
+
+	/// Convertit une chaîne en liste (`[a, b]` ou `a,b`) ou en objet
+	/// (`{ a: 1 }`), lorsque la valeur en est une.
+	fn parse_collection(mut self, is_seq: bool) -> Result<Self, serde_json::Error>
+	{
+		let Some(raw_value) = self.lenient_str() else {
+			return Ok(self);
+		};
+
+		self.value = if is_seq && !raw_value.starts_with('[') {
+			Value::Array(
+				raw_value
+					.split(',')
+					.map(str::trim)
+					.filter(|item| !item.is_empty())
+					.map(|item| Value::String(item.to_owned()))
+					.collect(),
+			)
+		} else {
+			serde_yaml::from_str(raw_value).map_err(|err| serde_json::Error::custom(format!("« {raw_value} » : {err}")))?
+		};
+		self.lenient_subtree = true;
+		Ok(self)
+	}
+
+	fn parse<T>(raw_value: &str) -> Result<T, serde_json::Error>
+	where
+		T: std::str::FromStr,
+		T::Err: std::fmt::Display,
+	{
+		raw_value
+			.parse()
+			.map_err(|err| serde_json::Error::custom(format!("« {raw_value} » : {err}")))
+	}
+}
+
+impl LenientParent<'_>
+{
+	fn child(&self, segment: String, value: Value) -> LenientValue<'_>
+	{
+		let mut path = self.path.clone();
+		path.push(segment);
+		LenientValue {
+			value,
+			path,
+			lenient_paths: self.lenient_paths,
+			secret_paths: self.secret_paths,
+			lenient_subtree: self.lenient_subtree,
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+macro_rules! deserialize_parsed {
+	($($method:ident => $visit:ident,)*) => {
+		$(
+			fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+			where
+				V: de::Visitor<'de>,
+			{
+				match self.lenient_str() {
+					| Some(raw_value) => visitor.$visit(Self::parse(raw_value)?),
+					| None => self.value.$method(visitor),
+				}
+			}
+		)*
+	};
+}
+
+impl<'de> de::Deserializer<'de> for LenientValue<'_>
+{
+	type Error = serde_json::Error;
+
+	deserialize_parsed! {
+		deserialize_i8 => visit_i8,
+		deserialize_i16 => visit_i16,
+		deserialize_i32 => visit_i32,
+		deserialize_i64 => visit_i64,
+		deserialize_i128 => visit_i128,
+		deserialize_u8 => visit_u8,
+		deserialize_u16 => visit_u16,
+		deserialize_u32 => visit_u32,
+		deserialize_u64 => visit_u64,
+		deserialize_u128 => visit_u128,
+		deserialize_f32 => visit_f32,
+		deserialize_f64 => visit_f64,
+	}
+
+	fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: de::Visitor<'de>,
+	{
+		let parent = self.parent();
+		match self.value {
+			| Value::Array(items) => {
+				visitor.visit_seq(LenientSeq {
+					items: items.into_iter().enumerate(),
+					parent,
+				})
+			}
+			| Value::Object(entries) => {
+				visitor.visit_map(LenientMap {
+					entries: entries.into_iter(),
+					value: None,
+					parent,
+				})
+			}
+			| value => value.deserialize_any(visitor),
+		}
+	}
+
+	fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: de::Visitor<'de>,
+	{
+		let Some(raw_value) = self.lenient_str() else {
+			return self.value.deserialize_bool(visitor);
+		};
+		match raw_value.to_ascii_lowercase().as_str() {
+			| "true" | "1" | "yes" | "on" => visitor.visit_bool(true),
+			| "false" | "0" | "no" | "off" => visitor.visit_bool(false),
+			| _ => Err(Self::Error::invalid_value(de::Unexpected::Str(raw_value), &visitor)),
+		}
+	}
+
+	fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: de::Visitor<'de>,
+	{
+		match &self.value {
+			| Value::Null => visitor.visit_none(),
+			| _ if self.lenient_str().is_some_and(str::is_empty) => visitor.visit_none(),
+			| _ => visitor.visit_some(self),
+		}
+	}
+
+	fn deserialize_newtype_struct<V>(self, name: &'static str, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: de::Visitor<'de>,
+	{
+		if name == SECRET_STRUCT_NAME {
+			self.secret_paths.borrow_mut().push(self.path.clone());
+		}
+		visitor.visit_newtype_struct(self)
+	}
+
+	fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: de::Visitor<'de>,
+	{
+		self.parse_collection(true)?.deserialize_any(visitor)
+	}
+
+	fn deserialize_tuple<V>(self, _: usize, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: de::Visitor<'de>,
+	{
+		self.deserialize_seq(visitor)
+	}
+
+	fn deserialize_tuple_struct<V>(self, _: &'static str, _: usize, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: de::Visitor<'de>,
+	{
+		self.deserialize_seq(visitor)
+	}
+
+	fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: de::Visitor<'de>,
+	{
+		self.parse_collection(false)?.deserialize_any(visitor)
+	}
+
+	fn deserialize_struct<V>(
+		self,
+		_: &'static str,
+		_: &'static [&'static str],
+		visitor: V,
+	) -> Result<V::Value, Self::Error>
+	where
+		V: de::Visitor<'de>,
+	{
+		self.deserialize_map(visitor)
+	}
+
+	fn deserialize_enum<V>(
+		self,
+		name: &'static str,
+		variants: &'static [&'static str],
+		visitor: V,
+	) -> Result<V::Value, Self::Error>
+	where
+		V: de::Visitor<'de>,
+	{
+		self.value.deserialize_enum(name, variants, visitor)
+	}
+
+	serde::forward_to_deserialize_any! {
+		char str string bytes byte_buf unit unit_struct identifier ignored_any
+	}
+}
+
+impl<'de> de::SeqAccess<'de> for LenientSeq<'_>
+{
+	type Error = serde_json::Error;
+
+	fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+	where
+		T: de::DeserializeSeed<'de>,
+	{
+		let Some((index, item)) = self.items.next() else {
+			return Ok(None);
+		};
+		seed.deserialize(self.parent.child(index.to_string(), item)).map(Some)
+	}
+
+	fn size_hint(&self) -> Option<usize>
+	{
+		Some(self.items.len())
+	}
+}
+
+impl<'de> de::MapAccess<'de> for LenientMap<'_>
+{
+	type Error = serde_json::Error;
+
+	fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+	where
+		K: de::DeserializeSeed<'de>,
+	{
+		let Some((key, value)) = self.entries.next() else {
+			return Ok(None);
+		};
+		let deserialized_key = seed.deserialize(de::value::StrDeserializer::<Self::Error>::new(&key))?;
+		self.value.replace((key, value));
+		Ok(Some(deserialized_key))
+	}
+
+	fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+	where
+		V: de::DeserializeSeed<'de>,
+	{
+		let (key, value) = self
+			.value
+			.take()
+			.ok_or_else(|| Self::Error::custom("valeur sans clé"))?;
+		seed.deserialize(self.parent.child(key, value))
+	}
+
+	fn size_hint(&self) -> Option<usize>
+	{
+		Some(self.entries.len())
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Dé-sérialise une valeur de configuration, en convertissant les chaînes de
+/// caractères des clés définies par les variables d'environnement de
+/// surcharge (voir [fetch_config()](super::fetch_config)) dans le type
+/// attendu. Retourne également les chemins des valeurs
+/// [Secret](crate::Secret) dé-sérialisées.
+pub(super) fn from_value<O>(value: Value, lenient_paths: &[Vec<String>])
+	-> Result<(O, Vec<Vec<String>>), serde_json::Error>
+where
+	O: serde::de::DeserializeOwned,
+{
+	let secret_paths = RefCell::default();
+	let output = O::deserialize(LenientValue {
+		value,
+		path: Vec::new(),
+		lenient_paths,
+		secret_paths: &secret_paths,
+		lenient_subtree: false,
+	})?;
+	Ok((output, secret_paths.into_inner()))
+}
+
+#[cfg(test)]
+mod tests
+{
+	use serde_json::json;
+
+	use super::*;
+
+	#[derive(Debug)]
+	#[derive(PartialEq)]
+	#[derive(serde::Deserialize)]
+	struct Database
+	{
+		pass: String,
+		port: u16,
+		debug: bool,
+		timeout: Option<f64>,
+		hosts: Vec<String>,
+		tls: Tls,
+	}
+
+	#[derive(Debug)]
+	#[derive(PartialEq)]
+	#[derive(serde::Deserialize)]
+	struct Tls
+	{
+		enabled: bool,
+	}
+
+	fn paths(paths: &[&str]) -> Vec<Vec<String>>
+	{
+		paths
+			.iter()
+			.map(|path| path.split('.').map(ToOwned::to_owned).collect())
+			.collect()
+	}
+
+	#[test]
+	fn test_lenient_paths_are_converted()
+	{
+		let config = json!({
+			"pass": "12345",
+			"port": "5432",
+			"debug": "yes",
+			"timeout": "",
+			"hosts": "a, b",
+			"tls": { "enabled": "off" },
+		});
+		let (database, _): (Database, _) = from_value(
+			config,
+			&paths(&["pass", "port", "debug", "timeout", "hosts", "tls.enabled"]),
+		)
+		.unwrap();
+		assert_eq!(
+			database,
+			Database {
+				pass: "12345".into(),
+				port: 5432,
+				debug: true,
+				timeout: None,
+				hosts: vec!["a".into(), "b".into()],
+				tls: Tls { enabled: false },
+			}
+		);
+	}
+
+	#[test]
+	fn test_lenient_collections()
+	{
+		let (hosts, _): (Vec<u16>, _) = from_value(json!("[1, 2]"), &[Vec::new()]).unwrap();
+		assert_eq!(hosts, [1, 2]);
+		let (timeout, _): (Option<f64>, _) = from_value(json!("1.5"), &[Vec::new()]).unwrap();
+		assert_eq!(timeout, Some(1.5));
+		let (pass, _): (String, _) = from_value(json!("007"), &[Vec::new()]).unwrap();
+		assert_eq!(pass, "007");
+	}
+
+	#[test]
+	fn test_other_paths_are_strict()
+	{
+		let config = json!({
+			"pass": "x",
+			"port": "5432",
+			"debug": true,
+			"timeout": null,
+			"hosts": [],
+			"tls": { "enabled": true },
+		});
+		assert!(from_value::<Database>(config.clone(), &[]).is_err());
+		assert!(from_value::<Database>(config, &paths(&["port"])).is_ok());
+	}
+
+	#[test]
+	fn test_invalid_values()
+	{
+		let err = from_value::<u16>(json!("abc"), &[Vec::new()]).unwrap_err();
+		assert!(err.to_string().contains("abc"), "{err}");
+		assert!(from_value::<bool>(json!("maybe"), &[Vec::new()]).is_err());
+		assert!(from_value::<u8>(json!("300"), &[Vec::new()]).is_err());
+	}
+
+	#[test]
+	fn test_secret_paths()
+	{
+		#[derive(serde::Deserialize)]
+		struct Credentials
+		{
+			user: String,
+			pass: crate::Secret<String>,
+			port: Option<crate::Secret<u16>>,
+			tokens: Vec<crate::Secret<String>>,
+		}
+
+		let config = json!({
+			"user": "admin",
+			"pass": "hunter2",
+			"port": "5432",
+			"tokens": ["a", "b"],
+		});
+		let (credentials, secret_paths): (Credentials, _) = from_value(config, &paths(&["port"])).unwrap();
+		assert_eq!(credentials.user, "admin");
+		assert_eq!(credentials.pass.expose(), "hunter2");
+		assert_eq!(credentials.port.map(|port| *port.expose()), Some(5432));
+		assert_eq!(credentials.tokens.len(), 2);
+		assert_eq!(secret_paths, paths(&["pass", "port", "tokens.0", "tokens.1"]));
+	}
+}
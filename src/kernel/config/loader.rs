@@ -0,0 +1,62 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::{fs, io, path};
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Lit un fichier de configuration et le dé-sérialise en une valeur
+/// générique, en fonction de son extension.
+pub(crate) fn read(filepath: &path::Path, extension: lexa_fs::Extension) -> io::Result<serde_json::Value>
+{
+	let content = fs::read_to_string(filepath)?;
+	parse(&content, extension).map_err(|err| {
+		io::Error::new(
+			io::ErrorKind::InvalidData,
+			format!("« {} » : {err}", filepath.display()),
+		)
+	})
+}
+
+/// Dé-sérialise le contenu d'un fichier de configuration en une valeur
+/// générique, en fonction de son extension.
+pub(crate) fn parse(content: &str, extension: lexa_fs::Extension) -> io::Result<serde_json::Value>
+{
+	let invalid_data = |err: String| io::Error::new(io::ErrorKind::InvalidData, err);
+
+	match extension {
+		| lexa_fs::Extension::ENV => Ok(parse_env(content)),
+		| lexa_fs::Extension::JSON => serde_json::from_str(content).map_err(|err| invalid_data(err.to_string())),
+		| lexa_fs::Extension::TOML => toml::from_str(content).map_err(|err| invalid_data(err.to_string())),
+		| lexa_fs::Extension::YAML => {
+			// NOTE: un fichier YAML vide est valide, mais ne contient aucune
+			//       valeur.
+			if content.trim().is_empty() {
+				return Ok(serde_json::Value::Null);
+			}
+			serde_yaml::from_str(content).map_err(|err| invalid_data(err.to_string()))
+		}
+	}
+}
+
+/// Analyse les déclarations `VAR=[value]` d'un fichier d'environnement.
+fn parse_env(content: &str) -> serde_json::Value
+{
+	let map = content
+		.lines()
+		.map(str::trim)
+		.filter(|line| !line.is_empty() && !line.starts_with('#'))
+		.filter_map(|line| line.split_once('='))
+		.map(|(key, value)| (key.trim().to_owned(), serde_json::Value::String(value.trim().to_owned())))
+		.collect();
+	serde_json::Value::Object(map)
+}
@@ -0,0 +1,90 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use serde_json::Value;
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Fusionne une valeur de configuration dans une autre.
+///
+/// Les objets sont fusionnés récursivement, clé par clé. Les autres valeurs
+/// (scalaires, listes) de `overlay` remplacent celles de `base`.
+pub fn merge(base: &mut Value, overlay: Value)
+{
+	match (base, overlay) {
+		| (Value::Object(base), Value::Object(overlay)) => {
+			for (key, value) in overlay {
+				match base.get_mut(&key) {
+					| Some(base_value) => merge(base_value, value),
+					| None => {
+						base.insert(key, value);
+					}
+				}
+			}
+		}
+		| (base, overlay) => *base = overlay,
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use serde_json::json;
+
+	use super::*;
+
+	#[test]
+	fn test_merge()
+	{
+		let mut base = json!({
+			"host": "base",
+			"port": 80,
+			"tls": { "enabled": false, "cert": "/base" },
+			"hosts": ["a", "b"],
+		});
+		merge(
+			&mut base,
+			json!({
+				"port": 8080,
+				"tls": { "enabled": true },
+				"hosts": ["c"],
+				"name": "overlay",
+			}),
+		);
+		assert_eq!(
+			base,
+			json!({
+				"host": "base",
+				"port": 8080,
+				"tls": { "enabled": true, "cert": "/base" },
+				"hosts": ["c"],
+				"name": "overlay",
+			})
+		);
+	}
+
+	#[test]
+	fn test_merge_replaces_other_values()
+	{
+		let mut base = json!({ "tls": { "enabled": true } });
+		merge(&mut base, json!({ "tls": null }));
+		assert_eq!(base, json!({ "tls": null }));
+
+		let mut base = json!("scalar");
+		merge(&mut base, json!({ "key": "value" }));
+		assert_eq!(base, json!({ "key": "value" }));
+
+		let mut base = serde_json::Value::Null;
+		merge(&mut base, json!({ "key": "value" }));
+		assert_eq!(base, json!({ "key": "value" }));
+	}
+}
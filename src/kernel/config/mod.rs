@@ -0,0 +1,685 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+mod dump;
+mod env;
+mod env_example;
+mod env_file;
+mod env_report;
+mod format;
+mod include;
+mod interpolate;
+mod lenient;
+mod loader;
+mod merge;
+mod provenance;
+mod schema;
+mod secret_file;
+mod source;
+mod validate;
+mod watch;
+
+use std::{io, path};
+
+pub use self::dump::{ConfigDump, ConfigDumpFormat, REDACTED_VALUE};
+pub(crate) use self::dump::{ConfigValue, LoadedConfigs};
+pub use self::env::*;
+#[cfg(feature = "schema")]
+pub use self::env_example::env_fields_from_schema;
+pub(crate) use self::env_example::{check_env_examples, default_env_fields, write_env_examples};
+pub use self::env_example::{render_env_example, EnvField, ENV_EXAMPLE_SUFFIX};
+pub(crate) use self::env_file::{fetch_env_file, record_env_vars};
+pub use self::env_report::{EnvReport, EnvViolation, EnvViolationKind};
+use self::env::{env_overrides, process_env_vars, secret_file_path, set_path};
+pub use self::include::CONFIG_INCLUDE_DIRECTIVE;
+use self::include::read_with_includes;
+pub use self::interpolate::InterpolationError;
+use self::interpolate::Interpolator;
+pub use self::merge::merge;
+pub use self::provenance::*;
+pub(crate) use self::schema::{validate_schemas, ConfigSchemas};
+pub use self::schema::SchemaMismatch;
+pub use self::secret_file::{read_secret_file, SecretFilePermissions, SECRET_FILE_SUFFIX};
+pub use self::source::*;
+pub use self::validate::*;
+pub use self::watch::WatchedConfig;
+use super::settings::KernelSettings;
+use crate::process::ProcessMode;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Configuration fusionnée, avant interpolation.
+#[derive(Clone)]
+struct LoadedConfig
+{
+	/// Les couches de la configuration, de la moins prioritaire à la plus
+	/// prioritaire.
+	layers: Vec<ConfigLayer>,
+	/// Valeur fusionnée, surcharges des variables d'environnement comprises.
+	value: serde_json::Value,
+	/// Chemins des clés définies par des valeurs brutes (variables
+	/// d'environnement de surcharge, sources de valeurs brutes), dont les
+	/// chaînes de caractères sont converties dans le type attendu lors de la
+	/// dé-sérialisation.
+	lenient_paths: Vec<Vec<String>>,
+	/// Chemins des clés définies par les sources et les variables
+	/// d'environnement de surcharge (dont les fichiers secrets). Leurs
+	/// valeurs, ainsi que celles des clés qu'elles contiennent, ne sont pas
+	/// interpolées.
+	literal_paths: Vec<Vec<String>>,
+	/// Fichiers lus lors du chargement : fichiers des couches, fichiers
+	/// inclus, fichiers des sources et fichiers secrets.
+	files: Vec<path::PathBuf>,
+}
+
+/// Configuration fusionnée et interpolée, prête à être dé-sérialisée. Voir
+/// [resolve_config()].
+pub(crate) struct ResolvedConfig
+{
+	/// Valeur de la configuration.
+	pub(crate) value: serde_json::Value,
+	/// Voir [LoadedConfig::lenient_paths].
+	pub(crate) lenient_paths: Vec<Vec<String>>,
+	/// Fichiers dont dépend la configuration : les fichiers lus lors de son
+	/// chargement et de celui des configurations qu'elle référence, ainsi que
+	/// les fichiers candidats de leurs couches (existants ou non).
+	pub(crate) files: Vec<path::PathBuf>,
+}
+
+/// Erreur d'une configuration dont aucune couche n'existe (ni fichier, ni
+/// source, ni variable d'environnement de surcharge). Voir
+/// [is_missing_config()].
+#[derive(Debug)]
+struct MissingConfig(String);
+
+/// Couche d'une configuration : un fichier du répertoire de configuration,
+/// la valeur d'une source (voir [ConfigSource]) ou une variable
+/// d'environnement de surcharge.
+#[derive(Clone)]
+struct ConfigLayer
+{
+	/// Source de la couche : `file`, `env` ou le nom d'une [ConfigSource].
+	source: String,
+	/// Fichier de la couche, lorsqu'il y en a un.
+	file: Option<path::PathBuf>,
+	/// Variable d'environnement de la couche, pour la source `env`.
+	variable: Option<String>,
+	value: serde_json::Value,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ConfigLayer
+{
+	/// Nom de la source des fichiers du répertoire de configuration.
+	const FILE_SOURCE: &'static str = "file";
+	/// Nom de la source des variables d'environnement de surcharge.
+	const ENV_SOURCE: &'static str = "env";
+
+	fn file(filepath: path::PathBuf, value: serde_json::Value) -> Self
+	{
+		Self {
+			source: Self::FILE_SOURCE.to_owned(),
+			file: Some(filepath),
+			variable: None,
+			value,
+		}
+	}
+
+	fn source(source: &dyn ConfigSource, value: serde_json::Value) -> Self
+	{
+		Self {
+			source: source.name().to_owned(),
+			file: source.location().map(ToOwned::to_owned),
+			variable: None,
+			value,
+		}
+	}
+
+	fn env(variable: String, value: serde_json::Value) -> Self
+	{
+		Self {
+			source: Self::ENV_SOURCE.to_owned(),
+			file: None,
+			variable: Some(variable),
+			value,
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl std::fmt::Display for MissingConfig
+{
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+	{
+		f.write_str(&self.0)
+	}
+}
+
+impl std::error::Error for MissingConfig {}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Désérialise un fichier de configuration situé dans son répertoire de
+/// configuration en une structure de données en fonction du mode d'exécution.
+///
+/// La configuration est obtenue en fusionnant, dans cet ordre, les fichiers
+/// suivants (voir [config_layers()]) :
+///
+///   1. `config/<config_name>.<EXT>` : la configuration de base ;
+///   2. `config/<mode>/<config_name>.<EXT>` : la surcharge du mode
+///      d'exécution (`dev`, `prod` ou `test`, aucune en local) ;
+///   3. `config/<config_name>.local.<EXT>` : une surcharge locale, qui NE
+///      DOIT PAS être versionnée ;
+///   4. les sources de configuration, dans leur ordre d'enregistrement (voir
+///      [ConfigSource]) ;
+///   5. les variables d'environnement `<PREFIX>__<CONFIG_NAME>__<KEY>`, où
+///      chaque `__` supplémentaire descend d'un niveau (voir
+///      [env_overrides()]) ; leurs valeurs brutes sont converties dans le
+///      type attendu (ex: `"20"` pour un `u16`, `"007"` reste `"007"` pour
+///      une [String]).
+///
+/// Chacune de ces couches est optionnelle, mais au moins l'une d'entre elles
+/// DOIT exister. Les objets sont fusionnés récursivement, les scalaires et les
+/// listes sont remplacés (voir [merge()]).
+///
+/// Chaque fichier PEUT inclure d'autres fichiers via la directive
+/// [CONFIG_INCLUDE_DIRECTIVE] ; ces derniers sont fusionnés avant lui.
+///
+/// Les références suivantes, contenues dans les chaînes de caractères des
+/// fichiers de la configuration, sont ensuite interpolées dans la
+/// configuration fusionnée (sauf si l'interpolation est désactivée pour
+/// cette configuration) ; les valeurs des sources et des variables
+/// d'environnement de surcharge (dont les fichiers secrets) ne sont jamais
+/// interpolées :
+///
+///   - `${ENV_VAR}` : la valeur d'une variable d'environnement ;
+///   - `${ENV_VAR:-default}` : idem, avec une valeur par défaut lorsque la
+///     variable n'est pas définie ou vide ;
+///   - `${config:other_file.key}` : la valeur d'une clé d'une autre
+///     configuration (ou de la même) ;
+///   - `$${` : un `${` littéral.
+///
+/// Lorsqu'une chaîne ne contient qu'une seule référence, la valeur référencée
+/// est conservée telle quelle (nombre, objet, ...). Les références
+/// circulaires et les références non résolues sont des erreurs (voir
+/// [InterpolationError]).
+///
+/// À savoir que **par défaut** le répertoire de configuration se trouve à la
+/// racine du projet `config/` et que le format de chaque fichier est détecté
+/// à partir de son extension <EXT>, parmi `yml`/`yaml`, `json` et `toml` (voir
+/// [resolve_config_layers()]). Ces formats, et leur ordre de priorité,
+/// peuvent être modifiés dans les paramètres de la configuration.
+///
+/// Retourne également la valeur fusionnée dont est issue la configuration,
+/// dans laquelle les valeurs des variables d'environnement de surcharge qui
+/// ne remplacent aucune valeur typée sont des chaînes de caractères, ainsi
+/// que les chemins de ses valeurs [Secret](crate::Secret).
+pub(crate) fn fetch_config<O>(config_name: &str, settings: &KernelSettings) -> io::Result<(O, ConfigValue)>
+where
+	O: serde::de::DeserializeOwned,
+{
+	let config = resolve_config(config_name, settings)?;
+	let value = config.value.clone();
+	let (config, secret_paths) = deserialize_config(config_name, config.value, &config.lenient_paths)?;
+	Ok((config, ConfigValue { value, secret_paths }))
+}
+
+/// Fusionne les fichiers de configuration d'un nom de configuration (voir
+/// [fetch_config()]). Retourne également les chemins des clés
+/// définies par des valeurs brutes (voir [deserialize_config()]) et les
+/// fichiers dont dépend la configuration.
+pub(crate) fn resolve_config(config_name: &str, settings: &KernelSettings) -> io::Result<ResolvedConfig>
+{
+	let loaded_config = load_config(config_name, settings)?;
+	let lenient_paths = loaded_config.lenient_paths.clone();
+
+	if !settings.config.has_interpolation(config_name) {
+		let files = config_files(settings, [(config_name, &loaded_config)]);
+		return Ok(ResolvedConfig {
+			value: loaded_config.value,
+			lenient_paths,
+			files,
+		});
+	}
+
+	let mut interpolator = Interpolator::new(settings);
+	let value = interpolator
+		.interpolate(config_name, loaded_config)
+		.map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+	let files = config_files(settings, interpolator.loaded_configs());
+
+	Ok(ResolvedConfig {
+		value,
+		lenient_paths,
+		files,
+	})
+}
+
+/// Fichiers dont dépendent des configurations chargées : les fichiers
+/// candidats de leurs couches, puis les fichiers lus lors de leur
+/// chargement, sans doublon.
+fn config_files<'a>(
+	settings: &KernelSettings,
+	loaded_configs: impl IntoIterator<Item = (&'a str, &'a LoadedConfig)>,
+) -> Vec<path::PathBuf>
+{
+	let mut files = Vec::new();
+
+	for (config_name, loaded_config) in loaded_configs {
+		let candidates = settings.directory.config().map(|config_directory| {
+			config_layer_candidates(
+				config_directory,
+				config_name,
+				settings.process_mode,
+				&settings.loader_extensions,
+			)
+		});
+
+		for filepath in candidates.into_iter().flatten().chain(loaded_config.files.iter().cloned()) {
+			if !files.contains(&filepath) {
+				files.push(filepath);
+			}
+		}
+	}
+
+	files
+}
+
+/// Dé-sérialise une configuration fusionnée. Les chaînes de caractères des
+/// clés définies par des valeurs brutes (variables d'environnement de
+/// surcharge, voir [ConfigSource::raw_values()]) sont converties dans le type
+/// attendu (ex: `"20"` pour un `u16`, `"007"` reste `"007"` pour une
+/// [String]). Retourne également les chemins des valeurs
+/// [Secret](crate::Secret) dé-sérialisées.
+pub(crate) fn deserialize_config<O>(
+	config_name: &str,
+	config: serde_json::Value,
+	lenient_paths: &[Vec<String>],
+) -> io::Result<(O, Vec<Vec<String>>)>
+where
+	O: serde::de::DeserializeOwned,
+{
+	lenient::from_value(config, lenient_paths)
+		.map_err(|err| io::Error::new(io::ErrorKind::InvalidData, format!("« {config_name} » : {err}")))
+}
+
+/// Fusionne les fichiers de configuration d'un nom de configuration, sans
+/// interpoler les références.
+fn load_config(config_name: &str, settings: &KernelSettings) -> io::Result<LoadedConfig>
+{
+	let config_directory = settings.directory.config();
+
+	let mut config = serde_json::Value::Null;
+	let mut layers = Vec::new();
+	let mut lenient_paths = Vec::new();
+	let mut literal_paths = Vec::new();
+	let mut files = Vec::new();
+
+	if let Some(config_directory) = config_directory {
+		for (filepath, _) in resolve_config_layers(
+			config_directory,
+			config_name,
+			settings.process_mode,
+			&settings.loader_extensions,
+		)? {
+			log::trace!("Chargement du fichier de configuration « {} »", filepath.display());
+			for (filepath, layer) in read_with_includes(&filepath, settings)? {
+				files.push(filepath.clone());
+				merge(&mut config, layer.clone());
+				layers.push(ConfigLayer::file(filepath, layer));
+			}
+		}
+	}
+
+	for source in settings.config.sources() {
+		let layer = source
+			.load(config_name)
+			.map_err(|err| invalid_layer(&err, format!("source « {} » : {err}", source.name())))?;
+		let Some(layer) = layer else {
+			continue;
+		};
+		log::trace!("Chargement de la configuration « {config_name} » depuis la source « {} »", source.name());
+		files.extend(source.location().map(ToOwned::to_owned));
+		let mut leaves = Vec::new();
+		provenance::collect_leaves(&layer, &mut Vec::new(), &mut leaves);
+		if source.raw_values() {
+			lenient_paths.extend(leaves.iter().cloned());
+		}
+		literal_paths.extend(leaves);
+		merge(&mut config, layer.clone());
+		layers.push(ConfigLayer::source(source, layer));
+	}
+
+	if settings.config.has_env_overrides(config_name) {
+		for (variable, mut path, mut raw_value) in
+			env_overrides(&settings.config.env_prefix, config_name, process_env_vars())
+		{
+			log::trace!("Surcharge de la configuration « {config_name} » par la variable « {variable} »");
+			if let Some(secret_path) = secret_file_path(&config, &path) {
+				files.push(path::PathBuf::from(&raw_value));
+				raw_value = read_secret_file(&raw_value, settings.config.secret_file_permissions)
+					.map_err(|err| invalid_layer(&err, format!("« {variable} » : {err}")))?;
+				path = secret_path;
+			}
+			let path = set_path(&mut config, &path, &raw_value)
+				.map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, format!("« {variable} » : {err}")))?;
+			let value = lookup(&config, &path).cloned().unwrap_or_default();
+			lenient_paths.push(path.clone());
+			literal_paths.push(path.clone());
+			let layer = path.into_iter().rev().fold(value, |value, segment| {
+				serde_json::Value::Object(serde_json::Map::from_iter([(segment, value)]))
+			});
+			layers.push(ConfigLayer::env(variable, layer));
+		}
+	}
+
+	if layers.is_empty() {
+		let Some(config_directory) = config_directory else {
+			return Err(missing_config(String::from(
+				"Le répertoire de configuration n'existe pas.",
+			)));
+		};
+		return Err(missing_config(format!(
+			"Aucun fichier de configuration « {config_name} » n'a été trouvé dans « {} ».",
+			config_directory.display()
+		)));
+	}
+
+	Ok(LoadedConfig {
+		layers,
+		value: config,
+		lenient_paths,
+		literal_paths,
+		files,
+	})
+}
+
+/// Indique si l'erreur d'un chargement de configuration (voir
+/// [fetch_config()]) signifie qu'aucune couche de cette configuration
+/// n'existe. Les couches référencées introuvables (fichier inclus, source,
+/// fichier secret) ne sont pas concernées : elles signalent une
+/// configuration cassée ([io::ErrorKind::InvalidData]) qui NE DOIT PAS être
+/// remplacée par une configuration par défaut.
+pub(crate) fn is_missing_config(err: &io::Error) -> bool
+{
+	err.kind() == io::ErrorKind::NotFound
+		&& err.get_ref().is_some_and(|reason| reason.is::<MissingConfig>())
+}
+
+fn missing_config(reason: String) -> io::Error
+{
+	io::Error::new(io::ErrorKind::NotFound, MissingConfig(reason))
+}
+
+/// Erreur d'une couche référencée par la configuration (source, fichier
+/// secret) : lorsqu'elle est introuvable, la configuration est invalide
+/// ([io::ErrorKind::InvalidData]), elle n'est pas absente (voir
+/// [is_missing_config()]).
+fn invalid_layer(err: &io::Error, reason: String) -> io::Error
+{
+	match err.kind() {
+		| io::ErrorKind::NotFound => io::Error::new(io::ErrorKind::InvalidData, reason),
+		| kind => io::Error::new(kind, reason),
+	}
+}
+
+/// Désérialise un fichier de configuration situé dans son répertoire de
+/// configuration en une structure de données en fonction du mode d'exécution.
+/// Voir [fetch_config()].
+///
+/// Si aucun fichier de configuration n'existe (et qu'aucune source ni
+/// variable d'environnement ne la définit), l'utilisateur est questionné sur
+/// les valeurs à remplir afin de créer le fichier du mode d'exécution ; la
+/// configuration est ensuite récupérée via [fetch_config()]. Toutes les
+/// autres erreurs (fichier invalide, interpolation, inclusion, ...) sont
+/// retournées.
+pub(crate) fn fetch_config_or_prompt<O>(config_name: &str, settings: &KernelSettings) -> io::Result<(O, ConfigValue)>
+where
+	O: std::fmt::Debug,
+	O: lexa_prompt::Prompt,
+	O: serde::ser::Serialize + serde::de::DeserializeOwned,
+{
+	match fetch_config(config_name, settings) {
+		| Err(err) if is_missing_config(&err) => {}
+		| result => return result,
+	}
+
+	let filepath = match settings.process_mode {
+		| ProcessMode::LOCAL => String::from(config_name),
+		| mode => format!("{}/{}", mode.short_name(), config_name),
+	};
+
+	if let Some(config_directory) = settings.directory.config() {
+		lexa_fs::load_or_prompt::<O>(config_directory, filepath, settings.loader_extension)?;
+		fetch_config(config_name, settings)
+	} else {
+		Err(missing_config(String::from(
+			"Le répertoire de configuration n'existe pas.",
+		)))
+	}
+}
+
+/// Les chemins, sans extension, des fichiers de configuration à fusionner,
+/// du moins prioritaire au plus prioritaire.
+pub fn config_layers(config_directory: &path::Path, config_name: &str, process_mode: ProcessMode) -> Vec<path::PathBuf>
+{
+	let mut layers = vec![config_directory.join(config_name)];
+
+	if process_mode != ProcessMode::LOCAL {
+		layers.push(config_directory.join(process_mode.short_name()).join(config_name));
+	}
+
+	layers.push(config_directory.join(format!("{config_name}.local")));
+
+	layers
+}
+
+/// Les fichiers existants de chaque couche de configuration (voir
+/// [config_layers()]) et leur format. Pour chaque couche, les formats sont
+/// essayés dans l'ordre donné ; une couche pour laquelle plusieurs fichiers
+/// existent (ex: `logger.yml` et `logger.json`) est une erreur.
+pub fn resolve_config_layers(
+	config_directory: &path::Path,
+	config_name: &str,
+	process_mode: ProcessMode,
+	formats: &[lexa_fs::Extension],
+) -> io::Result<Vec<(path::PathBuf, lexa_fs::Extension)>>
+{
+	config_layers(config_directory, config_name, process_mode)
+		.iter()
+		.filter_map(|stem| format::probe(stem, formats).transpose())
+		.collect()
+}
+
+/// Tous les fichiers candidats des couches de configuration, existants ou
+/// non.
+pub(crate) fn config_layer_candidates(
+	config_directory: &path::Path,
+	config_name: &str,
+	process_mode: ProcessMode,
+	formats: &[lexa_fs::Extension],
+) -> Vec<path::PathBuf>
+{
+	config_layers(config_directory, config_name, process_mode)
+		.iter()
+		.flat_map(|stem| format::candidates(stem, formats))
+		.map(|(filepath, _)| filepath)
+		.collect()
+}
+
+/// Les noms des configurations présentes dans le répertoire de configuration
+/// et dans le répertoire du mode d'exécution.
+pub(crate) fn config_names(
+	config_directory: &path::Path,
+	process_mode: ProcessMode,
+	formats: &[lexa_fs::Extension],
+) -> std::collections::BTreeSet<String>
+{
+	let mode_directory = (process_mode != ProcessMode::LOCAL).then(|| config_directory.join(process_mode.short_name()));
+
+	std::iter::once(config_directory.to_owned())
+		.chain(mode_directory)
+		.filter_map(|directory| std::fs::read_dir(directory).ok())
+		.flatten()
+		.filter_map(|entry| entry.ok().map(|entry| entry.path()))
+		.filter(|filepath| filepath.is_file())
+		.filter(|filepath| format::file_format(filepath).is_some_and(|format| formats.contains(&format)))
+		.filter_map(|filepath| {
+			let name = filepath.file_stem()?.to_str()?;
+			Some(name.strip_suffix(".local").unwrap_or(name).to_owned())
+		})
+		.collect()
+}
+
+/// Valeur d'une clé, à partir de son chemin.
+fn lookup<'a>(value: &'a serde_json::Value, key: &[String]) -> Option<&'a serde_json::Value>
+{
+	key.iter().try_fold(value, |value, segment| {
+		match value {
+			| serde_json::Value::Object(map) => map.get(segment),
+			| serde_json::Value::Array(items) => items.get(segment.parse::<usize>().ok()?),
+			| _ => None,
+		}
+	})
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::fs;
+
+	use super::*;
+
+	#[derive(Debug)]
+	#[derive(PartialEq, Eq)]
+	#[derive(serde::Serialize, serde::Deserialize)]
+	struct Server
+	{
+		host: String,
+		port: u16,
+	}
+
+	impl lexa_prompt::Prompt for Server
+	{
+		fn prompt() -> lexa_prompt::Result<Self>
+		{
+			panic!("l'utilisateur NE DOIT PAS être questionné");
+		}
+	}
+
+	fn settings(config_directory: &path::Path, process_mode: ProcessMode) -> KernelSettings
+	{
+		let mut settings = KernelSettings::new(config_directory, process_mode);
+		settings.directory.set_config_directory(config_directory);
+		settings
+	}
+
+	#[test]
+	fn test_config_layers_order()
+	{
+		let dir = path::Path::new("config");
+		assert_eq!(
+			config_layers(dir, "app", ProcessMode::DEVELOPMENT),
+			[dir.join("app"), dir.join("dev/app"), dir.join("app.local")]
+		);
+		assert_eq!(config_layers(dir, "app", ProcessMode::LOCAL), [dir.join("app"), dir.join("app.local")]);
+	}
+
+	#[test]
+	fn test_fetch_config_merges_layers()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		fs::create_dir(dir.path().join("prod")).unwrap();
+		fs::write(dir.path().join("server.yml"), "host: base\nport: 80\n").unwrap();
+		fs::write(dir.path().join("prod/server.yml"), "host: prod\n").unwrap();
+		fs::write(dir.path().join("server.local.yml"), "port: 8080\n").unwrap();
+
+		let (server, _): (Server, _) = fetch_config("server", &settings(dir.path(), ProcessMode::PRODUCTION)).unwrap();
+		assert_eq!(
+			server,
+			Server {
+				host: "prod".into(),
+				port: 8080
+			}
+		);
+
+		let (server, _): (Server, _) = fetch_config("server", &settings(dir.path(), ProcessMode::DEVELOPMENT)).unwrap();
+		assert_eq!(server.host, "base");
+	}
+
+	#[test]
+	fn test_fetch_config_raw_values()
+	{
+		#[derive(Debug)]
+		#[derive(serde::Deserialize)]
+		struct Database
+		{
+			pass: String,
+			port: u16,
+		}
+
+		let dir = tempfile::tempdir().unwrap();
+		fs::write(dir.path().join("db.yml"), "port: 80\n").unwrap();
+		fs::write(dir.path().join("config.properties"), "db.pass = 12345\ndb.port = 5432\n").unwrap();
+		let mut settings = settings(dir.path(), ProcessMode::LOCAL);
+		settings
+			.config
+			.add_source(KeyValueFileConfigSource::new(dir.path().join("config.properties")));
+
+		let (database, _): (Database, _) = fetch_config("db", &settings).unwrap();
+		assert_eq!(database.pass, "12345");
+		assert_eq!(database.port, 5432);
+	}
+
+	#[test]
+	fn test_fetch_config_not_found()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		let err = fetch_config::<Server>("server", &settings(dir.path(), ProcessMode::LOCAL)).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::NotFound);
+		assert!(is_missing_config(&err));
+	}
+
+	#[test]
+	fn test_fetch_config_missing_include_is_invalid()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		fs::write(dir.path().join("server.yml"), "$include: common.yml\nhost: h\nport: 80\n").unwrap();
+
+		let err = fetch_config::<Server>("server", &settings(dir.path(), ProcessMode::LOCAL)).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+		assert!(!is_missing_config(&err));
+	}
+
+	#[test]
+	fn test_fetch_config_or_prompt_returns_invalid_config_errors()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		fs::write(dir.path().join("server.yml"), "host: [\n").unwrap();
+		let settings = settings(dir.path(), ProcessMode::LOCAL);
+
+		let err = fetch_config_or_prompt::<Server>("server", &settings).unwrap_err();
+		assert_ne!(err.kind(), io::ErrorKind::NotFound);
+
+		fs::write(dir.path().join("server.yml"), "host: base\n").unwrap();
+		let err = fetch_config_or_prompt::<Server>("server", &settings).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+}
@@ -0,0 +1,268 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::{BTreeMap, HashMap};
+use std::{fmt, fs, io, path};
+
+use super::dump::redact_at;
+use super::interpolate::Interpolator;
+use super::schema::locate;
+use super::{format, load_config, lookup};
+use crate::kernel::settings::KernelSettings;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Origine d'une valeur d'une configuration.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct ConfigOrigin
+{
+	/// Source de la valeur : `file`, `env` ou le nom d'une
+	/// [ConfigSource](super::ConfigSource).
+	pub source: String,
+	/// Fichier de la valeur, lorsqu'il y en a un.
+	pub file: Option<path::PathBuf>,
+	/// Ligne de la clé dans le fichier, lorsqu'elle a pu être déterminée.
+	pub line: Option<usize>,
+	/// Variable d'environnement de la valeur, pour la source `env`.
+	pub variable: Option<String>,
+	/// Valeur définie par cette source, avant interpolation.
+	pub value: serde_json::Value,
+}
+
+/// Provenance des clés terminales (scalaires, listes, objets vides) de la
+/// valeur effective d'une configuration.
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct ConfigProvenance
+{
+	config_name: String,
+	/// Valeur effective de la configuration, interpolée.
+	value: serde_json::Value,
+	/// Origines de chaque clé terminale, de la plus prioritaire à la moins
+	/// prioritaire.
+	keys: BTreeMap<String, Vec<ConfigOrigin>>,
+}
+
+/// Explication de la valeur effective d'une clé d'une configuration.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct ConfigExplanation
+{
+	/// Chemin de la clé (ex: `database.host`).
+	pub key: String,
+	/// Valeur effective de la clé, interpolée.
+	pub value: serde_json::Value,
+	/// Origines de la valeur, de la plus prioritaire à la moins prioritaire.
+	pub origins: Vec<ConfigOrigin>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ConfigProvenance
+{
+	/// Nom de la configuration.
+	pub fn config_name(&self) -> &str
+	{
+		&self.config_name
+	}
+
+	/// Origines d'une clé terminale (ex: `host` pour `database.host`), de la
+	/// plus prioritaire à la moins prioritaire.
+	pub fn get(&self, key: &str) -> Option<&[ConfigOrigin]>
+	{
+		self.keys.get(key).map(Vec::as_slice)
+	}
+
+	/// Les clés terminales et leurs origines.
+	pub fn iter(&self) -> impl Iterator<Item = (&str, &[ConfigOrigin])>
+	{
+		self.keys.iter().map(|(key, origins)| (key.as_str(), origins.as_slice()))
+	}
+
+	/// Masque les valeurs secrètes de la configuration et de ses origines,
+	/// selon les chemins des valeurs secrètes, le schéma de la configuration
+	/// et les motifs des clés secrètes (voir
+	/// [Kernel::dump_config()](crate::Kernel::dump_config)).
+	pub(crate) fn redact(
+		&mut self,
+		secret_paths: &[Vec<String>],
+		schema: Option<&serde_json::Value>,
+		patterns: &[String],
+	)
+	{
+		for (key, origins) in self.keys.iter_mut() {
+			let path = split_key(key);
+			if let Some(value) = path.iter().try_fold(&mut self.value, |value, key| value.get_mut(key)) {
+				redact_at(&path, value, secret_paths, schema, patterns);
+			}
+			for origin in origins {
+				redact_at(&path, &mut origin.value, secret_paths, schema, patterns);
+			}
+		}
+	}
+
+	/// Explique les clés terminales situées sous une clé (ex: `host` ou
+	/// `tls`). Une clé vide explique toute la configuration.
+	pub fn explain(&self, key: &str) -> Vec<ConfigExplanation>
+	{
+		self.keys
+			.iter()
+			.filter(|(leaf, _)| key.is_empty() || *leaf == key || leaf.starts_with(&format!("{key}.")))
+			.map(|(leaf, origins)| {
+				let path = split_key(leaf);
+				ConfigExplanation {
+					key: format!("{}.{leaf}", self.config_name),
+					value: lookup(&self.value, &path).cloned().unwrap_or_default(),
+					origins: origins.clone(),
+				}
+			})
+			.collect()
+	}
+}
+
+impl ConfigExplanation
+{
+	/// Origine de la valeur effective.
+	pub fn winner(&self) -> Option<&ConfigOrigin>
+	{
+		self.origins.first()
+	}
+
+	/// Origines des valeurs remplacées par la valeur effective.
+	pub fn overridden(&self) -> &[ConfigOrigin]
+	{
+		self.origins.get(1..).unwrap_or_default()
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl fmt::Display for ConfigOrigin
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "{}", self.source)?;
+		match (&self.variable, &self.file, self.line) {
+			| (Some(variable), ..) => write!(f, " ({variable})")?,
+			| (None, Some(file), Some(line)) => write!(f, " ({}:{line})", file.display())?,
+			| (None, Some(file), None) => write!(f, " ({})", file.display())?,
+			| (None, None, _) => {}
+		}
+		write!(f, " = {}", self.value)
+	}
+}
+
+impl fmt::Display for ConfigExplanation
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "{} = {}", self.key, self.value)?;
+		if let Some(winner) = self.winner() {
+			write!(f, "\n\tdéfinie par : {winner}")?;
+		}
+		for origin in self.overridden() {
+			write!(f, "\n\tremplace    : {origin}")?;
+		}
+		Ok(())
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Provenance des clés d'une configuration : pour chaque clé terminale de la
+/// valeur effective, la source, le fichier et la ligne de chacune des
+/// couches qui la définissent (voir [fetch_config()](super::fetch_config)).
+pub fn config_provenance(config_name: &str, settings: &KernelSettings) -> io::Result<ConfigProvenance>
+{
+	let loaded_config = load_config(config_name, settings)?;
+
+	let mut leaves = Vec::new();
+	collect_leaves(&loaded_config.value, &mut Vec::new(), &mut leaves);
+
+	let mut contents = HashMap::new();
+	let mut keys = BTreeMap::new();
+
+	for path in leaves {
+		let origins = loaded_config
+			.layers
+			.iter()
+			.rev()
+			.filter_map(|layer| {
+				let value = lookup(&layer.value, &path)?.clone();
+				let line = layer
+					.file
+					.as_deref()
+					.filter(|filepath| filepath.is_file())
+					.and_then(|filepath| {
+						let extension = format::file_format(filepath).unwrap_or(settings.loader_extension);
+						let content = contents
+							.entry(filepath.to_owned())
+							.or_insert_with(|| fs::read_to_string(filepath).unwrap_or_default());
+						locate(content, extension, &path)
+					});
+				Some(ConfigOrigin {
+					source: layer.source.clone(),
+					file: layer.file.clone(),
+					line,
+					variable: layer.variable.clone(),
+					value,
+				})
+			})
+			.collect();
+		keys.insert(path.join("."), origins);
+	}
+
+	let value = if settings.config.has_interpolation(config_name) {
+		Interpolator::new(settings)
+			.interpolate(config_name, loaded_config)
+			.map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?
+	} else {
+		loaded_config.value
+	};
+
+	Ok(ConfigProvenance {
+		config_name: config_name.to_owned(),
+		value,
+		keys,
+	})
+}
+
+/// Chemins des clés terminales d'une valeur.
+pub(super) fn collect_leaves(value: &serde_json::Value, path: &mut Vec<String>, leaves: &mut Vec<Vec<String>>)
+{
+	match value {
+		| serde_json::Value::Object(map) if !map.is_empty() => {
+			for (key, value) in map {
+				path.push(key.clone());
+				collect_leaves(value, path, leaves);
+				path.pop();
+			}
+		}
+		| _ if !path.is_empty() => leaves.push(path.clone()),
+		| _ => {}
+	}
+}
+
+/// Chemin d'une clé `a.b.c`.
+fn split_key(key: &str) -> Vec<String>
+{
+	key.split('.').map(ToOwned::to_owned).collect()
+}
@@ -0,0 +1,800 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::{fmt, fs, path};
+
+use super::interpolate::Interpolator;
+use super::{
+	env_file_cascade,
+	format,
+	loader,
+	lookup,
+	merge,
+	read_with_includes,
+	resolve_config_layers,
+	ConfigLayer,
+	LoadedConfig,
+};
+use crate::kernel::settings::KernelSettings;
+use crate::KernelError;
+
+// ---- //
+// Type //
+// ---- //
+
+/// Erreur de validation : chemin de la clé et raison.
+type SchemaError = (Vec<String>, String);
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Schémas JSON des configurations et des variables d'environnement de
+/// l'application.
+#[derive(Default)]
+pub(crate) struct ConfigSchemas
+{
+	schemas: Vec<ConfigSchema>,
+}
+
+struct ConfigSchema
+{
+	/// Nom du schéma, utilisé pour le nom du fichier généré.
+	name: String,
+	/// Fichiers validés par ce schéma.
+	source: ConfigSchemaSource,
+	/// Document JSON Schema.
+	schema: serde_json::Value,
+}
+
+/// Erreur d'un fichier qui ne respecte pas son schéma.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct SchemaMismatch
+{
+	/// Fichier concerné.
+	pub file: path::PathBuf,
+	/// Ligne de la clé dans le fichier, lorsqu'elle a pu être trouvée.
+	pub line: Option<usize>,
+	/// Chemin de la clé (ex: `server.tls.cert_path`).
+	pub key: String,
+	/// Raison de l'erreur.
+	pub message: String,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+enum ConfigSchemaSource
+{
+	/// Les fichiers d'une configuration, voir [resolve_config_layers()].
+	Config,
+	/// Les fichiers d'environnement `<filename>` et leur suffixe de mode,
+	/// voir [env_file_cascade()]. Les valeurs de ces fichiers sont des chaînes
+	/// de caractères, converties en fonction du type attendu.
+	#[cfg_attr(not(feature = "schema"), allow(dead_code))]
+	Env
+	{
+		filename: String,
+		mode_suffix: String,
+	},
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ConfigSchemas
+{
+	/// Ajoute (ou remplace) le schéma d'une configuration.
+	pub(crate) fn insert_config(&mut self, config_name: impl Into<String>, schema: serde_json::Value)
+	{
+		self.insert(config_name.into(), ConfigSchemaSource::Config, schema);
+	}
+
+	/// Ajoute (ou remplace) le schéma des fichiers d'environnement
+	/// `<env_filename>`.
+	#[cfg_attr(not(feature = "schema"), allow(dead_code))]
+	pub(crate) fn insert_env(
+		&mut self,
+		env_filename: impl Into<String>,
+		mode_suffix: impl Into<String>,
+		schema: serde_json::Value,
+	)
+	{
+		let filename = env_filename.into();
+		let name = filename.trim_start_matches('.').to_owned();
+		let source = ConfigSchemaSource::Env {
+			filename,
+			mode_suffix: mode_suffix.into(),
+		};
+		self.insert(name, source, schema);
+	}
+
+	/// Schéma d'une configuration.
+	pub(crate) fn config_schema(&self, config_name: &str) -> Option<&serde_json::Value>
+	{
+		self.schemas
+			.iter()
+			.find(|config_schema| {
+				matches!(config_schema.source, ConfigSchemaSource::Config) && config_schema.name == config_name
+			})
+			.map(|config_schema| &config_schema.schema)
+	}
+
+	/// Schéma d'un fichier d'environnement (ex: `.env` pour `.env.dev.local`).
+	pub(crate) fn env_schema(&self, env_filename: &str) -> Option<&serde_json::Value>
+	{
+		self.schemas
+			.iter()
+			.find(|config_schema| {
+				matches!(
+					&config_schema.source,
+					ConfigSchemaSource::Env { filename, .. }
+						if env_filename == filename || env_filename.starts_with(&format!("{filename}."))
+				)
+			})
+			.map(|config_schema| &config_schema.schema)
+	}
+
+	fn insert(&mut self, name: String, source: ConfigSchemaSource, schema: serde_json::Value)
+	{
+		self.schemas.retain(|config_schema| config_schema.name != name);
+		self.schemas.push(ConfigSchema { name, source, schema });
+	}
+
+	/// Écrit les schémas dans un répertoire, sous le nom
+	/// `<name>.schema.json`. Retourne les chemins des fichiers écrits.
+	pub(crate) fn write(&self, dir: &path::Path) -> Result<Vec<path::PathBuf>, KernelError>
+	{
+		let write = |filepath: &path::Path, content: &str| {
+			fs::write(filepath, content).map_err(|reason| {
+				KernelError::Write {
+					path: filepath.to_owned(),
+					reason,
+				}
+			})
+		};
+
+		fs::create_dir_all(dir).map_err(|reason| {
+			KernelError::Write {
+				path: dir.to_owned(),
+				reason,
+			}
+		})?;
+
+		self.schemas
+			.iter()
+			.map(|config_schema| {
+				let filepath = dir.join(format!("{}.schema.json", config_schema.name));
+				let content = serde_json::to_string_pretty(&config_schema.schema).unwrap_or_default();
+				write(&filepath, &format!("{content}\n"))?;
+				Ok(filepath)
+			})
+			.collect()
+	}
+
+	/// Valide les fichiers existants avec leur schéma.
+	pub(crate) fn validate(&self, settings: &KernelSettings) -> Vec<SchemaMismatch>
+	{
+		let mut mismatches = Vec::new();
+
+		for config_schema in self.schemas.iter() {
+			let files = match &config_schema.source {
+				| ConfigSchemaSource::Config => {
+					let Some(config_directory) = settings.directory.config() else {
+						continue;
+					};
+					let layers = resolve_config_layers(
+						config_directory,
+						&config_schema.name,
+						settings.process_mode,
+						&settings.loader_extensions,
+					);
+					match layers {
+						| Ok(layers) => layers,
+						| Err(err) => {
+							let filepath = config_directory.join(&config_schema.name);
+							mismatches.push(SchemaMismatch::new(&filepath, None, &[], err));
+							continue;
+						}
+					}
+				}
+				| ConfigSchemaSource::Env { filename, mode_suffix } => {
+					let Some(env_directory) = settings.directory.env() else {
+						continue;
+					};
+					env_file_cascade(env_directory, filename, mode_suffix, settings.process_mode)
+						.into_iter()
+						.map(|filepath| (filepath, lexa_fs::Extension::ENV))
+						.collect()
+				}
+			};
+
+			config_schema.validate_files(&files, settings, &mut mismatches);
+		}
+
+		mismatches
+	}
+}
+
+impl ConfigSchema
+{
+	/// Valide la fusion des fichiers existants, après l'interpolation des
+	/// références d'une configuration. Chaque erreur est attribuée au fichier
+	/// le plus prioritaire qui définit la clé.
+	fn validate_files(
+		&self,
+		files: &[(path::PathBuf, lexa_fs::Extension)],
+		settings: &KernelSettings,
+		mismatches: &mut Vec<SchemaMismatch>,
+	)
+	{
+		let lenient = files.iter().any(|(_, extension)| *extension == lexa_fs::Extension::ENV);
+
+		let mut layers = Vec::new();
+		for (filepath, extension) in files {
+			let extension = *extension;
+			let files = match self.source {
+				| ConfigSchemaSource::Config => read_with_includes(filepath, settings),
+				| ConfigSchemaSource::Env { .. } => {
+					fs::read_to_string(filepath)
+						.and_then(|content| loader::parse(&content, extension))
+						.map(|value| vec![(filepath.clone(), value)])
+				}
+			};
+
+			match files {
+				| Ok(files) => {
+					for (filepath, value) in files {
+						let content = fs::read_to_string(&filepath).unwrap_or_default();
+						let extension = format::file_format(&filepath).unwrap_or(extension);
+						let value = if lenient { lowercase_keys(value) } else { value };
+						layers.push((filepath, content, extension, value));
+					}
+				}
+				| Err(err) => mismatches.push(SchemaMismatch::new(filepath, None, &[], err)),
+			}
+		}
+
+		if layers.is_empty() {
+			return;
+		}
+
+		let mut config = serde_json::Value::Null;
+		for (.., value) in layers.iter() {
+			merge(&mut config, value.clone());
+		}
+
+		if matches!(self.source, ConfigSchemaSource::Config) && settings.config.has_interpolation(&self.name) {
+			let loaded_config = LoadedConfig {
+				layers: layers
+					.iter()
+					.map(|(filepath, .., value)| ConfigLayer::file(filepath.clone(), value.clone()))
+					.collect(),
+				value: config,
+				lenient_paths: Vec::new(),
+				literal_paths: Vec::new(),
+				files: Vec::new(),
+			};
+
+			config = match Interpolator::new(settings).interpolate(&self.name, loaded_config) {
+				| Ok(config) => config,
+				| Err(err) => {
+					let key = err.key.split('.').skip(1).map(String::from).collect::<Vec<_>>();
+					let (filepath, content, extension, _) = err
+						.file
+						.as_ref()
+						.and_then(|file| layers.iter().find(|(filepath, ..)| filepath == file))
+						.unwrap_or(&layers[0]);
+					let line = locate(content, *extension, &key);
+					mismatches.push(SchemaMismatch::new(filepath, line, &key, err.reason));
+					return;
+				}
+			};
+		}
+
+		let mut errors = Vec::new();
+		Validator {
+			root: &self.schema,
+			lenient,
+		}
+		.check(&self.schema, &config, &mut Vec::new(), &mut errors);
+
+		for (key, message) in errors {
+			let (filepath, content, extension, _) = (0..=key.len())
+				.rev()
+				.find_map(|len| layers.iter().rev().find(|(.., value)| lookup(value, &key[..len]).is_some()))
+				.unwrap_or(&layers[0]);
+			let line = locate(content, *extension, &key);
+			mismatches.push(SchemaMismatch::new(filepath, line, &key, message));
+		}
+	}
+}
+
+impl SchemaMismatch
+{
+	fn new(file: &path::Path, line: Option<usize>, key: &[String], message: impl ToString) -> Self
+	{
+		Self {
+			file: file.to_owned(),
+			line,
+			key: key.join("."),
+			message: message.to_string(),
+		}
+	}
+}
+
+/// Validateur minimal de JSON Schema. Les mots-clés supportés sont : `$ref`
+/// (local), `allOf`, `anyOf`, `oneOf`, `enum`, `const`, `type`,
+/// `properties`, `required`, `additionalProperties`, `items`, `minimum`,
+/// `maximum`, `exclusiveMinimum`, `exclusiveMaximum`, `minLength`,
+/// `maxLength`, `pattern`, `minItems` et `maxItems`. Les autres sont ignorés.
+struct Validator<'a>
+{
+	root: &'a serde_json::Value,
+	/// Les chaînes de caractères sont converties en fonction du type attendu.
+	lenient: bool,
+}
+
+impl Validator<'_>
+{
+	fn check(
+		&self,
+		schema: &serde_json::Value,
+		value: &serde_json::Value,
+		path: &mut Vec<String>,
+		errors: &mut Vec<SchemaError>,
+	)
+	{
+		let schema = match schema {
+			| serde_json::Value::Bool(true) => return,
+			| serde_json::Value::Bool(false) => {
+				errors.push((path.clone(), String::from("aucune valeur n'est autorisée")));
+				return;
+			}
+			| serde_json::Value::Object(schema) => schema,
+			| _ => return,
+		};
+
+		if let Some(reference) = schema.get("$ref").and_then(|reference| reference.as_str()) {
+			match reference.strip_prefix('#').and_then(|pointer| self.root.pointer(pointer)) {
+				| Some(referenced_schema) => self.check(referenced_schema, value, path, errors),
+				| None => errors.push((path.clone(), format!("référence « {reference} » introuvable"))),
+			}
+		}
+
+		if let Some(schemas) = schema.get("allOf").and_then(|schemas| schemas.as_array()) {
+			for schema in schemas {
+				self.check(schema, value, path, errors);
+			}
+		}
+
+		for keyword in ["anyOf", "oneOf"] {
+			let Some(schemas) = schema.get(keyword).and_then(|schemas| schemas.as_array()) else {
+				continue;
+			};
+			let matches = schemas.iter().filter(|schema| self.is_valid(schema, value)).count();
+			if matches == 0 || (keyword == "oneOf" && matches > 1) {
+				errors.push((path.clone(), format!("la valeur {value} ne correspond à aucun des schémas attendus")));
+			}
+		}
+
+		if let Some(variants) = schema.get("enum").and_then(|variants| variants.as_array()) {
+			if !variants.iter().any(|variant| self.equals(variant, value)) {
+				let variants = variants.iter().map(|variant| variant.to_string()).collect::<Vec<_>>();
+				errors.push((
+					path.clone(),
+					format!("la valeur {value} n'est pas l'une de : {}", variants.join(", ")),
+				));
+			}
+		}
+
+		if let Some(constant) = schema.get("const") {
+			if !self.equals(constant, value) {
+				errors.push((path.clone(), format!("la valeur {value} est différente de {constant}")));
+			}
+		}
+
+		if let Some(types) = schema.get("type") {
+			let types = match types {
+				| serde_json::Value::String(ty) => vec![ty.as_str()],
+				| serde_json::Value::Array(types) => types.iter().filter_map(|ty| ty.as_str()).collect(),
+				| _ => Vec::new(),
+			};
+			if !types.is_empty() && !types.iter().any(|ty| self.is_type(ty, value)) {
+				errors.push((
+					path.clone(),
+					format!("type invalide, attendu « {} », obtenu {value}", types.join(" | ")),
+				));
+				return;
+			}
+		}
+
+		match value {
+			| serde_json::Value::Object(map) => self.check_object(schema, map, path, errors),
+			| serde_json::Value::Array(items) => self.check_array(schema, items, path, errors),
+			| _ => self.check_scalar(schema, value, path, errors),
+		}
+	}
+
+	fn check_object(
+		&self,
+		schema: &serde_json::Map<String, serde_json::Value>,
+		map: &serde_json::Map<String, serde_json::Value>,
+		path: &mut Vec<String>,
+		errors: &mut Vec<SchemaError>,
+	)
+	{
+		let properties = schema.get("properties").and_then(|properties| properties.as_object());
+
+		if let Some(required) = schema.get("required").and_then(|required| required.as_array()) {
+			for key in required.iter().filter_map(|key| key.as_str()) {
+				if !map.contains_key(key) {
+					path.push(key.to_owned());
+					errors.push((path.clone(), String::from("clé requise manquante")));
+					path.pop();
+				}
+			}
+		}
+
+		for (key, value) in map {
+			path.push(key.clone());
+			match properties.and_then(|properties| properties.get(key)) {
+				| Some(property_schema) => self.check(property_schema, value, path, errors),
+				| None => {
+					match schema.get("additionalProperties") {
+						| Some(serde_json::Value::Bool(false)) => {
+							errors.push((path.clone(), String::from("clé inconnue")));
+						}
+						| Some(additional_schema) => self.check(additional_schema, value, path, errors),
+						| None => {}
+					}
+				}
+			}
+			path.pop();
+		}
+	}
+
+	fn check_array(
+		&self,
+		schema: &serde_json::Map<String, serde_json::Value>,
+		items: &[serde_json::Value],
+		path: &mut Vec<String>,
+		errors: &mut Vec<SchemaError>,
+	)
+	{
+		let len = items.len() as f64;
+		if let Some(min) = schema.get("minItems").and_then(|min| min.as_f64()) {
+			if len < min {
+				errors.push((path.clone(), format!("au moins {min} élément(s) attendu(s)")));
+			}
+		}
+		if let Some(max) = schema.get("maxItems").and_then(|max| max.as_f64()) {
+			if len > max {
+				errors.push((path.clone(), format!("au plus {max} élément(s) attendu(s)")));
+			}
+		}
+
+		if let Some(items_schema) = schema.get("items") {
+			for (index, item) in items.iter().enumerate() {
+				path.push(index.to_string());
+				self.check(items_schema, item, path, errors);
+				path.pop();
+			}
+		}
+	}
+
+	fn check_scalar(
+		&self,
+		schema: &serde_json::Map<String, serde_json::Value>,
+		value: &serde_json::Value,
+		path: &[String],
+		errors: &mut Vec<SchemaError>,
+	)
+	{
+		let bound = |keyword: &str| schema.get(keyword).and_then(|bound| bound.as_f64());
+
+		if let Some(number) = self.as_number(value) {
+			let bounds = [
+				("minimum", bound("minimum").filter(|min| number < *min)),
+				("maximum", bound("maximum").filter(|max| number > *max)),
+				("exclusiveMinimum", bound("exclusiveMinimum").filter(|min| number <= *min)),
+				("exclusiveMaximum", bound("exclusiveMaximum").filter(|max| number >= *max)),
+			];
+			for (keyword, limit) in bounds {
+				if let Some(limit) = limit {
+					errors.push((path.to_vec(), format!("la valeur {number} ne respecte pas « {keyword}: {limit} »")));
+				}
+			}
+		}
+
+		if let Some(string) = value.as_str() {
+			let len = string.chars().count() as f64;
+			if let Some(min) = bound("minLength").filter(|min| len < *min) {
+				errors.push((path.to_vec(), format!("au moins {min} caractère(s) attendu(s)")));
+			}
+			if let Some(max) = bound("maxLength").filter(|max| len > *max) {
+				errors.push((path.to_vec(), format!("au plus {max} caractère(s) attendu(s)")));
+			}
+			if let Some(pattern) = schema.get("pattern").and_then(|pattern| pattern.as_str()) {
+				match regex::Regex::new(pattern) {
+					| Ok(regex) if regex.is_match(string) => {}
+					| Ok(_) => errors.push((path.to_vec(), format!("la valeur ne respecte pas le motif « {pattern} »"))),
+					| Err(err) => errors.push((path.to_vec(), format!("motif « {pattern} » invalide : {err}"))),
+				}
+			}
+		}
+	}
+
+	fn is_valid(&self, schema: &serde_json::Value, value: &serde_json::Value) -> bool
+	{
+		let mut errors = Vec::new();
+		self.check(schema, value, &mut Vec::new(), &mut errors);
+		errors.is_empty()
+	}
+
+	fn is_type(&self, ty: &str, value: &serde_json::Value) -> bool
+	{
+		match (ty, value) {
+			| ("null", serde_json::Value::Null) => true,
+			| ("boolean", serde_json::Value::Bool(_)) => true,
+			| ("object", serde_json::Value::Object(_)) => true,
+			| ("array", serde_json::Value::Array(_)) => true,
+			| ("string", serde_json::Value::String(_)) => true,
+			| ("number", _) => self.as_number(value).is_some(),
+			| ("integer", _) => self.as_number(value).is_some_and(|number| number.fract() == 0.0),
+			| ("boolean", serde_json::Value::String(string)) => self.lenient && string.parse::<bool>().is_ok(),
+			| ("null", serde_json::Value::String(string)) => self.lenient && string.is_empty(),
+			| _ => false,
+		}
+	}
+
+	fn as_number(&self, value: &serde_json::Value) -> Option<f64>
+	{
+		match value {
+			| serde_json::Value::Number(number) => number.as_f64(),
+			| serde_json::Value::String(string) if self.lenient => string.parse().ok(),
+			| _ => None,
+		}
+	}
+
+	fn equals(&self, expected: &serde_json::Value, value: &serde_json::Value) -> bool
+	{
+		match (expected, value) {
+			| (serde_json::Value::String(_), _) | (_, serde_json::Value::Array(_) | serde_json::Value::Object(_)) => {
+				expected == value
+			}
+			| (_, serde_json::Value::String(string)) if self.lenient => {
+				serde_json::from_str::<serde_json::Value>(string).is_ok_and(|parsed| parsed == *expected)
+			}
+			| _ => expected == value,
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl fmt::Display for SchemaMismatch
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "{}", self.file.display())?;
+		if let Some(line) = self.line {
+			write!(f, ":{line}")?;
+		}
+		if !self.key.is_empty() {
+			write!(f, ": {}", self.key)?;
+		}
+		write!(f, ": {}", self.message)
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Les clés d'un fichier d'environnement sont insensibles à la casse.
+fn lowercase_keys(value: serde_json::Value) -> serde_json::Value
+{
+	match value {
+		| serde_json::Value::Object(map) => {
+			serde_json::Value::Object(map.into_iter().map(|(key, value)| (key.to_lowercase(), value)).collect())
+		}
+		| value => value,
+	}
+}
+
+/// Numéro de ligne (à partir de 1) d'une clé dans le contenu d'un fichier.
+/// Chaque segment du chemin est recherché à partir de la ligne du segment
+/// précédent ; lorsqu'un segment est introuvable, la ligne du dernier segment
+/// trouvé est retournée.
+pub(super) fn locate(content: &str, extension: lexa_fs::Extension, key: &[String]) -> Option<usize>
+{
+	let lines = content.lines().collect::<Vec<_>>();
+	let mut found = None;
+	let mut start = 0;
+
+	for segment in key.iter().filter(|segment| segment.parse::<usize>().is_err()) {
+		let matches = |line: &str| {
+			let line = line.trim_start().trim_start_matches("- ");
+			match extension {
+				| lexa_fs::Extension::ENV => line.to_lowercase().starts_with(&format!("{segment}=")),
+				| lexa_fs::Extension::JSON => line.starts_with(&format!("\"{segment}\"")),
+				| lexa_fs::Extension::TOML => {
+					line.split(['=', ']', '.']).any(|part| part.trim().trim_matches(['[', '"']) == segment)
+				}
+				| lexa_fs::Extension::YAML => {
+					line.starts_with(&format!("{segment}:")) || line.starts_with(&format!("\"{segment}\":"))
+				}
+			}
+		};
+
+		let Some(index) = lines[start..].iter().position(|line| matches(line)) else {
+			break;
+		};
+		start += index;
+		found = Some(start + 1);
+		start += 1;
+	}
+
+	found
+}
+
+/// Valide les fichiers et retourne une [KernelError::Schema] contenant toutes
+/// les erreurs.
+pub(crate) fn validate_schemas(schemas: &ConfigSchemas, settings: &KernelSettings) -> Result<(), KernelError>
+{
+	let mismatches = schemas.validate(settings);
+	if mismatches.is_empty() {
+		return Ok(());
+	}
+	Err(KernelError::Schema(mismatches))
+}
+
+
+#[cfg(test)]
+mod tests
+{
+	use serde_json::json;
+
+	use super::*;
+	use crate::process::ProcessMode;
+
+	/// Les clés en erreur d'une valeur validée par un schéma.
+	fn check(schema: serde_json::Value, value: serde_json::Value, lenient: bool) -> Vec<String>
+	{
+		let mut errors = Vec::new();
+		Validator { root: &schema, lenient }.check(&schema, &value, &mut Vec::new(), &mut errors);
+		errors.into_iter().map(|(key, _)| key.join(".")).collect()
+	}
+
+	#[test]
+	fn test_validator()
+	{
+		let cases = [
+			// type
+			(json!({ "type": "string" }), json!("a"), vec![]),
+			(json!({ "type": "string" }), json!(1), vec![""]),
+			(json!({ "type": ["string", "null"] }), json!(null), vec![]),
+			(json!({ "type": "integer" }), json!(1.0), vec![]),
+			(json!({ "type": "integer" }), json!(1.5), vec![""]),
+			(json!({ "type": "boolean" }), json!("true"), vec![""]),
+			// required
+			(json!({ "required": ["host", "port"] }), json!({ "host": "h" }), vec!["port"]),
+			(json!({ "required": ["host"] }), json!({ "host": "h" }), vec![]),
+			// enum / const
+			(json!({ "enum": ["dev", "prod"] }), json!("dev"), vec![]),
+			(json!({ "enum": ["dev", "prod"] }), json!("local"), vec![""]),
+			(json!({ "const": 1 }), json!(2), vec![""]),
+			// minimum / maximum
+			(json!({ "minimum": 1, "maximum": 10 }), json!(1), vec![]),
+			(json!({ "minimum": 1, "maximum": 10 }), json!(0), vec![""]),
+			(json!({ "minimum": 1, "maximum": 10 }), json!(11), vec![""]),
+			(json!({ "exclusiveMinimum": 1 }), json!(1), vec![""]),
+			(json!({ "exclusiveMaximum": 10 }), json!(10), vec![""]),
+			(json!({ "minLength": 2, "maxLength": 3 }), json!("é"), vec![""]),
+			(json!({ "minLength": 2, "maxLength": 3 }), json!("abcd"), vec![""]),
+			// pattern
+			(json!({ "pattern": "^[a-z]+$" }), json!("host"), vec![]),
+			(json!({ "pattern": "^[a-z]+$" }), json!("Host"), vec![""]),
+			(json!({ "pattern": "(" }), json!("host"), vec![""]),
+			// items
+			(json!({ "items": { "type": "integer" } }), json!([1, "2", 3]), vec!["1"]),
+			(json!({ "minItems": 1, "maxItems": 2 }), json!([]), vec![""]),
+			(json!({ "minItems": 1, "maxItems": 2 }), json!([1, 2, 3]), vec![""]),
+			// additionalProperties
+			(
+				json!({ "properties": { "host": {} }, "additionalProperties": false }),
+				json!({ "host": "h", "port": 80 }),
+				vec!["port"],
+			),
+			(
+				json!({ "properties": { "host": {} }, "additionalProperties": { "type": "string" } }),
+				json!({ "host": 1, "user": "u", "port": 80 }),
+				vec!["port"],
+			),
+			(json!({ "properties": { "host": {} } }), json!({ "port": 80 }), vec![]),
+			// $ref
+			(
+				json!({
+					"properties": { "server": { "$ref": "#/$defs/server" } },
+					"$defs": { "server": { "properties": { "port": { "type": "integer" } } } }
+				}),
+				json!({ "server": { "port": "80" } }),
+				vec!["server.port"],
+			),
+			(json!({ "$ref": "#/$defs/missing" }), json!(1), vec![""]),
+			// allOf / anyOf / oneOf
+			(json!({ "allOf": [{ "minimum": 1 }, { "maximum": 2 }] }), json!(3), vec![""]),
+			(json!({ "anyOf": [{ "type": "string" }, { "type": "integer" }] }), json!(1), vec![]),
+			(json!({ "oneOf": [{ "type": "number" }, { "type": "integer" }] }), json!(1), vec![""]),
+			// booléens
+			(json!(true), json!(1), vec![]),
+			(json!(false), json!(1), vec![""]),
+		];
+
+		for (schema, value, expected) in cases {
+			assert_eq!(check(schema.clone(), value.clone(), false), expected, "{schema} / {value}");
+		}
+	}
+
+	#[test]
+	fn test_validator_lenient()
+	{
+		let schema = json!({
+			"properties": {
+				"port": { "type": "integer", "maximum": 100 },
+				"debug": { "type": "boolean" },
+				"mode": { "enum": [1, 2] }
+			}
+		});
+
+		assert!(check(schema.clone(), json!({ "port": "80", "debug": "true", "mode": "1" }), true).is_empty());
+		assert_eq!(check(schema.clone(), json!({ "port": "800" }), true), ["port"]);
+		assert_eq!(check(schema, json!({ "port": "a" }), true), ["port"]);
+	}
+
+	#[test]
+	fn test_validate_locates_mismatches()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		fs::write(dir.path().join("server.yml"), "host: localhost\nport: 80\n").unwrap();
+		fs::write(dir.path().join("server.local.yml"), "port: http\n").unwrap();
+
+		let mut settings = KernelSettings::new(dir.path(), ProcessMode::LOCAL);
+		settings.directory.set_config_directory(dir.path());
+
+		let mut schemas = ConfigSchemas::default();
+		schemas.insert_config(
+			"server",
+			json!({
+				"required": ["host", "port", "tls"],
+				"properties": { "host": { "type": "string" }, "port": { "type": "integer" } }
+			}),
+		);
+
+		let mismatches = schemas.validate(&settings);
+		assert_eq!(
+			mismatches
+				.iter()
+				.map(|mismatch| (mismatch.file.clone(), mismatch.line, mismatch.key.as_str()))
+				.collect::<Vec<_>>(),
+			[
+				(dir.path().join("server.local.yml"), None, "tls"),
+				(dir.path().join("server.local.yml"), Some(1), "port"),
+			]
+		);
+	}
+}
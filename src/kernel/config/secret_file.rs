@@ -0,0 +1,128 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::{fs, io, path};
+
+// -------- //
+// Constant //
+// -------- //
+
+/// Suffixe des variables d'environnement dont la valeur est le chemin d'un
+/// fichier secret (ex: `DB_PASSWORD_FILE=/run/secrets/db`).
+pub const SECRET_FILE_SUFFIX: &str = "_FILE";
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Comportement du kernel face aux fichiers secrets lisibles par tous les
+/// utilisateurs du système.
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum SecretFilePermissions
+{
+	/// Le fichier est lu, un avertissement est émis.
+	#[default]
+	Warn,
+	/// Le fichier est refusé.
+	Refuse,
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Lit la valeur d'un fichier secret (ex: `/run/secrets/db`). Les fins de
+/// ligne finales sont supprimées.
+///
+/// Sur les systèmes Unix, un fichier lisible par tous les utilisateurs est
+/// signalé ou refusé, en fonction de `permissions`.
+pub fn read_secret_file(filepath: impl AsRef<path::Path>, permissions: SecretFilePermissions) -> io::Result<String>
+{
+	let filepath = filepath.as_ref();
+
+	#[cfg(unix)]
+	{
+		use std::os::unix::fs::PermissionsExt;
+
+		let mode = fs::metadata(filepath)?.permissions().mode();
+		if mode & 0o004 != 0 {
+			let reason = format!(
+				"le fichier secret « {} » est lisible par tous les utilisateurs (permissions {:o}).",
+				filepath.display(),
+				mode & 0o777,
+			);
+			match permissions {
+				| SecretFilePermissions::Warn => log::warn!("{reason}"),
+				| SecretFilePermissions::Refuse => {
+					return Err(io::Error::new(io::ErrorKind::PermissionDenied, reason));
+				}
+			}
+		}
+	}
+
+	#[cfg(not(unix))]
+	let _ = permissions;
+
+	let mut value = fs::read_to_string(filepath)?;
+	let len = value.trim_end_matches(['\n', '\r']).len();
+	value.truncate(len);
+	Ok(value)
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	#[test]
+	fn test_read_secret_file_trims_line_endings()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		let filepath = dir.path().join("db");
+		fs::write(&filepath, "hunter2\r\n\n").unwrap();
+		restrict(&filepath);
+
+		assert_eq!(read_secret_file(&filepath, SecretFilePermissions::Refuse).unwrap(), "hunter2");
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn test_read_secret_file_permissions()
+	{
+		use std::os::unix::fs::PermissionsExt;
+
+		let dir = tempfile::tempdir().unwrap();
+		let filepath = dir.path().join("db");
+		fs::write(&filepath, "hunter2").unwrap();
+		fs::set_permissions(&filepath, fs::Permissions::from_mode(0o644)).unwrap();
+
+		assert_eq!(read_secret_file(&filepath, SecretFilePermissions::Warn).unwrap(), "hunter2");
+		let err = read_secret_file(&filepath, SecretFilePermissions::Refuse).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+
+		restrict(&filepath);
+		assert_eq!(read_secret_file(&filepath, SecretFilePermissions::Refuse).unwrap(), "hunter2");
+	}
+
+	/// Retire les permissions des autres utilisateurs.
+	fn restrict(filepath: &path::Path)
+	{
+		#[cfg(unix)]
+		{
+			use std::os::unix::fs::PermissionsExt;
+			fs::set_permissions(filepath, fs::Permissions::from_mode(0o600)).unwrap();
+		}
+		#[cfg(not(unix))]
+		let _ = filepath;
+	}
+}
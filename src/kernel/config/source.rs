@@ -0,0 +1,350 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::sync::OnceLock;
+use std::{fs, io, path};
+
+use super::env::set_path;
+use super::loader;
+
+// --------- //
+// Interface //
+// --------- //
+
+/// Source de configuration, autre que les fichiers du répertoire de
+/// configuration (voir [Kernel::with_config_source()](crate::Kernel::with_config_source)).
+///
+/// D'autres stockages (base de données locale, service distant, ...)
+/// PEUVENT être pris en charge en implémentant cette interface.
+pub trait ConfigSource
+	: Send + Sync
+{
+	/// Nom de la source, utilisé dans les messages (ex: `memory`).
+	fn name(&self) -> &str;
+
+	/// Chemin de la source, lorsqu'elle en a un.
+	fn location(&self) -> Option<&path::Path>
+	{
+		None
+	}
+
+	/// Valeur d'une configuration, ou [None] lorsque la source ne définit
+	/// pas cette configuration.
+	fn load(&self, config_name: &str) -> io::Result<Option<serde_json::Value>>;
+
+	/// Est-ce que les valeurs de la source sont des chaînes de caractères
+	/// brutes (ex: le contenu d'un fichier), converties dans le type attendu
+	/// lors de la dé-sérialisation (ex: `"20"` pour un `u16`)? Par défaut,
+	/// les valeurs sont typées.
+	fn raw_values(&self) -> bool
+	{
+		false
+	}
+}
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Configurations stockées en mémoire, utile pour les tests.
+#[derive(Debug)]
+#[derive(Default)]
+pub struct MemoryConfigSource
+{
+	configs: HashMap<String, serde_json::Value>,
+}
+
+/// Configurations embarquées dans le binaire, par exemple via
+/// [include_str!()] :
+///
+/// ```ignore
+/// EmbeddedConfigSource::default()
+///     .with("logger", include_str!("../config/logger.yml"), LoaderExtension::YAML)
+/// ```
+#[derive(Debug)]
+#[derive(Default)]
+pub struct EmbeddedConfigSource
+{
+	configs: HashMap<String, (&'static str, lexa_fs::Extension)>,
+}
+
+/// Configurations lues sur l'entrée standard. Le document lu est un objet
+/// dont chaque clé est un nom de configuration. L'entrée standard n'est lue
+/// qu'une seule fois.
+#[derive(Debug)]
+pub struct StdinConfigSource
+{
+	format: lexa_fs::Extension,
+	document: OnceLock<Result<serde_json::Value, String>>,
+}
+
+/// Configurations lues dans un répertoire contenant un fichier par clé, à la
+/// manière d'une ConfigMap Kubernetes : le fichier `database.pool.size`
+/// définit la clé `pool.size` de la configuration `database`. Le contenu de
+/// chaque fichier est la valeur brute de la clé, sans le saut de ligne final,
+/// convertie dans le type attendu (voir [ConfigSource::raw_values()]). Les
+/// fichiers cachés sont ignorés.
+#[derive(Debug)]
+pub struct KeyPerFileConfigSource
+{
+	directory: path::PathBuf,
+}
+
+/// Configurations lues dans un fichier clé-valeur, dont chaque ligne
+/// `database.pool.size = 20` définit la clé `pool.size` de la configuration
+/// `database`, avec une valeur brute (voir [ConfigSource::raw_values()]). Les
+/// lignes vides et celles qui commencent par `#` sont ignorées.
+#[derive(Debug)]
+pub struct KeyValueFileConfigSource
+{
+	filepath: path::PathBuf,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl MemoryConfigSource
+{
+	/// Ajoute une configuration.
+	pub fn with(mut self, config_name: impl Into<String>, config: impl serde::Serialize) -> Self
+	{
+		self.insert(config_name, config);
+		self
+	}
+
+	/// Ajoute ou remplace une configuration.
+	pub fn insert(&mut self, config_name: impl Into<String>, config: impl serde::Serialize)
+	{
+		let config = serde_json::to_value(config).expect("Configuration sérialisable");
+		self.configs.insert(config_name.into(), config);
+	}
+}
+
+impl EmbeddedConfigSource
+{
+	/// Ajoute le contenu d'une configuration et son format.
+	pub fn with(
+		mut self,
+		config_name: impl Into<String>,
+		content: &'static str,
+		format: impl Into<lexa_fs::Extension>,
+	) -> Self
+	{
+		self.configs.insert(config_name.into(), (content, format.into()));
+		self
+	}
+}
+
+impl StdinConfigSource
+{
+	pub fn new(format: impl Into<lexa_fs::Extension>) -> Self
+	{
+		Self {
+			format: format.into(),
+			document: Default::default(),
+		}
+	}
+}
+
+impl KeyPerFileConfigSource
+{
+	pub fn new(directory: impl Into<path::PathBuf>) -> Self
+	{
+		Self {
+			directory: directory.into(),
+		}
+	}
+}
+
+impl KeyValueFileConfigSource
+{
+	pub fn new(filepath: impl Into<path::PathBuf>) -> Self
+	{
+		Self {
+			filepath: filepath.into(),
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl ConfigSource for MemoryConfigSource
+{
+	fn name(&self) -> &str
+	{
+		"memory"
+	}
+
+	fn load(&self, config_name: &str) -> io::Result<Option<serde_json::Value>>
+	{
+		Ok(self.configs.get(config_name).cloned())
+	}
+}
+
+impl ConfigSource for EmbeddedConfigSource
+{
+	fn name(&self) -> &str
+	{
+		"embedded"
+	}
+
+	fn load(&self, config_name: &str) -> io::Result<Option<serde_json::Value>>
+	{
+		self.configs
+			.get(config_name)
+			.map(|(content, format)| loader::parse(content, *format))
+			.transpose()
+	}
+}
+
+impl ConfigSource for StdinConfigSource
+{
+	fn name(&self) -> &str
+	{
+		"stdin"
+	}
+
+	fn load(&self, config_name: &str) -> io::Result<Option<serde_json::Value>>
+	{
+		let document = self.document.get_or_init(|| {
+			let mut content = String::new();
+			io::stdin().read_to_string(&mut content).map_err(|err| err.to_string())?;
+			loader::parse(&content, self.format).map_err(|err| err.to_string())
+		});
+
+		match document {
+			| Ok(document) => Ok(document.get(config_name).cloned()),
+			| Err(err) => Err(io::Error::new(io::ErrorKind::InvalidData, err.as_str())),
+		}
+	}
+}
+
+impl ConfigSource for KeyPerFileConfigSource
+{
+	fn name(&self) -> &str
+	{
+		"key-per-file"
+	}
+
+	fn location(&self) -> Option<&path::Path>
+	{
+		Some(&self.directory)
+	}
+
+	fn load(&self, config_name: &str) -> io::Result<Option<serde_json::Value>>
+	{
+		if !self.directory.is_dir() {
+			return Ok(None);
+		}
+
+		let mut entries = Vec::new();
+		for entry in fs::read_dir(&self.directory)? {
+			let filepath = entry?.path();
+			let Some(filename) = filepath.file_name().and_then(|filename| filename.to_str()) else {
+				continue;
+			};
+			if filename.starts_with('.') || !filepath.is_file() {
+				continue;
+			}
+			if let Some(key) = config_key(filename, config_name) {
+				entries.push((key, filepath));
+			}
+		}
+
+		// NOTE: l'ordre des fichiers d'un répertoire n'est pas garanti.
+		entries.sort();
+
+		let mut config = None;
+		for (key, filepath) in entries {
+			let content = fs::read_to_string(&filepath)?;
+			let raw_value = content.strip_suffix('\n').unwrap_or(&content);
+			let raw_value = raw_value.strip_suffix('\r').unwrap_or(raw_value);
+			set_path(config.get_or_insert(serde_json::Value::Null), &key, raw_value).map_err(|err| {
+				io::Error::new(io::ErrorKind::InvalidData, format!("« {} » : {err}", filepath.display()))
+			})?;
+		}
+		Ok(config)
+	}
+
+	fn raw_values(&self) -> bool
+	{
+		true
+	}
+}
+
+impl ConfigSource for KeyValueFileConfigSource
+{
+	fn name(&self) -> &str
+	{
+		"key-value-file"
+	}
+
+	fn location(&self) -> Option<&path::Path>
+	{
+		Some(&self.filepath)
+	}
+
+	fn load(&self, config_name: &str) -> io::Result<Option<serde_json::Value>>
+	{
+		if !self.filepath.is_file() {
+			return Ok(None);
+		}
+
+		let content = fs::read_to_string(&self.filepath)?;
+
+		let mut config = None;
+		for (n, line) in content.lines().enumerate() {
+			let line = line.trim();
+			if line.is_empty() || line.starts_with('#') {
+				continue;
+			}
+
+			let Some((key, raw_value)) = line.split_once('=') else {
+				return Err(io::Error::new(
+					io::ErrorKind::InvalidData,
+					format!("« {}:{} » : `=` manquant", self.filepath.display(), n + 1),
+				));
+			};
+
+			if let Some(key) = config_key(key.trim(), config_name) {
+				set_path(config.get_or_insert(serde_json::Value::Null), &key, raw_value.trim()).map_err(|err| {
+					io::Error::new(
+						io::ErrorKind::InvalidData,
+						format!("« {}:{} » : {err}", self.filepath.display(), n + 1),
+					)
+				})?;
+			}
+		}
+		Ok(config)
+	}
+
+	fn raw_values(&self) -> bool
+	{
+		true
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Chemin d'une clé `<config_name>.<key>`, lorsqu'elle appartient à la
+/// configuration.
+fn config_key(name: &str, config_name: &str) -> Option<Vec<String>>
+{
+	let key = name.strip_prefix(config_name)?.strip_prefix('.')?;
+	let key: Vec<_> = key.split('.').map(ToOwned::to_owned).collect();
+	(!key.iter().any(String::is_empty)).then_some(key)
+}
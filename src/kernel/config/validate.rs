@@ -0,0 +1,194 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::fmt;
+
+use crate::KernelError;
+
+// --------- //
+// Interface //
+// --------- //
+
+/// Validation sémantique d'une configuration, exécutée par le kernel après
+/// la dé-sérialisation lorsqu'elle est demandée (voir
+/// [Kernel::fetch_validated_config()](crate::Kernel::fetch_validated_config)
+/// et [WatchedConfig::validated()](crate::WatchedConfig::validated)).
+///
+/// Cette interface est optionnelle : les paramètres de l'application adapter,
+/// les variables d'environnement et les configurations des modules sont
+/// validés via leur propre interface
+/// ([ApplicationAdapterSettingsInterface::validate_settings()](crate::ApplicationAdapterSettingsInterface::validate_settings),
+/// [ApplicationEnvInterface::validate_env()](crate::ApplicationEnvInterface::validate_env),
+/// [KernelModule::validate_config()](crate::KernelModule::validate_config)).
+///
+/// La validation DOIT collecter toutes les violations, plutôt que de
+/// s'arrêter à la première, afin qu'elles soient retournées ensemble dans une
+/// [KernelError::InvalidConfig]. Par défaut, aucune validation n'est
+/// effectuée.
+///
+/// ```text
+/// impl ValidateConfig for ServerConfig
+/// {
+///     fn validate(&self, violations: &mut ConfigViolations)
+///     {
+///         violations.check("port", self.port != 0, "le port ne peut pas être nul");
+///         violations.nested("tls", &self.tls);
+///     }
+/// }
+/// ```
+pub trait ValidateConfig
+{
+	/// Ajoute les violations de la configuration.
+	fn validate(&self, _: &mut ConfigViolations) {}
+}
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Violations d'une configuration, chacune associée au chemin de sa clé
+/// (ex: `server.tls.cert_path`).
+#[derive(Debug)]
+#[derive(Default)]
+pub struct ConfigViolations
+{
+	/// Chemin de la clé courante, voir [ConfigViolations::nested()].
+	prefix: Vec<String>,
+	violations: Vec<ConfigViolation>,
+}
+
+/// Violation d'une clé de configuration.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct ConfigViolation
+{
+	/// Chemin de la clé (ex: `server.tls.cert_path`).
+	pub path: String,
+	/// Raison de la violation.
+	pub message: String,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ConfigViolations
+{
+	/// Ajoute une violation pour une clé, relative au chemin courant.
+	pub fn push(&mut self, key: impl AsRef<str>, message: impl ToString)
+	{
+		let path = self
+			.prefix
+			.iter()
+			.map(String::as_str)
+			.chain(Some(key.as_ref()).filter(|key| !key.is_empty()))
+			.collect::<Vec<_>>()
+			.join(".");
+
+		self.violations.push(ConfigViolation {
+			path,
+			message: message.to_string(),
+		});
+	}
+
+	/// Ajoute une violation pour une clé lorsque la condition n'est pas
+	/// respectée.
+	pub fn check(&mut self, key: impl AsRef<str>, condition: bool, message: impl ToString)
+	{
+		if !condition {
+			self.push(key, message);
+		}
+	}
+
+	/// Valide une valeur imbriquée ; les chemins de ses violations sont
+	/// préfixés par la clé.
+	pub fn nested(&mut self, key: impl ToString, value: &impl ValidateConfig)
+	{
+		self.prefix.push(key.to_string());
+		value.validate(self);
+		self.prefix.pop();
+	}
+
+	/// Est-ce qu'aucune violation n'a été ajoutée?
+	pub fn is_empty(&self) -> bool
+	{
+		self.violations.is_empty()
+	}
+
+	/// Nombre de violations.
+	pub fn len(&self) -> usize
+	{
+		self.violations.len()
+	}
+
+	/// Les violations, dans leur ordre d'ajout.
+	pub fn iter(&self) -> impl Iterator<Item = &ConfigViolation>
+	{
+		self.violations.iter()
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl fmt::Display for ConfigViolation
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "{}: {}", self.path, self.message)
+	}
+}
+
+impl fmt::Display for ConfigViolations
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		for violation in self.iter() {
+			write!(f, "\n\t\t- {violation}")?;
+		}
+		Ok(())
+	}
+}
+
+impl ValidateConfig for ()
+{
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Exécute la validation d'une configuration. Retourne une
+/// [KernelError::InvalidConfig] contenant toutes les violations.
+pub fn validate_config(config_name: impl ToString, config: &impl ValidateConfig) -> Result<(), KernelError>
+{
+	validate_config_with(config_name, |violations| config.validate(violations))
+}
+
+/// Voir [validate_config()]. Les violations sont ajoutées par une fonction.
+pub(crate) fn validate_config_with(
+	config_name: impl ToString,
+	validate: impl FnOnce(&mut ConfigViolations),
+) -> Result<(), KernelError>
+{
+	let mut violations = ConfigViolations::default();
+	validate(&mut violations);
+
+	if violations.is_empty() {
+		return Ok(());
+	}
+
+	Err(KernelError::InvalidConfig {
+		name: config_name.to_string(),
+		violations,
+	})
+}
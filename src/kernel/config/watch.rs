@@ -0,0 +1,424 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, SystemTime};
+use std::{fs, io, path};
+
+use super::{deserialize_config, resolve_config, validate_config, ConfigValue, ResolvedConfig, ValidateConfig};
+use crate::kernel::settings::KernelSettings;
+use crate::logger::signal::LoggerWriter;
+use crate::process::{register_hangup, ShutdownToken};
+use crate::{HookError, KernelError};
+
+// ---- //
+// Type //
+// ---- //
+
+type ConfigValidator<T> = Box<dyn Fn(&str, &T) -> Result<(), KernelError> + Send + Sync>;
+
+type ConfigSubscriber<T> = Box<dyn Fn(&Arc<T>) + Send + Sync>;
+
+/// Date de modification et taille des fichiers d'une configuration.
+type ConfigFingerprint = BTreeMap<path::PathBuf, Option<(SystemTime, u64)>>;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Configuration surveillée par le kernel.
+///
+/// La configuration est rechargée lorsque l'un de ses fichiers est modifié
+/// (ou créé), ou lorsque le processus reçoit le signal SIGHUP. Les fichiers
+/// surveillés sont ceux lus lors du dernier chargement (couches, fichiers
+/// inclus, configurations référencées via `${config:...}`, sources, fichiers
+/// secrets) ainsi que les fichiers candidats des couches. La nouvelle valeur
+/// est validée (voir [WatchedConfig::validated()] et
+/// [WatchedConfig::with_validator()]) puis publiée de manière atomique :
+/// les lecteurs obtiennent un instantané via [WatchedConfig::get()] et les
+/// abonnés sont notifiés. En cas d'erreur, l'ancienne valeur est conservée.
+///
+/// Cette structure est clonable ; tous ses clones partagent le même
+/// instantané. La surveillance s'arrête lorsque tous les clones ont été
+/// libérés ou lorsque l'arrêt de l'application est demandé.
+pub struct WatchedConfig<T>
+{
+	inner: Arc<WatchedConfigInner<T>>,
+}
+
+struct WatchedConfigInner<T>
+{
+	/// Nom de la configuration.
+	name: String,
+	/// Paramètres du kernel au moment de la surveillance.
+	settings: KernelSettings,
+	/// Valeur de la configuration actuelle, partagée avec la configuration
+	/// effective du kernel (voir
+	/// [Kernel::dump_config()](crate::Kernel::dump_config)). Le verrou
+	/// sérialise également les rechargements.
+	value: Arc<Mutex<ConfigValue>>,
+	/// Instantané de la configuration actuelle.
+	snapshot: RwLock<Arc<T>>,
+	/// Fonctions de validation des nouvelles valeurs.
+	validators: Mutex<Vec<ConfigValidator<T>>>,
+	/// Fonctions appelées après chaque rechargement.
+	subscribers: Mutex<Vec<ConfigSubscriber<T>>>,
+	/// Fichiers surveillés, mis à jour à chaque chargement.
+	files: Mutex<Vec<path::PathBuf>>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<T> WatchedConfig<T>
+where
+	T: serde::de::DeserializeOwned,
+	T: Send + Sync + 'static,
+{
+	/// Charge la configuration.
+	pub(crate) fn new(config_name: &str, settings: KernelSettings) -> Result<Self, KernelError>
+	{
+		let ResolvedConfig {
+			value,
+			lenient_paths,
+			files,
+		} = Self::resolve(config_name, &settings)?;
+		let (config, secret_paths) = Self::deserialize(config_name, value.clone(), &lenient_paths)?;
+
+		Ok(Self {
+			inner: Arc::new(WatchedConfigInner {
+				name: config_name.to_owned(),
+				settings,
+				value: Arc::new(Mutex::new(ConfigValue { value, secret_paths })),
+				snapshot: RwLock::new(Arc::new(config)),
+				validators: Default::default(),
+				subscribers: Default::default(),
+				files: Mutex::new(files),
+			}),
+		})
+	}
+
+	/// Surveille les fichiers de la configuration en tâche de fond.
+	pub(crate) fn watch(&self, writer: LoggerWriter, token: ShutdownToken, interval: Duration) -> io::Result<()>
+	{
+		let hangup = Arc::new(AtomicBool::new(false));
+		let registration = register_hangup(&hangup)?;
+
+		let mut last_fingerprint = fingerprint(&self.inner.files());
+		let inner = Arc::downgrade(&self.inner);
+
+		std::thread::Builder::new()
+			.name(format!("lexa-kernel-config-{}", self.inner.name))
+			.spawn(move || {
+				let _registration = registration;
+
+				while !token.wait_timeout(interval) {
+					let Some(inner) = inner.upgrade() else {
+						break;
+					};
+
+					let current_fingerprint = fingerprint(&inner.files());
+					let hangup = hangup.swap(false, Ordering::SeqCst);
+					if !hangup && current_fingerprint == last_fingerprint {
+						continue;
+					}
+
+					let watched_config = Self { inner };
+					match watched_config.reload() {
+						| Ok(true) => log::info!("La configuration a été rechargée."),
+						| Ok(false) => {}
+						| Err(err) => writer.send_error(err),
+					}
+					// NOTE: les fichiers surveillés PEUVENT avoir changé lors
+					//       du rechargement (ex: nouvelle inclusion).
+					last_fingerprint = fingerprint(&watched_config.inner.files());
+				}
+			})?;
+
+		Ok(())
+	}
+
+	/// Nom de la configuration.
+	pub fn name(&self) -> &str
+	{
+		&self.inner.name
+	}
+
+	/// Valeur de la configuration actuelle, mise à jour à chaque
+	/// rechargement.
+	pub(crate) fn shared_value(&self) -> Arc<Mutex<ConfigValue>>
+	{
+		Arc::clone(&self.inner.value)
+	}
+
+	/// Instantané de la configuration actuelle.
+	pub fn get(&self) -> Arc<T>
+	{
+		Arc::clone(&self.inner.snapshot.read().unwrap_or_else(|err| err.into_inner()))
+	}
+
+	/// Ajoute une fonction de validation. La valeur actuelle est validée
+	/// immédiatement (une erreur est retournée si elle est invalide), puis
+	/// les valeurs des prochains rechargements : une nouvelle valeur invalide
+	/// n'est pas publiée.
+	pub fn with_validator(
+		self,
+		validator: impl Fn(&T) -> Result<(), HookError> + Send + Sync + 'static,
+	) -> Result<Self, KernelError>
+	{
+		self.push_validator(Box::new(move |config_name, config| {
+			validator(config).map_err(|err| {
+				KernelError::Config {
+					name: config_name.to_owned(),
+					reason: io::Error::new(io::ErrorKind::InvalidData, err),
+				}
+			})
+		}))
+	}
+
+	/// Valide la configuration via [ValidateConfig], de la même manière que
+	/// [WatchedConfig::with_validator()].
+	pub fn validated(self) -> Result<Self, KernelError>
+	where
+		T: ValidateConfig,
+	{
+		self.push_validator(Box::new(|config_name, config| validate_config(config_name, config)))
+	}
+
+	fn push_validator(self, validator: ConfigValidator<T>) -> Result<Self, KernelError>
+	{
+		validator(&self.inner.name, &self.get())?;
+		self.inner
+			.validators
+			.lock()
+			.unwrap_or_else(|err| err.into_inner())
+			.push(validator);
+		Ok(self)
+	}
+
+	/// Ajoute une fonction appelée avec la nouvelle valeur après chaque
+	/// rechargement.
+	pub fn subscribe(&self, subscriber: impl Fn(&Arc<T>) + Send + Sync + 'static)
+	{
+		self.inner
+			.subscribers
+			.lock()
+			.unwrap_or_else(|err| err.into_inner())
+			.push(Box::new(subscriber));
+	}
+
+	/// Recharge la configuration. Retourne `true` si une nouvelle valeur a
+	/// été publiée, `false` si la configuration n'a pas changé. En cas
+	/// d'erreur, l'ancienne valeur est conservée.
+	pub fn reload(&self) -> Result<bool, KernelError>
+	{
+		let name = &self.inner.name;
+		let mut current = self.inner.value.lock().unwrap_or_else(|err| err.into_inner());
+
+		let ResolvedConfig {
+			value,
+			lenient_paths,
+			files,
+		} = Self::resolve(name, &self.inner.settings)?;
+		*self.inner.files.lock().unwrap_or_else(|err| err.into_inner()) = files;
+
+		if current.value == value {
+			return Ok(false);
+		}
+
+		let (config, secret_paths) = Self::deserialize(name, value.clone(), &lenient_paths)?;
+
+		for validator in self.inner.validators.lock().unwrap_or_else(|err| err.into_inner()).iter() {
+			validator(name, &config)?;
+		}
+
+		let config = Arc::new(config);
+		*self.inner.snapshot.write().unwrap_or_else(|err| err.into_inner()) = Arc::clone(&config);
+		*current = ConfigValue { value, secret_paths };
+		drop(current);
+
+		for subscriber in self.inner.subscribers.lock().unwrap_or_else(|err| err.into_inner()).iter() {
+			subscriber(&config);
+		}
+
+		Ok(true)
+	}
+
+	fn resolve(config_name: &str, settings: &KernelSettings) -> Result<ResolvedConfig, KernelError>
+	{
+		resolve_config(config_name, settings).map_err(|reason| {
+			KernelError::Config {
+				name: config_name.to_owned(),
+				reason,
+			}
+		})
+	}
+
+	fn deserialize(
+		config_name: &str,
+		value: serde_json::Value,
+		lenient_paths: &[Vec<String>],
+	) -> Result<(T, Vec<Vec<String>>), KernelError>
+	{
+		deserialize_config(config_name, value, lenient_paths).map_err(|reason| {
+			KernelError::Config {
+				name: config_name.to_owned(),
+				reason,
+			}
+		})
+	}
+}
+
+impl<T> WatchedConfigInner<T>
+{
+	/// Les fichiers surveillés.
+	fn files(&self) -> Vec<path::PathBuf>
+	{
+		self.files.lock().unwrap_or_else(|err| err.into_inner()).clone()
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<T> Clone for WatchedConfig<T>
+{
+	fn clone(&self) -> Self
+	{
+		Self {
+			inner: Arc::clone(&self.inner),
+		}
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+fn fingerprint(files: &[path::PathBuf]) -> ConfigFingerprint
+{
+	files
+		.iter()
+		.map(|filepath| {
+			let metadata = fs::metadata(filepath)
+				.ok()
+				.map(|metadata| (metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH), metadata.len()));
+			(filepath.clone(), metadata)
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::process::ProcessMode;
+
+	#[derive(Debug)]
+	#[derive(serde::Deserialize)]
+	struct App
+	{
+		host: String,
+		port: u16,
+	}
+
+	impl ValidateConfig for App
+	{
+		fn validate(&self, violations: &mut crate::ConfigViolations)
+		{
+			violations.check("host", !self.host.is_empty(), "ne peut pas être vide");
+		}
+	}
+
+	fn settings(config_directory: &path::Path) -> KernelSettings
+	{
+		let mut settings = KernelSettings::new(config_directory, ProcessMode::LOCAL);
+		settings.directory.set_config_directory(config_directory);
+		settings
+	}
+
+	fn config_directory() -> tempfile::TempDir
+	{
+		let dir = tempfile::tempdir().unwrap();
+		fs::write(dir.path().join("app.yml"), "$include: common.yml\nhost: ${config:db.host}\n").unwrap();
+		fs::write(dir.path().join("common.yml"), "port: 80\n").unwrap();
+		fs::write(dir.path().join("db.yml"), "host: localhost\n").unwrap();
+		dir
+	}
+
+	#[test]
+	fn test_watched_files()
+	{
+		let dir = config_directory();
+		let watched_config = WatchedConfig::<App>::new("app", settings(dir.path())).unwrap();
+
+		let files = watched_config.inner.files();
+		for filename in ["app.yml", "app.local.yml", "common.yml", "db.yml", "db.local.yml"] {
+			assert!(files.contains(&dir.path().join(filename)), "{filename} n'est pas surveillé");
+		}
+	}
+
+	#[test]
+	fn test_reload_dependencies()
+	{
+		let dir = config_directory();
+		let watched_config = WatchedConfig::<App>::new("app", settings(dir.path())).unwrap();
+		assert!(!watched_config.reload().unwrap());
+
+		fs::write(dir.path().join("common.yml"), "port: 8080\n").unwrap();
+		fs::write(dir.path().join("db.yml"), "host: db\n").unwrap();
+		assert!(watched_config.reload().unwrap());
+		assert_eq!(watched_config.get().port, 8080);
+		assert_eq!(watched_config.get().host, "db");
+	}
+
+	#[test]
+	fn test_with_validator_validates_current_value()
+	{
+		let dir = config_directory();
+		let watched_config = WatchedConfig::<App>::new("app", settings(dir.path())).unwrap();
+
+		let privileged = |app: &App| -> Result<(), HookError> {
+			if app.port < 1024 {
+				return Err("port privilégié".into());
+			}
+			Ok(())
+		};
+		assert!(watched_config.clone().with_validator(privileged).is_err());
+
+		fs::write(dir.path().join("common.yml"), "port: 8080\n").unwrap();
+		assert!(watched_config.reload().unwrap());
+		let watched_config = watched_config.with_validator(privileged).unwrap();
+
+		fs::write(dir.path().join("common.yml"), "port: 80\n").unwrap();
+		assert!(watched_config.reload().is_err());
+		assert_eq!(watched_config.get().port, 8080);
+	}
+
+	#[test]
+	fn test_validated()
+	{
+		let dir = config_directory();
+		let watched_config = WatchedConfig::<App>::new("app", settings(dir.path())).unwrap().validated().unwrap();
+
+		fs::write(dir.path().join("db.yml"), "host: ''\n").unwrap();
+		assert!(matches!(watched_config.reload(), Err(KernelError::InvalidConfig { .. })));
+		assert_eq!(watched_config.get().host, "localhost");
+
+		assert!(matches!(
+			WatchedConfig::<App>::new("app", settings(dir.path())).unwrap().validated(),
+			Err(KernelError::InvalidConfig { .. })
+		));
+	}
+}
@@ -18,4 +18,253 @@
 pub enum KernelError
 {
 	LexaEnv(#[from] lexa_env::Error),
+	ProcessMode(#[from] crate::process::ProcessModeError),
+	Command(#[from] crate::KernelCommandError),
+	/// Un répertoire de l'application n'est pas défini.
+	#[error("\n\t[{}]: le répertoire {0} n'est pas défini.", std::any::type_name::<Self>())]
+	DirectoryUndefined(&'static str),
+	/// Un répertoire de l'application n'existe pas.
+	#[error(
+		"\n\t[{}]: le répertoire {name} « {} » n'existe pas.",
+		std::any::type_name::<Self>(),
+		path.display()
+	)]
+	DirectoryNotFound
+	{
+		name: &'static str,
+		path: std::path::PathBuf,
+	},
+	/// Un fichier de configuration n'a pas pu être chargé.
+	#[error(
+		"\n\t[{}]: la configuration « {name} » n'a pas pu être chargée. Raison « {reason} »",
+		std::any::type_name::<Self>()
+	)]
+	Config
+	{
+		name: String,
+		reason: std::io::Error,
+	},
+	/// Une configuration ne respecte pas ses règles de validation.
+	#[error(
+		"\n\t[{}]: la configuration « {name} » est invalide ({} violation(s)) :{violations}",
+		std::any::type_name::<Self>(),
+		violations.len()
+	)]
+	InvalidConfig
+	{
+		name: String,
+		violations: crate::ConfigViolations,
+	},
+	/// Des fichiers ne respectent pas leur schéma JSON.
+	#[error(
+		"\n\t[{}]: {} erreur(s) de schéma :{}",
+		std::any::type_name::<Self>(),
+		.0.len(),
+		.0.iter().map(|mismatch| format!("\n\t\t- {mismatch}")).collect::<String>()
+	)]
+	Schema(Vec<crate::SchemaMismatch>),
+	/// Un fichier n'a pas pu être écrit.
+	#[error(
+		"\n\t[{}]: le fichier « {} » n'a pas pu être écrit. Raison « {reason} »",
+		std::any::type_name::<Self>(),
+		path.display()
+	)]
+	Write
+	{
+		path: std::path::PathBuf,
+		reason: std::io::Error,
+	},
+	/// Des variables d'environnement sont manquantes, invalides ou inconnues.
+	#[error(
+		"\n\t[{}]: {} variable(s) d'environnement manquante(s), invalide(s) ou inconnue(s) :{0}",
+		std::any::type_name::<Self>(),
+		.0.len()
+	)]
+	InvalidEnv(crate::EnvReport),
+	/// Un fichier secret n'a pas pu être lu.
+	#[error(
+		"\n\t[{}]: le fichier secret « {} » n'a pas pu être lu. Raison « {reason} »",
+		std::any::type_name::<Self>(),
+		path.display()
+	)]
+	SecretFile
+	{
+		path: std::path::PathBuf,
+		reason: std::io::Error,
+	},
+	/// Une variable d'environnement est définie à la fois directement et via
+	/// un fichier secret (`<VAR>_FILE`).
+	#[error(
+		"\n\t[{}]: les variables d'environnement « {0} » et « {0}_FILE » NE DOIVENT PAS être définies en même \
+		 temps.",
+		std::any::type_name::<Self>()
+	)]
+	SecretFileConflict(String),
+	/// Des fichiers d'exemple des variables d'environnement sont manquants,
+	/// différents des variables d'environnement de l'application ou ne sont
+	/// plus attendus.
+	#[error(
+		"\n\t[{}]: {} fichier(s) d'exemple des variables d'environnement à régénérer :{}",
+		std::any::type_name::<Self>(),
+		.0.len(),
+		.0.iter().map(|path| format!("\n\t\t- {}", path.display())).collect::<String>()
+	)]
+	EnvExampleOutdated(Vec<std::path::PathBuf>),
+	/// Les paramètres de l'application adapter n'ont pas pu être chargés.
+	#[error(
+		"\n\t[{}]: les paramètres de l'application adapter « {filename} » n'ont pas pu être chargés. Raison « \
+		 {reason} »",
+		std::any::type_name::<Self>()
+	)]
+	Adapter
+	{
+		filename: &'static str,
+		reason: std::io::Error,
+	},
+	/// Le fichier des variables d'environnement n'a pas pu être chargé.
+	#[error(
+		"\n\t[{}]: les variables d'environnement « {} » n'ont pas pu être chargées. Raison « {reason} »",
+		std::any::type_name::<Self>(),
+		path.display()
+	)]
+	Env
+	{
+		path: std::path::PathBuf,
+		reason: Box<KernelError>,
+	},
+	/// Le logger n'a pas pu être initialisé.
+	#[error("\n\t[{}]: le logger n'a pas pu être initialisé. Raison « {0} »", std::any::type_name::<Self>())]
+	Logger(String),
+	/// Une méthode d'initialisation du kernel n'a pas été appelée.
+	#[error(
+		"\n\t[{}]: veuillez appeler la méthode « Kernel#{0} » lors de l'initialisation de l'application.",
+		std::any::type_name::<Self>()
+	)]
+	NotIncluded(&'static str),
+	/// Une fonction de cycle de vie a échoué.
+	#[error(
+		"\n\t[{}]: la fonction de la phase « {phase} » a échoué. Raison « {reason} »",
+		std::any::type_name::<Self>()
+	)]
+	Hook
+	{
+		phase: crate::HookPhase,
+		reason: crate::HookError,
+	},
+	/// Un module a échoué.
+	#[error(
+		"\n\t[{}]: le module « {name} » a échoué lors de la phase « {phase} ». Raison « {reason} »",
+		std::any::type_name::<Self>()
+	)]
+	Module
+	{
+		name: &'static str,
+		phase: &'static str,
+		reason: crate::HookError,
+	},
+	/// Un module a été ajouté plusieurs fois.
+	#[error("\n\t[{}]: le module « {0} » a été ajouté plusieurs fois.", std::any::type_name::<Self>())]
+	ModuleDuplicate(&'static str),
+	/// Un module dépend d'un module qui n'a pas été ajouté.
+	#[error(
+		"\n\t[{}]: le module « {module} » dépend du module « {dependency} » qui n'a pas été ajouté.",
+		std::any::type_name::<Self>()
+	)]
+	ModuleDependency
+	{
+		module: &'static str,
+		dependency: &'static str,
+	},
+	/// Les dépendances des modules forment un cycle.
+	#[error(
+		"\n\t[{}]: dépendance circulaire entre les modules « {} ».",
+		std::any::type_name::<Self>(),
+		.0.join(" -> ")
+	)]
+	ModuleCycle(Vec<&'static str>),
+	/// Aucun service n'est enregistré pour ce type.
+	#[error("\n\t[{}]: aucun service n'est enregistré pour le type « {0} ».", std::any::type_name::<Self>())]
+	ServiceNotFound(&'static str),
+	/// La construction d'un service a échoué.
+	#[error(
+		"\n\t[{}]: la construction du service « {name} » a échoué. Raison « {reason} »",
+		std::any::type_name::<Self>()
+	)]
+	Service
+	{
+		name: &'static str,
+		reason: crate::HookError,
+	},
+	/// L'application adapter a échoué.
+	#[error("\n\t[{}]: l'application a échoué. Raison « {0} »", std::any::type_name::<Self>())]
+	Run(#[from] crate::HookError),
+	/// L'application adapter s'est terminée avec un code de sortie d'échec.
+	#[error(
+		"\n\t[{}]: l'application s'est terminée avec le code de sortie d'échec {0}.",
+		std::any::type_name::<Self>()
+	)]
+	ExitStatus(u8),
+	/// Plusieurs erreurs sont survenues lors de l'initialisation du kernel.
+	/// Voir [Kernel::try_build()](crate::Kernel::try_build).
+	#[error(
+		"\n\t[{}]: {} erreur(s) lors de l'initialisation du kernel :{}",
+		std::any::type_name::<Self>(),
+		.0.len(),
+		.0.iter().map(ToString::to_string).collect::<String>()
+	)]
+	Multiple(Vec<KernelError>),
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl KernelError
+{
+	/// Code de sortie utilisé pour les erreurs de configuration (voir
+	/// `EX_CONFIG` de `sysexits.h`).
+	pub const CONFIG_EXIT_CODE: u8 = 78;
+
+	/// Code de sortie du processus associé à l'erreur :
+	///
+	/// - le code de sortie de l'application adapter, s'il y en a un ;
+	/// - celui de la première erreur, pour [KernelError::Multiple] ;
+	/// - [KernelError::CONFIG_EXIT_CODE] pour les erreurs liées aux
+	///   répertoires, à la configuration, au mode d'exécution, aux variables
+	///   d'environnement et aux fichiers secrets ;
+	/// - `1` dans les autres cas.
+	pub fn exit_code(&self) -> std::process::ExitCode
+	{
+		std::process::ExitCode::from(self.code())
+	}
+
+	/// Quitte le processus avec le code de sortie associé à l'erreur. Voir
+	/// [KernelError::exit_code()].
+	pub(crate) fn exit(&self) -> !
+	{
+		std::process::exit(self.code().into())
+	}
+
+	/// Voir [KernelError::exit_code()].
+	fn code(&self) -> u8
+	{
+		match self {
+			| Self::ExitStatus(code) => *code,
+			| Self::Multiple(errors) => errors.first().map_or(1, Self::code),
+			| Self::LexaEnv(_)
+			| Self::ProcessMode(_)
+			| Self::DirectoryUndefined(_)
+			| Self::DirectoryNotFound { .. }
+			| Self::Config { .. }
+			| Self::InvalidConfig { .. }
+			| Self::Schema(_)
+			| Self::InvalidEnv(_)
+			| Self::SecretFile { .. }
+			| Self::SecretFileConflict(_)
+			| Self::EnvExampleOutdated(_)
+			| Self::Adapter { .. }
+			| Self::Env { .. } => Self::CONFIG_EXIT_CODE,
+			| _ => 1,
+		}
+	}
 }
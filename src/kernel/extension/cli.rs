@@ -8,7 +8,7 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
-use crate::{ApplicationCLIInterface, Kernel};
+use crate::{ApplicationCLIInterface, Kernel, KernelError};
 
 // --------- //
 // Interface // -> Extension
@@ -28,6 +28,10 @@ where
 	/// [ApplicationCLIExtension::include_cli_args()].
 	fn cli_args(&self) -> UserCLI;
 
+	/// Voir [ApplicationCLIExtension::cli_args()]. Retourne une erreur si les
+	/// arguments de la CLI n'ont pas été inclus.
+	fn try_cli_args(&self) -> Result<UserCLI, KernelError>;
+
 	/// Inclut les arguments de la CLI.
 	fn include_cli_args(self) -> Self;
 }
@@ -56,10 +60,14 @@ where
 {
 	fn cli_args(&self) -> UserCLI
 	{
-		self.cli_args.clone().expect(
-			"Veuillez appeler la méthode « Kernel#include_cli_args » lors de \
-			 l'initialisation de l'application.",
-		)
+		self.try_cli_args().unwrap_or_else(|err| panic!("{err}"))
+	}
+
+	fn try_cli_args(&self) -> Result<UserCLI, KernelError>
+	{
+		self.cli_args
+			.clone()
+			.ok_or(KernelError::NotIncluded("include_cli_args"))
 	}
 
 	fn include_cli_args(mut self) -> Self
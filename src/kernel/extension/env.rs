@@ -8,7 +8,19 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
-use crate::{ApplicationEnvInterface, Kernel};
+use std::any::{self, Any, TypeId};
+
+use crate::kernel::config::{
+	check_env_examples,
+	env_file_cascade,
+	env_segment,
+	record_env_vars,
+	validate_config_with,
+	write_env_examples,
+};
+use crate::settings::KernelSettings;
+use crate::settings::EnvLoadingStrategy;
+use crate::{ApplicationEnvInterface, Kernel, KernelError};
 
 // --------- //
 // Interface //
@@ -27,22 +39,93 @@ where
 	/// Définit le répertoire des variables d'environnement.
 	fn define_env_directory(self, dir: impl Into<std::path::PathBuf>) -> Self;
 
+	/// Définit la stratégie de chargement des variables d'environnement (voir
+	/// [EnvLoadingStrategy]).
+	fn define_env_strategy(self, strategy: EnvLoadingStrategy) -> Self;
+
+	/// Définit le préfixe des variables d'environnement du processus qui
+	/// définissent les champs des variables d'environnement de l'application.
+	/// Par défaut, ce préfixe est dérivé du nom de l'application (`lexa-app`
+	/// donne `LEXA_APP_`).
+	fn define_env_prefix(self, prefix: impl Into<String>) -> Self;
+
+	/// Définit le répertoire des fichiers secrets (ex: `/run/secrets`), dont
+	/// chaque fichier `<field>` définit la variable d'environnement `<FIELD>`
+	/// lorsque celle-ci n'est pas définie.
+	fn define_secrets_directory(self, dir: impl Into<std::path::PathBuf>) -> Self;
+
 	/// Les variables d'environnement de l'application. En supposant qu'ils ont
 	/// été définie par la fonction d'implémentation
 	/// [ApplicationEnvExtension::include_env_vars()] ou
 	/// [ApplicationEnvExtension::with_env_vars()].
 	fn env(&self) -> UserEnv;
 
-	/// Inclut les variables d'environnement de l'application à partir d'un
-	/// fichier d'environnement (ex: .env). Ce fichier est résolu en fonction du
-	/// mode d'exécution.
+	/// Voir [ApplicationEnvExtension::env()]. Retourne une erreur si les
+	/// variables d'environnement n'ont pas été incluses.
+	fn try_env(&self) -> Result<UserEnv, KernelError>;
+
+	/// Inclut les variables d'environnement de l'application à partir des
+	/// fichiers d'environnement (ex: `.env`, `.env.local`, `.env.dev`,
+	/// `.env.dev.local`), résolus en fonction du mode d'exécution et fusionnés
+	/// du moins prioritaire au plus prioritaire (voir
+	/// [ApplicationEnvInterface::with_suffix()]) :
+	///
+	///   1. `<FILENAME>` ;
+	///   2. `<FILENAME>.<mode>` ;
+	///   3. `<FILENAME>.local` ;
+	///   4. `<FILENAME>.<mode>.local`.
+	///
+	/// Chacun de ces fichiers est optionnel, mais au moins l'un d'entre eux
+	/// DOIT exister, sauf si la stratégie de chargement inclut les variables
+	/// d'environnement du processus (voir
+	/// [ApplicationEnvExtension::define_env_strategy()]). Les fichiers
+	/// `.local` NE DOIVENT PAS être versionnés et sont ignorés en mode
+	/// [TEST](crate::process::ProcessMode::TEST).
+	///
+	/// En cas d'erreur, celle-ci est signalée via le
+	/// [LoggerSignal](crate::Kernel::signal), avec le rapport de chacune des
+	/// variables manquantes, invalides ou inconnues (voir
+	/// [EnvReport](crate::EnvReport)), puis le programme est arrêté avec le
+	/// code de sortie [KernelError::CONFIG_EXIT_CODE].
 	fn include_env_vars(self) -> Self;
 
+	/// Voir [ApplicationEnvExtension::include_env_vars()]. Retourne une
+	/// erreur si le répertoire ou les fichiers d'environnement ne peuvent pas
+	/// être chargés.
+	fn try_include_env_vars(self) -> Result<Self, KernelError>;
+
 	/// Inclut les variables d'environnement de l'application à partir d'un
 	/// fichier d'environnement explicitement donné en argument.
+	///
+	/// En cas d'erreur, voir [ApplicationEnvExtension::include_env_vars()].
 	fn with_env_vars(self, env_filepath: impl AsRef<std::path::Path>) -> Self
 	where
 		UserEnv: serde::de::DeserializeOwned;
+
+	/// Voir [ApplicationEnvExtension::with_env_vars()]. Retourne une erreur
+	/// si le fichier d'environnement ne peut pas être chargé.
+	fn try_with_env_vars(self, env_filepath: impl AsRef<std::path::Path>) -> Result<Self, KernelError>
+	where
+		UserEnv: serde::de::DeserializeOwned;
+
+	/// Écrit les fichiers d'exemple des variables d'environnement de
+	/// l'application dans le répertoire des variables d'environnement, à partir
+	/// de la description de ses champs (voir
+	/// [ApplicationEnvInterface::describe_env()]) :
+	///
+	///   - `<FILENAME>.example` ;
+	///   - `<FILENAME>.<mode>.example`, pour chaque mode d'exécution dont les
+	///     champs diffèrent de ceux du fichier `<FILENAME>.example`.
+	///
+	/// Les fichiers `<FILENAME>.<mode>.example` qui ne sont plus attendus
+	/// sont supprimés. Retourne les chemins des fichiers écrits.
+	fn write_env_examples(&self) -> Result<Vec<std::path::PathBuf>, KernelError>;
+
+	/// Vérifie que les fichiers d'exemple des variables d'environnement (voir
+	/// [ApplicationEnvExtension::write_env_examples()]) sont à jour. Les
+	/// fichiers à régénérer sont retournés ensemble dans une
+	/// [KernelError::EnvExampleOutdated] (ex: pour la CI).
+	fn check_env_examples(&self) -> Result<(), KernelError>;
 }
 
 /// Interface adapter liée aux variables d'environnement.
@@ -58,6 +141,214 @@ pub trait ApplicationAdapterEnvInterface
 	fn set_env(&mut self, env: Self::Env);
 }
 
+/// Interface adapter liée aux variables d'environnement d'un espace de noms
+/// (voir [Kernel::include_namespaced_env_vars()]).
+///
+/// Ces variables d'environnement sont également enregistrées dans le
+/// conteneur de services (voir
+/// [ApplicationAdapterInterface::set_services()](crate::ApplicationAdapterInterface::set_services)).
+pub trait ApplicationAdapterNamespacedEnvInterface<NamespacedEnv>
+	: Sized
+where
+	NamespacedEnv: ApplicationEnvInterface,
+{
+	/// Définit les variables d'environnement de l'espace de noms pour
+	/// l'adapter. Par défaut, ces variables d'environnement sont ignorées.
+	fn set_namespaced_env(&mut self, _: NamespacedEnv) {}
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<A, UserEnv, C> Kernel<A, UserEnv, C>
+{
+	/// Chemins des fichiers d'environnement `<E::FILENAME>`, résolus en
+	/// fonction du mode d'exécution (voir [env_file_cascade()]) et de la
+	/// stratégie de chargement. Avec la stratégie
+	/// [EnvLoadingStrategy::FileOnly], lorsqu'aucun fichier n'existe, seul le
+	/// chemin du fichier `<E::FILENAME>` est retourné.
+	fn env_filepaths<E>(&self) -> Result<Vec<std::path::PathBuf>, KernelError>
+	where
+		E: ApplicationEnvInterface,
+	{
+		let env_directory = match self.settings.env.strategy {
+			| EnvLoadingStrategy::FileOnly => self.settings.directory.try_env()?,
+			| EnvLoadingStrategy::ProcessOnly => return Ok(Vec::new()),
+			| EnvLoadingStrategy::FileOverlaidByProcess => {
+				match self.settings.directory.try_env() {
+					| Ok(env_directory) => env_directory,
+					| Err(_) => return Ok(Vec::new()),
+				}
+			}
+		};
+		let env_filepaths = env_file_cascade(
+			env_directory,
+			E::FILENAME,
+			&E::with_suffix(&self.settings).to_string(),
+			self.settings.process_mode,
+		);
+		if env_filepaths.is_empty() && self.settings.env.strategy == EnvLoadingStrategy::FileOnly {
+			return Ok(vec![env_directory.join(E::FILENAME)]);
+		}
+		Ok(env_filepaths)
+	}
+
+	/// Signale l'erreur de chargement des variables d'environnement et
+	/// arrête le programme avec le code de sortie
+	/// [KernelError::CONFIG_EXIT_CODE], plutôt que de poursuivre sans
+	/// variables d'environnement.
+	fn abort_env(&self, err: KernelError) -> !
+	{
+		self.logger_signal
+			.send_critical_with_exit_code(err, i32::from(KernelError::CONFIG_EXIT_CODE))
+	}
+
+	/// Récupère les variables d'environnement `E`, du fichier le moins
+	/// prioritaire au plus prioritaire, et les valide. Les variables dont
+	/// elles sont issues sont enregistrées pour la configuration effective
+	/// (voir [Kernel::dump_config()]).
+	fn fetch_env_vars<E>(&self, env_filepaths: &[&std::path::Path], settings: &KernelSettings)
+		-> Result<E, KernelError>
+	where
+		E: ApplicationEnvInterface,
+	{
+		let env_filepath = env_filepaths.first().copied().unwrap_or(std::path::Path::new(E::FILENAME));
+		let (env_vars, recorded_env_vars) = record_env_vars(|| E::fetch(env_filepaths, settings));
+		let env_vars = env_vars.map_err(|err| {
+			KernelError::Env {
+				path: env_filepath.to_owned(),
+				reason: Box::new(err),
+			}
+		})?;
+		validate_config_with(env_filepath.display(), |violations| env_vars.validate_env(violations))?;
+
+		// NOTE: les valeurs des variables d'environnement NE DOIVENT PAS être
+		//       journalisées, elles peuvent être secrètes.
+		log::debug!(
+			"Variables d'environnement « {} » chargées depuis {:?}",
+			any::type_name::<E>(),
+			env_filepaths
+		);
+		if let Some(recorded_env_vars) = recorded_env_vars {
+			self.loaded_configs.insert_env(E::FILENAME, recorded_env_vars);
+		}
+
+		Ok(env_vars)
+	}
+}
+
+impl<A, UserEnv, C> Kernel<A, UserEnv, C>
+where
+	UserEnv: ApplicationEnvInterface,
+	A: ApplicationAdapterEnvInterface<Env = UserEnv>,
+{
+	/// Charge les variables d'environnement des fichiers d'environnement
+	/// résolus en fonction du mode d'exécution.
+	fn try_load_env_vars(&mut self) -> Result<(), KernelError>
+	{
+		let env_filepaths = self.env_filepaths::<UserEnv>()?;
+		let env_filepaths: Vec<_> = env_filepaths.iter().map(std::path::PathBuf::as_path).collect();
+		self.load_env_vars(&env_filepaths)
+	}
+
+	/// Charge les variables d'environnement, du fichier le moins prioritaire
+	/// au plus prioritaire, et les transmet à l'application adapter.
+	fn load_env_vars(&mut self, env_filepaths: &[&std::path::Path]) -> Result<(), KernelError>
+	{
+		let env_vars = self.fetch_env_vars::<UserEnv>(env_filepaths, &self.settings)?;
+		self.application_adapter.set_env(env_vars.clone());
+		self.env_vars.replace(env_vars);
+		Ok(())
+	}
+}
+
+impl<A, UserEnv, C> Kernel<A, UserEnv, C>
+{
+	/// Inclut les variables d'environnement d'un espace de noms (ex:
+	/// `DatabaseEnv` depuis les fichiers `.env.db`), en plus des variables
+	/// d'environnement de l'application. Les fichiers d'environnement sont
+	/// résolus à partir de `<NamespacedEnv::FILENAME>` et les variables du
+	/// processus à partir du préfixe de l'espace de noms (voir
+	/// [ApplicationEnvInterface::with_prefix()]), comme pour
+	/// [ApplicationEnvExtension::include_env_vars()]. Les variables d'un
+	/// espace de noms ne sont visibles ni des autres espaces de noms, ni de
+	/// l'application.
+	///
+	/// Ces variables d'environnement sont récupérables par leur type (voir
+	/// [Kernel::namespaced_env()]), transmises à l'application adapter et
+	/// enregistrées dans le conteneur de services.
+	///
+	/// En cas d'erreur, voir [ApplicationEnvExtension::include_env_vars()].
+	pub fn include_namespaced_env_vars<NamespacedEnv>(mut self) -> Self
+	where
+		NamespacedEnv: ApplicationEnvInterface + Send + Sync + 'static,
+		A: ApplicationAdapterNamespacedEnvInterface<NamespacedEnv>,
+	{
+		if let Err(err) = self.try_load_namespaced_env_vars::<NamespacedEnv>() {
+			self.abort_env(err);
+		}
+
+		self
+	}
+
+	/// Voir [Kernel::include_namespaced_env_vars()]. Retourne une erreur si
+	/// le répertoire ou les fichiers d'environnement ne peuvent pas être
+	/// chargés.
+	pub fn try_include_namespaced_env_vars<NamespacedEnv>(mut self) -> Result<Self, KernelError>
+	where
+		NamespacedEnv: ApplicationEnvInterface + Send + Sync + 'static,
+		A: ApplicationAdapterNamespacedEnvInterface<NamespacedEnv>,
+	{
+		self.try_load_namespaced_env_vars::<NamespacedEnv>()?;
+		Ok(self)
+	}
+
+	/// Les variables d'environnement d'un espace de noms. En supposant
+	/// qu'ils ont été définie par la fonction
+	/// [Kernel::include_namespaced_env_vars()].
+	pub fn namespaced_env<NamespacedEnv>(&self) -> NamespacedEnv
+	where
+		NamespacedEnv: ApplicationEnvInterface + 'static,
+	{
+		self.try_namespaced_env().unwrap_or_else(|err| panic!("{err}"))
+	}
+
+	/// Voir [Kernel::namespaced_env()]. Retourne une erreur si les variables
+	/// d'environnement de cet espace de noms n'ont pas été incluses.
+	pub fn try_namespaced_env<NamespacedEnv>(&self) -> Result<NamespacedEnv, KernelError>
+	where
+		NamespacedEnv: ApplicationEnvInterface + 'static,
+	{
+		self.namespaced_env_vars
+			.get(&TypeId::of::<NamespacedEnv>())
+			.and_then(|env_vars| env_vars.downcast_ref::<NamespacedEnv>())
+			.cloned()
+			.ok_or(KernelError::NotIncluded("include_namespaced_env_vars"))
+	}
+
+	/// Charge les variables d'environnement d'un espace de noms et les
+	/// transmet à l'application adapter et au conteneur de services.
+	fn try_load_namespaced_env_vars<NamespacedEnv>(&mut self) -> Result<(), KernelError>
+	where
+		NamespacedEnv: ApplicationEnvInterface + Send + Sync + 'static,
+		A: ApplicationAdapterNamespacedEnvInterface<NamespacedEnv>,
+	{
+		let env_filepaths = self.env_filepaths::<NamespacedEnv>()?;
+		let env_filepaths: Vec<_> = env_filepaths.iter().map(std::path::PathBuf::as_path).collect();
+		let mut settings = self.settings.clone();
+		settings.env.prefix = format!("{}{}_", self.settings.env.prefix, env_segment(NamespacedEnv::namespace()));
+		let env_vars = self.fetch_env_vars::<NamespacedEnv>(&env_filepaths, &settings)?;
+
+		self.application_adapter.set_namespaced_env(env_vars.clone());
+		self.services.singleton(env_vars.clone());
+		self.namespaced_env_vars
+			.insert(TypeId::of::<NamespacedEnv>(), Box::new(env_vars) as Box<dyn Any + Send + Sync>);
+
+		Ok(())
+	}
+}
+
 // -------------- //
 // Implémentation // -> Interface
 // -------------- //
@@ -73,43 +364,216 @@ where
 		self
 	}
 
+	fn define_env_strategy(mut self, strategy: EnvLoadingStrategy) -> Self
+	{
+		self.settings.env.strategy = strategy;
+		self
+	}
+
+	fn define_env_prefix(mut self, prefix: impl Into<String>) -> Self
+	{
+		self.settings.env.prefix = prefix.into();
+		self
+	}
+
+	fn define_secrets_directory(mut self, dir: impl Into<std::path::PathBuf>) -> Self
+	{
+		self.settings.directory.set_secrets_directory(dir);
+		self
+	}
+
 	fn env(&self) -> UserEnv
 	{
-		self.env_vars.clone().expect(
-			"\nVeuillez appeler la méthode « Kernel#include_env_vars » lors de \
-			 l'initialisation de l'application.\n",
-		)
+		self.try_env().unwrap_or_else(|err| panic!("{err}"))
 	}
 
-	fn include_env_vars(self) -> Self
+	fn try_env(&self) -> Result<UserEnv, KernelError>
 	{
-		let env_filepath = format!(
-			"{}{}",
-			UserEnv::FILENAME,
-			UserEnv::with_suffix(&self.settings).to_string(),
-		);
-		let env_filepath = self.settings.directory.env_sudo().join(env_filepath);
-		self.with_env_vars(env_filepath)
+		self.env_vars
+			.clone()
+			.ok_or(KernelError::NotIncluded("include_env_vars"))
+	}
+
+	fn include_env_vars(mut self) -> Self
+	{
+		if let Err(err) = self.try_load_env_vars() {
+			self.abort_env(err);
+		}
+
+		self
+	}
+
+	fn try_include_env_vars(mut self) -> Result<Self, KernelError>
+	{
+		self.try_load_env_vars()?;
+		Ok(self)
 	}
 
 	fn with_env_vars(mut self, env_filepath: impl AsRef<std::path::Path>) -> Self
 	where
 		UserEnv: serde::de::DeserializeOwned,
 	{
-		match UserEnv::fetch_from_file(&env_filepath) {
-			| Ok(env_vars) => {
-				log::debug!("Variables d'environnement de l'application « {:#?} »", &env_vars);
-				self.application_adapter.set_env(env_vars.clone());
-				self.env_vars.replace(env_vars);
-			}
+		if let Err(err) = self.load_env_vars(&[env_filepath.as_ref()]) {
+			self.abort_env(err);
+		}
 
-			| Err(err) => {
-				let ef_s = env_filepath.as_ref().display();
-				let err_s = format!("Erreur liée aux variables d'environnement « {ef_s} » : {err}");
-				self.logger_signal.send_error(err_s);
-			}
+		self
+	}
+
+	fn try_with_env_vars(mut self, env_filepath: impl AsRef<std::path::Path>) -> Result<Self, KernelError>
+	where
+		UserEnv: serde::de::DeserializeOwned,
+	{
+		self.load_env_vars(&[env_filepath.as_ref()])?;
+		Ok(self)
+	}
+
+	fn write_env_examples(&self) -> Result<Vec<std::path::PathBuf>, KernelError>
+	{
+		write_env_examples::<UserEnv>(self.settings.directory.try_env()?, &self.settings)
+	}
+
+	fn check_env_examples(&self) -> Result<(), KernelError>
+	{
+		check_env_examples::<UserEnv>(self.settings.directory.try_env()?, &self.settings)
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::fs;
+
+	use super::*;
+	use crate::ApplicationAdapterInterface;
+
+	#[derive(Debug)]
+	#[derive(Clone)]
+	#[derive(serde::Deserialize)]
+	struct AppEnv
+	{
+		name: String,
+		db_host: Option<String>,
+	}
+
+	impl ApplicationEnvInterface for AppEnv
+	{
+		const FILENAME: &'static str = ".env";
+	}
+
+	#[derive(Debug)]
+	#[derive(Clone)]
+	#[derive(serde::Deserialize)]
+	struct DatabaseEnv
+	{
+		db_host: String,
+		name: Option<String>,
+	}
+
+	impl ApplicationEnvInterface for DatabaseEnv
+	{
+		const FILENAME: &'static str = ".env.db";
+	}
+
+	#[derive(Default)]
+	struct Adapter
+	{
+		env: Option<AppEnv>,
+	}
+
+	impl ApplicationAdapterInterface for Adapter
+	{
+		type Settings = ();
+
+		fn new(_: Self::Settings) -> Self
+		{
+			Self::default()
+		}
+	}
+
+	impl ApplicationAdapterEnvInterface for Adapter
+	{
+		type Env = AppEnv;
+
+		fn env(&self) -> &Self::Env
+		{
+			self.env.as_ref().unwrap()
+		}
+
+		fn set_env(&mut self, env: Self::Env)
+		{
+			self.env.replace(env);
+		}
+	}
+
+	impl ApplicationAdapterNamespacedEnvInterface<DatabaseEnv> for Adapter {}
+
+	#[test]
+	fn test_namespaced_env_vars_are_isolated()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		fs::write(dir.path().join(".env"), "NAME=app\n").unwrap();
+		fs::write(dir.path().join(".env.db"), "DB_HOST=db\n").unwrap();
+
+		let new_kernel = |strategy| {
+			Kernel::<Adapter, AppEnv>::new("test", "0.0.0", dir.path())
+				.define_env_directory(dir.path())
+				.define_env_strategy(strategy)
 		};
 
-		self
+		let kernel = new_kernel(EnvLoadingStrategy::FileOnly)
+			.try_include_env_vars()
+			.and_then(Kernel::try_include_namespaced_env_vars::<DatabaseEnv>)
+			.unwrap();
+		assert_eq!(kernel.env().db_host, None);
+		assert_eq!(kernel.namespaced_env::<DatabaseEnv>().name, None);
+
+		let kernel = new_kernel(EnvLoadingStrategy::FileOnly)
+			.try_include_namespaced_env_vars::<DatabaseEnv>()
+			.and_then(ApplicationEnvExtension::try_include_env_vars)
+			.unwrap();
+		assert_eq!(kernel.env().name, "app");
+		assert_eq!(kernel.env().db_host, None);
+		assert_eq!(kernel.namespaced_env::<DatabaseEnv>().db_host, "db");
+		assert_eq!(std::env::var_os("DB_HOST"), None);
+
+		let prefix = "LEXA_KERNEL_TEST_NAMESPACED_";
+		std::env::set_var(format!("{prefix}NAME"), "process");
+		std::env::set_var(format!("{prefix}DB_DB_HOST"), "process-db");
+
+		let kernel = new_kernel(EnvLoadingStrategy::FileOverlaidByProcess)
+			.define_env_prefix(prefix)
+			.try_include_env_vars()
+			.and_then(Kernel::try_include_namespaced_env_vars::<DatabaseEnv>)
+			.unwrap();
+		assert_eq!(kernel.env().name, "process");
+		assert_eq!(kernel.env().db_host, None);
+		assert_eq!(kernel.namespaced_env::<DatabaseEnv>().db_host, "process-db");
+		assert_eq!(kernel.namespaced_env::<DatabaseEnv>().name, None);
+	}
+
+	#[test]
+	fn test_dump_config_contains_the_loaded_env_vars()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		fs::write(dir.path().join(".env"), "NAME=file\nDB_HOST=db\n").unwrap();
+
+		let prefix = "LEXA_KERNEL_TEST_DUMP_";
+		std::env::set_var(format!("{prefix}NAME"), "process");
+
+		let new_kernel = |strategy| {
+			Kernel::<Adapter, AppEnv>::new("test", "0.0.0", dir.path())
+				.define_env_directory(dir.path())
+				.define_env_strategy(strategy)
+				.define_env_prefix(prefix)
+				.try_include_env_vars()
+				.unwrap()
+		};
+
+		let dump = new_kernel(EnvLoadingStrategy::FileOverlaidByProcess).dump_config();
+		assert_eq!(dump.env[".env"], serde_json::json!({ "NAME": "process", "DB_HOST": "db" }));
+
+		let dump = new_kernel(EnvLoadingStrategy::ProcessOnly).dump_config();
+		assert_eq!(dump.env[".env"], serde_json::json!({ "NAME": "process" }));
 	}
 }
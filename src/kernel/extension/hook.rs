@@ -0,0 +1,116 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use crate::{HookContext, HookError, HookFuture, HookPhase, HookPolicy, Kernel};
+
+// --------- //
+// Interface // -> Extension
+// --------- //
+
+/// Extension d'application pour les fonctions de cycle de vie.
+///
+/// Les phases sont exécutées dans l'ordre suivant lors du démarrage de
+/// l'application : [HookPhase::Boot], [HookPhase::BeforeRun], la fonction
+/// `run` de l'application adapter, [HookPhase::AfterRun] puis
+/// [HookPhase::Shutdown]. La phase [HookPhase::Error] est exécutée à chaque
+/// échec d'une fonction.
+///
+/// Les fonctions asynchrones ne sont exécutées que lorsque l'application est
+/// démarrée de manière asynchrone.
+pub trait ApplicationHookExtension
+	: Sized
+{
+	/// Ajoute une fonction de cycle de vie avec son comportement en cas
+	/// d'échec.
+	fn with_hook<Err>(
+		self,
+		phase: HookPhase,
+		policy: HookPolicy,
+		hook: impl FnMut(&HookContext) -> Result<(), Err> + Send + 'static,
+	) -> Self
+	where
+		Err: Into<HookError>;
+
+	/// Ajoute une fonction de cycle de vie asynchrone avec son comportement
+	/// en cas d'échec.
+	fn with_async_hook(
+		self,
+		phase: HookPhase,
+		policy: HookPolicy,
+		hook: impl FnMut(&HookContext) -> HookFuture + Send + 'static,
+	) -> Self;
+
+	/// Ajoute une fonction exécutée avant le démarrage de l'application.
+	fn on_boot<Err>(self, hook: impl FnMut(&HookContext) -> Result<(), Err> + Send + 'static) -> Self
+	where
+		Err: Into<HookError>,
+	{
+		self.with_hook(HookPhase::Boot, HookPhase::Boot.default_policy(), hook)
+	}
+
+	/// Ajoute une fonction exécutée juste avant l'appel à la fonction `run`
+	/// de l'application adapter.
+	fn on_before_run<Err>(self, hook: impl FnMut(&HookContext) -> Result<(), Err> + Send + 'static) -> Self
+	where
+		Err: Into<HookError>,
+	{
+		self.with_hook(HookPhase::BeforeRun, HookPhase::BeforeRun.default_policy(), hook)
+	}
+
+	/// Ajoute une fonction exécutée juste après l'appel à la fonction `run`
+	/// de l'application adapter.
+	fn on_after_run<Err>(self, hook: impl FnMut(&HookContext) -> Result<(), Err> + Send + 'static) -> Self
+	where
+		Err: Into<HookError>,
+	{
+		self.with_hook(HookPhase::AfterRun, HookPhase::AfterRun.default_policy(), hook)
+	}
+
+	/// Ajoute une fonction exécutée à chaque erreur du cycle de vie. L'erreur
+	/// est accessible via [HookContext::error()].
+	fn on_error<Err>(self, hook: impl FnMut(&HookContext) -> Result<(), Err> + Send + 'static) -> Self
+	where
+		Err: Into<HookError>,
+	{
+		self.with_hook(HookPhase::Error, HookPhase::Error.default_policy(), hook)
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<A, E, C> ApplicationHookExtension for Kernel<A, E, C>
+{
+	fn with_hook<Err>(
+		mut self,
+		phase: HookPhase,
+		policy: HookPolicy,
+		mut hook: impl FnMut(&HookContext) -> Result<(), Err> + Send + 'static,
+	) -> Self
+	where
+		Err: Into<HookError>,
+	{
+		self.hooks
+			.push_sync(phase, policy, move |context| hook(context).map_err(Into::into));
+		self
+	}
+
+	fn with_async_hook(
+		mut self,
+		phase: HookPhase,
+		policy: HookPolicy,
+		hook: impl FnMut(&HookContext) -> HookFuture + Send + 'static,
+	) -> Self
+	{
+		self.hooks.push_async(phase, policy, hook);
+		self
+	}
+}
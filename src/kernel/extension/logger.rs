@@ -8,7 +8,8 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
-use crate::kernel::Kernel;
+use crate::kernel::config::is_missing_config;
+use crate::kernel::{Kernel, KernelError};
 use crate::logger::settings::{LoggerSettings, LoggerSettingsPreset};
 use crate::process::ProcessMode;
 
@@ -26,8 +27,20 @@ pub trait ApplicationLoggerExtension
 	/// l'application](crate::kernel::settings::KernelSettings).
 	fn initialize_logger(self) -> Self;
 
+	/// Voir [ApplicationLoggerExtension::initialize_logger()]. Retourne une
+	/// erreur si le fichier de configuration du logger est invalide ou si le
+	/// logger n'a pas pu être initialisé.
+	fn try_initialize_logger(self) -> Result<Self, KernelError>;
+
 	/// Définit un logger avec ses paramètres.
 	fn with_logger(self, settings: impl Into<LoggerSettings>) -> Self;
+
+	/// Voir [ApplicationLoggerExtension::with_logger()]. Retourne une erreur
+	/// si le logger n'a pas pu être initialisé.
+	fn try_with_logger(
+		self,
+		settings: impl Into<LoggerSettings>,
+	) -> Result<Self, KernelError>;
 }
 
 // -------------- //
@@ -38,7 +51,7 @@ impl<A, E, C> ApplicationLoggerExtension for Kernel<A, E, C>
 {
 	fn initialize_logger(self) -> Self
 	{
-		let settings = match self.fetch_config(LoggerSettings::FILENAME) {
+		let settings = match self.fetch_validated_config(LoggerSettings::FILENAME) {
 			| Ok(logger_settings) => logger_settings,
 			| Err(err) => {
 				self.logger_signal.send_warning(format!(
@@ -56,10 +69,52 @@ impl<A, E, C> ApplicationLoggerExtension for Kernel<A, E, C>
 		self.with_logger(settings)
 	}
 
-	fn with_logger(self, settings: impl Into<LoggerSettings>) -> Self
+	fn try_initialize_logger(self) -> Result<Self, KernelError>
+	{
+		let settings = match self.fetch_validated_config(LoggerSettings::FILENAME) {
+			| Ok(logger_settings) => logger_settings,
+			| Err(KernelError::Config { reason, .. }) if is_missing_config(&reason) => {
+				LoggerSettings {
+					preset: LoggerSettingsPreset::Default,
+					..Default::default()
+				}
+			}
+			| Err(err) => return Err(err),
+		};
+
+		self.try_with_logger(settings)
+	}
+
+	fn with_logger(mut self, settings: impl Into<LoggerSettings>) -> Self
 	{
 		let settings = settings.into();
 
+		if let Err(err) = self.make_logger(&settings) {
+			self.logger_signal.send_error(err.to_string());
+			self.errors.push(err);
+		}
+
+		self
+	}
+
+	fn try_with_logger(
+		self,
+		settings: impl Into<LoggerSettings>,
+	) -> Result<Self, KernelError>
+	{
+		self.make_logger(&settings.into())?;
+		Ok(self)
+	}
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<A, E, C> Kernel<A, E, C>
+{
+	fn make_logger(&self, settings: &LoggerSettings) -> Result<(), KernelError>
+	{
 		#[cfg(not(feature = "tracing"))]
 		let level_based_on_process_mode = match self.settings.process_mode {
 			| ProcessMode::LOCAL => lexa_logger::LevelFilter::Debug,
@@ -76,14 +131,12 @@ impl<A, E, C> ApplicationLoggerExtension for Kernel<A, E, C>
 			| ProcessMode::TEST => tracing::level_filters::LevelFilter::TRACE,
 		};
 
-		if let Err(err) = settings.make_builder(level_based_on_process_mode) {
-			self.logger_signal
-				.send_error(format!("Erreur liée au logger. Raison « {err} »"));
-			return self;
-		}
+		settings
+			.make_builder(level_based_on_process_mode)
+			.map_err(|err| KernelError::Logger(err.to_string()))?;
 
 		log::debug!("Paramètres du logger « {settings:#?} »");
 
-		self
+		Ok(())
 	}
 }
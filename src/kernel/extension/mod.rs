@@ -10,10 +10,20 @@
 
 mod cli;
 mod env;
+mod hook;
 mod logger;
+mod module;
+mod schema;
+mod service;
+mod shutdown;
 mod startup;
 
 pub use self::cli::*;
 pub use self::env::*;
+pub use self::hook::*;
 pub use self::logger::*;
+pub use self::module::*;
+pub use self::schema::*;
+pub use self::service::*;
+pub use self::shutdown::*;
 pub use self::startup::*;
@@ -0,0 +1,37 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use crate::{Kernel, KernelModule};
+
+// --------- //
+// Interface // -> Extension
+// --------- //
+
+/// Extension d'application pour les modules du kernel.
+pub trait ApplicationModuleExtension
+	: Sized
+{
+	/// Ajoute un module au kernel. Les modules sont enregistrés et démarrés
+	/// au démarrage de l'application, dans l'ordre de leurs dépendances.
+	fn with_module(self, module: impl KernelModule) -> Self;
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<A, E, C> ApplicationModuleExtension for Kernel<A, E, C>
+{
+	fn with_module(mut self, module: impl KernelModule) -> Self
+	{
+		self.modules.push(module);
+		self
+	}
+}
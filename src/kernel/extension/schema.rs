@@ -0,0 +1,120 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::path;
+
+use crate::kernel::config::validate_schemas;
+use crate::{Kernel, KernelError};
+
+// --------- //
+// Interface // -> Extension
+// --------- //
+
+/// Extension d'application pour les schémas JSON des configurations et des
+/// variables d'environnement.
+///
+/// Les schémas enregistrés PEUVENT être écrits dans un répertoire (afin d'être
+/// utilisés par les éditeurs ou la CI) et les fichiers existants PEUVENT être
+/// validés. Avec la fonctionnalité `schema`, le schéma des paramètres du
+/// logger est enregistré par défaut et les schémas PEUVENT être générés à
+/// partir des types (voir `schemars::JsonSchema`).
+pub trait ApplicationSchemaExtension
+	: Sized
+{
+	/// Enregistre le schéma JSON d'une configuration.
+	fn with_json_schema(self, config_name: impl Into<String>, schema: serde_json::Value) -> Self;
+
+	/// Enregistre le schéma JSON, généré à partir de son type, d'une
+	/// configuration.
+	#[cfg(feature = "schema")]
+	fn with_config_schema<T>(self, config_name: impl Into<String>) -> Self
+	where
+		T: schemars::JsonSchema;
+
+	/// Écrit les schémas enregistrés dans un répertoire, sous le nom
+	/// `<name>.schema.json`. Retourne les chemins des fichiers écrits.
+	fn write_json_schemas(&self, dir: impl AsRef<path::Path>) -> Result<Vec<path::PathBuf>, KernelError>;
+
+	/// Valide les fichiers existants des configurations et des variables
+	/// d'environnement avec leur schéma. Toutes les erreurs (fichier, ligne
+	/// et clé) sont retournées ensemble dans une [KernelError::Schema].
+	fn validate_json_schemas(&self) -> Result<(), KernelError>;
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+#[cfg(feature = "schema")]
+impl<A, E, C> Kernel<A, E, C>
+where
+	A: crate::ApplicationAdapterInterface,
+	A::Settings: schemars::JsonSchema,
+{
+	/// Enregistre le schéma JSON des paramètres de l'application adapter.
+	pub fn with_adapter_schema(self) -> Self
+	{
+		let filename = <A::Settings as crate::ApplicationAdapterSettingsInterface>::FILENAME;
+		if filename.is_empty() {
+			return self;
+		}
+		self.with_config_schema::<A::Settings>(filename)
+	}
+}
+
+#[cfg(feature = "schema")]
+impl<A, E, C> Kernel<A, E, C>
+where
+	E: crate::ApplicationEnvInterface,
+	E: schemars::JsonSchema,
+{
+	/// Enregistre le schéma JSON des variables d'environnement de
+	/// l'application. Les fichiers d'environnement sont résolus en fonction du
+	/// mode d'exécution (voir
+	/// [ApplicationEnvExtension::include_env_vars()](crate::ApplicationEnvExtension::include_env_vars)).
+	pub fn with_env_schema(mut self) -> Self
+	{
+		let mode_suffix = E::with_suffix(&self.settings).to_string();
+		self.schemas
+			.insert_env(E::FILENAME, mode_suffix, schemars::schema_for!(E).to_value());
+		self
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<A, E, C> ApplicationSchemaExtension for Kernel<A, E, C>
+{
+	fn with_json_schema(mut self, config_name: impl Into<String>, schema: serde_json::Value) -> Self
+	{
+		self.schemas.insert_config(config_name, schema);
+		self
+	}
+
+	#[cfg(feature = "schema")]
+	fn with_config_schema<T>(self, config_name: impl Into<String>) -> Self
+	where
+		T: schemars::JsonSchema,
+	{
+		self.with_json_schema(config_name, schemars::schema_for!(T).to_value())
+	}
+
+	fn write_json_schemas(&self, dir: impl AsRef<path::Path>) -> Result<Vec<path::PathBuf>, KernelError>
+	{
+		self.schemas.write(dir.as_ref())
+	}
+
+	fn validate_json_schemas(&self) -> Result<(), KernelError>
+	{
+		validate_schemas(&self.schemas, &self.settings)
+	}
+}
@@ -0,0 +1,84 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use crate::{HookError, Kernel, ServiceContainer};
+
+// --------- //
+// Interface // -> Extension
+// --------- //
+
+/// Extension d'application pour le conteneur de services.
+///
+/// Voir [ServiceContainer].
+pub trait ApplicationServiceExtension
+	: Sized
+{
+	/// Enregistre une instance de service.
+	fn with_singleton<T>(self, service: T) -> Self
+	where
+		T: Send + Sync + 'static;
+
+	/// Enregistre un service construit lors de sa première résolution.
+	fn with_lazy_singleton<T, Err>(
+		self,
+		factory: impl Fn(&ServiceContainer) -> Result<T, Err> + Send + Sync + 'static,
+	) -> Self
+	where
+		T: Send + Sync + 'static,
+		Err: Into<HookError>;
+
+	/// Enregistre une fabrique de service.
+	fn with_factory<T, Err>(
+		self,
+		factory: impl Fn(&ServiceContainer) -> Result<T, Err> + Send + Sync + 'static,
+	) -> Self
+	where
+		T: Send + Sync + 'static,
+		Err: Into<HookError>;
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<A, E, C> ApplicationServiceExtension for Kernel<A, E, C>
+{
+	fn with_singleton<T>(self, service: T) -> Self
+	where
+		T: Send + Sync + 'static,
+	{
+		self.services.singleton(service);
+		self
+	}
+
+	fn with_lazy_singleton<T, Err>(
+		self,
+		factory: impl Fn(&ServiceContainer) -> Result<T, Err> + Send + Sync + 'static,
+	) -> Self
+	where
+		T: Send + Sync + 'static,
+		Err: Into<HookError>,
+	{
+		self.services.lazy_singleton(factory);
+		self
+	}
+
+	fn with_factory<T, Err>(
+		self,
+		factory: impl Fn(&ServiceContainer) -> Result<T, Err> + Send + Sync + 'static,
+	) -> Self
+	where
+		T: Send + Sync + 'static,
+		Err: Into<HookError>,
+	{
+		self.services.factory(factory);
+		self
+	}
+}
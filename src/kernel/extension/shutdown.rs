@@ -0,0 +1,73 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::time::Duration;
+
+use crate::process::ShutdownToken;
+use crate::{HookPhase, HookPolicy, Kernel};
+
+// --------- //
+// Interface // -> Extension
+// --------- //
+
+/// Extension d'application pour l'arrêt de l'application.
+///
+/// Lors du démarrage de l'application, le kernel installe les gestionnaires
+/// des signaux d'arrêt (SIGINT, SIGTERM). À la réception d'un de ces
+/// signaux, le [jeton d'arrêt](ShutdownToken) est déclenché : l'application
+/// adapter, qui reçoit ce jeton via
+/// [ApplicationAdapterInterface::set_shutdown_token()](crate::ApplicationAdapterInterface::set_shutdown_token),
+/// DOIT alors terminer sa fonction `run`. Le kernel exécute ensuite
+/// les fonctions d'arrêt. Si l'arrêt dépasse le délai imparti, le processus
+/// est arrêté de force avec le code de sortie
+/// [SHUTDOWN_TIMEOUT_EXIT_CODE](crate::process::SHUTDOWN_TIMEOUT_EXIT_CODE).
+pub trait ApplicationShutdownExtension
+	: Sized
+{
+	/// Définit le délai accordé à l'arrêt de l'application.
+	fn define_shutdown_timeout(self, timeout: Duration) -> Self;
+
+	/// Ajoute une fonction exécutée lors de l'arrêt de l'application. Les
+	/// fonctions sont exécutées dans l'ordre inverse de leur ajout.
+	fn on_shutdown(self, hook: impl FnOnce() + Send + 'static) -> Self;
+
+	/// Le jeton d'arrêt de l'application.
+	fn shutdown_token(&self) -> ShutdownToken;
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<A, E, C> ApplicationShutdownExtension for Kernel<A, E, C>
+{
+	fn define_shutdown_timeout(mut self, timeout: Duration) -> Self
+	{
+		self.shutdown.timeout = timeout;
+		self
+	}
+
+	fn on_shutdown(mut self, hook: impl FnOnce() + Send + 'static) -> Self
+	{
+		let mut hook = Some(hook);
+		self.hooks.push_sync(HookPhase::Shutdown, HookPolicy::Continue, move |_| {
+			if let Some(hook) = hook.take() {
+				hook();
+			}
+			Ok(())
+		});
+		self
+	}
+
+	fn shutdown_token(&self) -> ShutdownToken
+	{
+		self.shutdown.token.clone()
+	}
+}
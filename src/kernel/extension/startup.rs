@@ -8,9 +8,12 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+use std::process::ExitCode;
+
 use console::style;
 
-use crate::Kernel;
+use crate::kernel::config::{config_names, resolve_config_layers};
+use crate::{ApplicationTerminationInterface, HookContext, HookPhase, Kernel, KernelCommand, KernelError};
 
 // --------- //
 // Interface //
@@ -52,12 +55,345 @@ pub trait AsyncApplicationStartupCallableExtension
 	async fn run(self, callable: Self::Callable);
 }
 
+/// Interface de lancement d'application dont l'exécution peut échouer.
+///
+/// Lorsque l'application adapter implémente cette interface, le kernel
+/// retourne un `Result<(), KernelError>` : les erreurs (commande du kernel,
+/// démarrage, application adapter, fonctions de cycle de vie, arrêt) sont
+/// signalées via le [LoggerSignal](crate::Kernel::signal) et les fonctions de
+/// la phase [HookPhase::Error], puis les fonctions de la phase
+/// [HookPhase::Shutdown] sont exécutées avant de le retourner.
+pub trait ApplicationFallibleStartupExtension
+	: Sized
+{
+	type Output: ApplicationTerminationInterface;
+
+	/// Démarre l'application.
+	fn try_run(self) -> Self::Output;
+
+	/// Démarre l'application et retourne le code de sortie du processus.
+	fn run_with_exit_code(self) -> ExitCode
+	{
+		self.try_run().exit_code()
+	}
+}
+
+/// Interface de lancement d'application dont l'exécution peut échouer. Voir
+/// [ApplicationFallibleStartupExtension].
+pub trait ApplicationFallibleStartupCallableExtension
+	: Sized
+{
+	type Callable;
+	type Output: ApplicationTerminationInterface;
+
+	/// Démarre l'application.
+	fn try_run(self, callable: Self::Callable) -> Self::Output;
+
+	/// Démarre l'application et retourne le code de sortie du processus.
+	fn run_with_exit_code(self, callable: Self::Callable) -> ExitCode
+	{
+		self.try_run(callable).exit_code()
+	}
+}
+
+/// Interface de lancement d'application asynchrone dont l'exécution peut
+/// échouer. Voir [ApplicationFallibleStartupExtension].
+pub trait AsyncApplicationFallibleStartupExtension
+	: Sized
+{
+	type Output: ApplicationTerminationInterface;
+
+	/// Démarre l'application.
+	async fn try_run(self) -> Self::Output;
+
+	/// Démarre l'application et retourne le code de sortie du processus.
+	async fn run_with_exit_code(self) -> ExitCode
+	{
+		self.try_run().await.exit_code()
+	}
+}
+
+/// Interface de lancement d'application asynchrone dont l'exécution peut
+/// échouer. Voir [ApplicationFallibleStartupExtension].
+pub trait AsyncApplicationFallibleStartupCallableExtension
+	: Sized
+{
+	type Callable;
+	type Output: ApplicationTerminationInterface;
+
+	/// Démarre l'application.
+	async fn try_run(self, callable: Self::Callable) -> Self::Output;
+
+	/// Démarre l'application et retourne le code de sortie du processus.
+	async fn run_with_exit_code(self, callable: Self::Callable) -> ExitCode
+	{
+		self.try_run(callable).await.exit_code()
+	}
+}
+
+// ----- //
+// Macro //
+// ----- //
+
+/// Construit le contexte des fonctions de cycle de vie à partir des champs du
+/// kernel (et non de `&self`), afin de pouvoir emprunter mutablement les
+/// autres champs, ou après que l'application adapter a été déplacée.
+macro_rules! hook_context {
+	($kernel:ident) => {
+		HookContext::new(
+			&$kernel.application_name,
+			&$kernel.application_version,
+			&$kernel.settings,
+			&$kernel.logger_signal,
+			&$kernel.services,
+		)
+	};
+}
+
 // -------------- //
 // Implémentation //
 // -------------- //
 
 impl<A, E, C> Kernel<A, E, C>
 {
+	/// Exécute la commande du kernel (voir [KernelCommand]), le cas échéant,
+	/// à la place de l'application. Lorsque la commande le nécessite, le
+	/// kernel est démarré au préalable, puis arrêté. Retourne [None]
+	/// lorsqu'aucune commande n'est demandée.
+	fn execute_command(&mut self) -> Option<Result<(), KernelError>>
+	{
+		let command = match self.command.clone()? {
+			| Ok(command) => command,
+			| Err(err) => {
+				let outcome = Err(KernelError::from(err));
+				self.report(&outcome);
+				return Some(outcome);
+			}
+		};
+
+		let outcome = self.check_process_mode();
+		if outcome.is_err() {
+			self.report(&outcome);
+			return Some(outcome);
+		}
+
+		if !command.boots_kernel() {
+			let outcome = self.print_command(&command);
+			self.report(&outcome);
+			return Some(outcome);
+		}
+
+		if let Err(err) = self.boot() {
+			_ = self.terminate();
+			return Some(Err(err));
+		}
+
+		let outcome = self.print_command(&command);
+		self.report(&outcome);
+		Some(outcome.and(self.terminate()))
+	}
+
+	/// Voir [Kernel::execute_command()]. Les fonctions asynchrones sont
+	/// également exécutées.
+	async fn execute_command_async(&mut self) -> Option<Result<(), KernelError>>
+	{
+		let command = match self.command.clone()? {
+			| Ok(command) => command,
+			| Err(err) => {
+				let outcome = Err(KernelError::from(err));
+				self.report_async(&outcome).await;
+				return Some(outcome);
+			}
+		};
+
+		let outcome = self.check_process_mode();
+		if outcome.is_err() {
+			self.report_async(&outcome).await;
+			return Some(outcome);
+		}
+
+		if !command.boots_kernel() {
+			let outcome = self.print_command(&command);
+			self.report_async(&outcome).await;
+			return Some(outcome);
+		}
+
+		if let Err(err) = self.boot_async().await {
+			_ = self.terminate_async().await;
+			return Some(Err(err));
+		}
+
+		let outcome = self.print_command(&command);
+		self.report_async(&outcome).await;
+		Some(outcome.and(self.terminate_async().await))
+	}
+
+	/// Retourne l'erreur de résolution du mode d'exécution, lorsque le mode
+	/// n'a pas été redéfinit (voir [Kernel::define_process_mode()]).
+	fn check_process_mode(&mut self) -> Result<(), KernelError>
+	{
+		self.process_mode_error.take().map_or(Ok(()), Err)
+	}
+
+	/// Signale l'erreur d'un résultat via le [LoggerSignal](crate::Kernel::signal)
+	/// et exécute les fonctions de la phase [HookPhase::Error].
+	fn report<T>(&mut self, outcome: &Result<T, KernelError>)
+	{
+		if let Err(err) = outcome {
+			let context = hook_context!(self);
+			self.hooks.report(err, &context);
+		}
+	}
+
+	/// Voir [Kernel::report()]. Les fonctions asynchrones de la phase
+	/// [HookPhase::Error] sont également exécutées.
+	async fn report_async<T>(&mut self, outcome: &Result<T, KernelError>)
+	{
+		if let Err(err) = outcome {
+			let context = hook_context!(self);
+			self.hooks.report_async(err, &context).await;
+		}
+	}
+
+	/// Affiche le résultat d'une commande du kernel.
+	fn print_command(&self, command: &KernelCommand) -> Result<(), KernelError>
+	{
+		match command {
+			| KernelCommand::ConfigExplain(key) => {
+				self.explain_config(key).map(|explanations| {
+					for explanation in explanations {
+						println!("{explanation}");
+					}
+				})
+			}
+			| KernelCommand::ConfigDump { format, .. } => {
+				print!("{}", self.dump_config().render(*format));
+				Ok(())
+			}
+			| KernelCommand::DryRun => Ok(()),
+		}
+	}
+
+	/// Voir [Kernel::execute_command()]. En cas d'erreur, le processus est
+	/// arrêté avec le code de sortie de l'erreur (voir [Kernel::exit()]).
+	/// Retourne `true` lorsqu'une commande a été exécutée.
+	fn run_command(&mut self) -> bool
+	{
+		match self.execute_command() {
+			| Some(Err(err)) => self.exit(&err),
+			| Some(Ok(())) => true,
+			| None => false,
+		}
+	}
+
+	/// Voir [Kernel::run_command()]. Les fonctions asynchrones sont également
+	/// exécutées.
+	async fn run_command_async(&mut self) -> bool
+	{
+		match self.execute_command_async().await {
+			| Some(Err(err)) => self.exit(&err),
+			| Some(Ok(())) => true,
+			| None => false,
+		}
+	}
+
+	/// Démarre les modules, exécute les fonctions de la phase
+	/// [HookPhase::Boot], prépare le démarrage de l'application puis exécute
+	/// les fonctions de la phase [HookPhase::BeforeRun]. Échoue lorsque le
+	/// mode d'exécution est invalide (voir [Kernel::check_process_mode()]).
+	fn boot(&mut self) -> Result<(), KernelError>
+	{
+		let outcome = self.check_process_mode();
+		if outcome.is_err() {
+			self.report(&outcome);
+			return outcome;
+		}
+
+		let context = hook_context!(self);
+		if let Err(err) = self.modules.start(&context) {
+			self.hooks.report(&err, &context);
+			return Err(err);
+		}
+		self.hooks.execute(HookPhase::Boot, &context)?;
+
+		self.startup();
+
+		let context = hook_context!(self);
+		self.hooks.execute(HookPhase::BeforeRun, &context)
+	}
+
+	/// Voir [Kernel::boot()]. Les fonctions asynchrones sont également
+	/// exécutées.
+	async fn boot_async(&mut self) -> Result<(), KernelError>
+	{
+		let outcome = self.check_process_mode();
+		if outcome.is_err() {
+			self.report_async(&outcome).await;
+			return outcome;
+		}
+
+		let context = hook_context!(self);
+		if let Err(err) = self.modules.start(&context) {
+			self.hooks.report_async(&err, &context).await;
+			return Err(err);
+		}
+		self.hooks.execute_async(HookPhase::Boot, &context).await?;
+
+		self.startup();
+
+		let context = hook_context!(self);
+		self.hooks.execute_async(HookPhase::BeforeRun, &context).await
+	}
+
+	/// Prépare le démarrage de l'application.
+	fn startup(&mut self)
+	{
+		if self.settings.startup_info {
+			self.display_startup_information();
+		}
+
+		self.shutdown.listen(&self.logger_signal);
+	}
+
+	/// Arrête l'application suite à l'échec du démarrage.
+	fn abort(&mut self, err: &KernelError) -> !
+	{
+		_ = self.terminate();
+		self.exit(err)
+	}
+
+	/// Voir [Kernel::abort()]. Les fonctions asynchrones sont également
+	/// exécutées.
+	async fn abort_async(&mut self, err: &KernelError) -> !
+	{
+		_ = self.terminate_async().await;
+		self.exit(err)
+	}
+
+	/// Quitte le processus avec le code de sortie d'une erreur, déjà
+	/// signalée. Voir [KernelError::exit_code()].
+	fn exit(&self, err: &KernelError) -> !
+	{
+		self.logger_signal.terminated();
+		err.exit()
+	}
+
+	/// Exécute les fonctions de la phase [HookPhase::Shutdown] et arrête les
+	/// modules.
+	fn terminate(&mut self) -> Result<(), KernelError>
+	{
+		let context = hook_context!(self);
+		self.shutdown.terminate(&mut self.hooks, &mut self.modules, &context)
+	}
+
+	/// Voir [Kernel::terminate()]. Les fonctions asynchrones sont également
+	/// exécutées.
+	async fn terminate_async(&mut self) -> Result<(), KernelError>
+	{
+		let context = hook_context!(self);
+		self.shutdown.terminate_async(&mut self.hooks, &mut self.modules, &context).await
+	}
+
 	fn display_startup_information(&self)
 	{
 		println!(
@@ -86,6 +422,22 @@ impl<A, E, C> Kernel<A, E, C>
 			self.settings.directory.env()
 		);
 
+		if let Some(config_directory) = self.settings.directory.config() {
+			let formats = &self.settings.loader_extensions;
+			for config_name in config_names(config_directory, self.settings.process_mode, formats) {
+				match resolve_config_layers(config_directory, &config_name, self.settings.process_mode, formats) {
+					| Ok(layers) => {
+						for (filepath, format) in layers {
+							println!("\tConfiguration « {config_name} » ({format:?}): {filepath:?}");
+						}
+					}
+					| Err(err) => {
+						println!("\tConfiguration « {config_name} »: {}", style(err).red());
+					}
+				}
+			}
+		}
+
 		println!();
 	}
 }
@@ -98,13 +450,26 @@ impl<A, E, C> ApplicationStartupExtension for Kernel<A, E, C>
 where
 	A: ApplicationStartupExtension,
 {
-	fn run(self)
+	fn run(mut self)
 	{
-		if self.settings.startup_info {
-			self.display_startup_information();
+		if self.run_command() {
+			return;
+		}
+
+		if let Err(err) = self.boot() {
+			self.abort(&err);
 		}
 
 		self.application_adapter.run();
+
+		let context = hook_context!(self);
+		let after_run = self.hooks.execute(HookPhase::AfterRun, &context);
+		let shutdown = self.shutdown.terminate(&mut self.hooks, &mut self.modules, &context);
+
+		if let Err(err) = after_run.and(shutdown) {
+			self.logger_signal.terminated();
+			err.exit();
+		}
 	}
 }
 
@@ -112,13 +477,26 @@ impl<A, E, C> AsyncApplicationStartupExtension for Kernel<A, E, C>
 where
 	A: AsyncApplicationStartupExtension,
 {
-	async fn run(self)
+	async fn run(mut self)
 	{
-		if self.settings.startup_info {
-			self.display_startup_information();
+		if self.run_command_async().await {
+			return;
+		}
+
+		if let Err(err) = self.boot_async().await {
+			self.abort_async(&err).await;
 		}
 
 		self.application_adapter.run().await;
+
+		let context = hook_context!(self);
+		let after_run = self.hooks.execute_async(HookPhase::AfterRun, &context).await;
+		let shutdown = self.shutdown.terminate_async(&mut self.hooks, &mut self.modules, &context).await;
+
+		if let Err(err) = after_run.and(shutdown) {
+			self.logger_signal.terminated();
+			err.exit();
+		}
 	}
 }
 
@@ -128,13 +506,26 @@ where
 {
 	type Callable = A::Callable;
 
-	fn run(self, callable: Self::Callable)
+	fn run(mut self, callable: Self::Callable)
 	{
-		if self.settings.startup_info {
-			self.display_startup_information();
+		if self.run_command() {
+			return;
+		}
+
+		if let Err(err) = self.boot() {
+			self.abort(&err);
 		}
 
 		self.application_adapter.run(callable);
+
+		let context = hook_context!(self);
+		let after_run = self.hooks.execute(HookPhase::AfterRun, &context);
+		let shutdown = self.shutdown.terminate(&mut self.hooks, &mut self.modules, &context);
+
+		if let Err(err) = after_run.and(shutdown) {
+			self.logger_signal.terminated();
+			err.exit();
+		}
 	}
 }
 
@@ -144,12 +535,264 @@ where
 {
 	type Callable = A::Callable;
 
-	async fn run(self, callable: Self::Callable)
+	async fn run(mut self, callable: Self::Callable)
 	{
-		if self.settings.startup_info {
-			self.display_startup_information();
+		if self.run_command_async().await {
+			return;
+		}
+
+		if let Err(err) = self.boot_async().await {
+			self.abort_async(&err).await;
 		}
 
 		self.application_adapter.run(callable).await;
+
+		let context = hook_context!(self);
+		let after_run = self.hooks.execute_async(HookPhase::AfterRun, &context).await;
+		let shutdown = self.shutdown.terminate_async(&mut self.hooks, &mut self.modules, &context).await;
+
+		if let Err(err) = after_run.and(shutdown) {
+			self.logger_signal.terminated();
+			err.exit();
+		}
+	}
+}
+
+impl<A, E, C> ApplicationFallibleStartupExtension for Kernel<A, E, C>
+where
+	A: ApplicationFallibleStartupExtension,
+{
+	type Output = Result<(), KernelError>;
+
+	fn try_run(mut self) -> Self::Output
+	{
+		if let Some(outcome) = self.execute_command() {
+			return outcome;
+		}
+
+		if let Err(err) = self.boot() {
+			_ = self.terminate();
+			return Err(err);
+		}
+
+		let outcome = self.application_adapter.try_run().into_result();
+
+		let context = hook_context!(self);
+		if let Err(err) = &outcome {
+			self.hooks.report(err, &context);
+		}
+		let after_run = self.hooks.execute(HookPhase::AfterRun, &context);
+		let shutdown = self.shutdown.terminate(&mut self.hooks, &mut self.modules, &context);
+
+		outcome.and(after_run).and(shutdown)
+	}
+}
+
+impl<A, E, C> AsyncApplicationFallibleStartupExtension for Kernel<A, E, C>
+where
+	A: AsyncApplicationFallibleStartupExtension,
+{
+	type Output = Result<(), KernelError>;
+
+	async fn try_run(mut self) -> Self::Output
+	{
+		if let Some(outcome) = self.execute_command_async().await {
+			return outcome;
+		}
+
+		if let Err(err) = self.boot_async().await {
+			_ = self.terminate_async().await;
+			return Err(err);
+		}
+
+		let outcome = self.application_adapter.try_run().await.into_result();
+
+		let context = hook_context!(self);
+		if let Err(err) = &outcome {
+			self.hooks.report_async(err, &context).await;
+		}
+		let after_run = self.hooks.execute_async(HookPhase::AfterRun, &context).await;
+		let shutdown = self.shutdown.terminate_async(&mut self.hooks, &mut self.modules, &context).await;
+
+		outcome.and(after_run).and(shutdown)
+	}
+}
+
+impl<A, E, C, CB> ApplicationFallibleStartupCallableExtension for Kernel<A, E, C>
+where
+	A: ApplicationFallibleStartupCallableExtension<Callable = CB>,
+{
+	type Callable = A::Callable;
+	type Output = Result<(), KernelError>;
+
+	fn try_run(mut self, callable: Self::Callable) -> Self::Output
+	{
+		if let Some(outcome) = self.execute_command() {
+			return outcome;
+		}
+
+		if let Err(err) = self.boot() {
+			_ = self.terminate();
+			return Err(err);
+		}
+
+		let outcome = self.application_adapter.try_run(callable).into_result();
+
+		let context = hook_context!(self);
+		if let Err(err) = &outcome {
+			self.hooks.report(err, &context);
+		}
+		let after_run = self.hooks.execute(HookPhase::AfterRun, &context);
+		let shutdown = self.shutdown.terminate(&mut self.hooks, &mut self.modules, &context);
+
+		outcome.and(after_run).and(shutdown)
+	}
+}
+
+impl<A, E, C, CB> AsyncApplicationFallibleStartupCallableExtension for Kernel<A, E, C>
+where
+	A: AsyncApplicationFallibleStartupCallableExtension<Callable = CB>,
+{
+	type Callable = A::Callable;
+	type Output = Result<(), KernelError>;
+
+	async fn try_run(mut self, callable: Self::Callable) -> Self::Output
+	{
+		if let Some(outcome) = self.execute_command_async().await {
+			return outcome;
+		}
+
+		if let Err(err) = self.boot_async().await {
+			_ = self.terminate_async().await;
+			return Err(err);
+		}
+
+		let outcome = self.application_adapter.try_run(callable).await.into_result();
+
+		let context = hook_context!(self);
+		if let Err(err) = &outcome {
+			self.hooks.report_async(err, &context).await;
+		}
+		let after_run = self.hooks.execute_async(HookPhase::AfterRun, &context).await;
+		let shutdown = self.shutdown.terminate_async(&mut self.hooks, &mut self.modules, &context).await;
+
+		outcome.and(after_run).and(shutdown)
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::sync::{Arc, Mutex};
+
+	use super::*;
+	use crate::{ApplicationAdapterInterface, ApplicationHookExtension, HookPolicy, KernelCommandError};
+
+	struct Adapter;
+
+	impl ApplicationAdapterInterface for Adapter
+	{
+		type Settings = ();
+
+		fn new(_: Self::Settings) -> Self
+		{
+			Self
+		}
+	}
+
+	impl ApplicationFallibleStartupExtension for Adapter
+	{
+		type Output = Result<(), KernelError>;
+
+		fn try_run(self) -> Self::Output
+		{
+			Ok(())
+		}
+	}
+
+	impl AsyncApplicationFallibleStartupExtension for Adapter
+	{
+		type Output = Result<(), KernelError>;
+
+		async fn try_run(self) -> Self::Output
+		{
+			Ok(())
+		}
+	}
+
+	fn kernel(dir: &tempfile::TempDir, reported: &Arc<Mutex<Vec<String>>>) -> Kernel<Adapter>
+	{
+		let reported = Arc::clone(reported);
+		let mut kernel = Kernel::<Adapter>::new("test", "0.0.0", dir.path()).on_error(move |context| {
+			let err = context.error().map(ToString::to_string).unwrap_or_default();
+			reported.lock().unwrap().push(err);
+			Ok::<_, KernelError>(())
+		});
+		kernel.settings.startup_info = false;
+		kernel
+	}
+
+	#[test]
+	fn test_try_run_reports_command_errors()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		let reported = Arc::default();
+		let mut kernel = kernel(&dir, &reported);
+		kernel.command = Some(Ok(KernelCommand::ConfigExplain(String::from("missing.key"))));
+
+		assert!(matches!(ApplicationFallibleStartupExtension::try_run(kernel), Err(KernelError::Config { .. })));
+		assert_eq!(reported.lock().unwrap().len(), 1);
+	}
+
+	#[test]
+	fn test_try_run_fails_on_invalid_command()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		let reported = Arc::default();
+		let mut kernel = kernel(&dir, &reported);
+		kernel.command = Some(Err(KernelCommandError::Unknown(String::from("check"))));
+
+		let err = ApplicationFallibleStartupExtension::try_run(kernel).unwrap_err();
+		assert!(matches!(err, KernelError::Command(KernelCommandError::Unknown(_))));
+		assert_ne!(err.exit_code(), std::process::ExitCode::SUCCESS);
+		assert_eq!(reported.lock().unwrap().len(), 1);
+	}
+
+	#[test]
+	fn test_try_run_reports_hook_errors()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		let reported = Arc::default();
+		let kernel = kernel(&dir, &reported).on_before_run(|_| Err("before run"));
+
+		assert!(matches!(
+			ApplicationFallibleStartupExtension::try_run(kernel),
+			Err(KernelError::Hook {
+				phase: HookPhase::BeforeRun,
+				..
+			})
+		));
+		assert_eq!(reported.lock().unwrap().len(), 1);
+	}
+
+	#[tokio::test]
+	async fn test_try_run_async_executes_async_error_hooks()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		let reported: Arc<Mutex<Vec<String>>> = Arc::default();
+		let async_reported = Arc::clone(&reported);
+		let kernel = kernel(&dir, &reported)
+			.with_async_hook(HookPhase::Error, HookPolicy::Continue, move |context| {
+				let err = context.error().map(ToString::to_string).unwrap_or_default();
+				let reported = Arc::clone(&async_reported);
+				Box::pin(async move {
+					reported.lock().unwrap().push(err);
+					Ok(())
+				})
+			})
+			.on_before_run(|_| Err("before run"));
+
+		assert!(AsyncApplicationFallibleStartupExtension::try_run(kernel).await.is_err());
+		assert_eq!(reported.lock().unwrap().len(), 2);
 	}
 }
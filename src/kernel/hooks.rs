@@ -0,0 +1,404 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::future::Future;
+use std::pin::Pin;
+use std::{error, fmt};
+
+use super::service::ServiceContainer;
+use super::settings::KernelSettings;
+use crate::logger::signal::LoggerSignal;
+use crate::KernelError;
+
+// ---- //
+// Type //
+// ---- //
+
+/// Erreur retournée par une fonction de cycle de vie.
+pub type HookError = Box<dyn error::Error + Send + Sync>;
+
+/// Future retournée par une fonction de cycle de vie asynchrone.
+pub type HookFuture = Pin<Box<dyn Future<Output = Result<(), HookError>>>>;
+
+type SyncHook = Box<dyn FnMut(&HookContext) -> Result<(), HookError> + Send>;
+type AsyncHook = Box<dyn FnMut(&HookContext) -> HookFuture + Send>;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Contexte donné aux fonctions de cycle de vie.
+pub struct HookContext<'a>
+{
+	application_name: &'a str,
+	application_version: &'a str,
+	settings: &'a KernelSettings,
+	signal: &'a LoggerSignal,
+	services: &'a ServiceContainer,
+	error: Option<&'a KernelError>,
+}
+
+/// Registre des fonctions de cycle de vie du kernel.
+#[derive(Default)]
+pub(crate) struct KernelHooks
+{
+	hooks: Vec<KernelHook>,
+}
+
+struct KernelHook
+{
+	phase: HookPhase,
+	policy: HookPolicy,
+	kind: KernelHookKind,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Les phases du cycle de vie du kernel, dans leur ordre d'exécution.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq, Hash)]
+pub enum HookPhase
+{
+	/// Avant le démarrage de l'application (avant l'affichage des
+	/// informations de démarrage et l'installation des gestionnaires de
+	/// signaux).
+	Boot,
+	/// Juste avant l'appel à la fonction `run` de l'application adapter.
+	BeforeRun,
+	/// Juste après l'appel à la fonction `run` de l'application adapter.
+	AfterRun,
+	/// Lorsqu'une erreur survient pendant le cycle de vie.
+	Error,
+	/// Lors de l'arrêt de l'application. Les fonctions de cette phase sont
+	/// exécutées dans l'ordre inverse de leur enregistrement.
+	Shutdown,
+}
+
+/// Comportement du kernel lorsqu'une fonction de cycle de vie échoue.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum HookPolicy
+{
+	/// Les fonctions restantes de la phase ne sont pas exécutées et
+	/// l'application est arrêtée.
+	Abort,
+	/// L'erreur est signalée et les fonctions restantes sont exécutées.
+	Continue,
+}
+
+enum KernelHookKind
+{
+	Sync(SyncHook),
+	Async(AsyncHook),
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<'a> HookContext<'a>
+{
+	pub(crate) fn new(
+		application_name: &'a str,
+		application_version: &'a str,
+		settings: &'a KernelSettings,
+		signal: &'a LoggerSignal,
+		services: &'a ServiceContainer,
+	) -> Self
+	{
+		Self {
+			application_name,
+			application_version,
+			settings,
+			signal,
+			services,
+			error: None,
+		}
+	}
+
+	/// Nom de l'application.
+	pub fn application_name(&self) -> &str
+	{
+		self.application_name
+	}
+
+	/// Version de l'application.
+	pub fn application_version(&self) -> &str
+	{
+		self.application_version
+	}
+
+	/// Paramètres du kernel.
+	pub fn settings(&self) -> &KernelSettings
+	{
+		self.settings
+	}
+
+	/// Logger Signal.
+	pub fn signal(&self) -> &LoggerSignal
+	{
+		self.signal
+	}
+
+	/// Conteneur de services.
+	pub fn services(&self) -> &ServiceContainer
+	{
+		self.services
+	}
+
+	/// L'erreur à l'origine de la phase [HookPhase::Error].
+	pub fn error(&self) -> Option<&KernelError>
+	{
+		self.error
+	}
+
+	fn with_error(&self, error: &'a KernelError) -> Self
+	{
+		Self {
+			error: Some(error),
+			..*self
+		}
+	}
+}
+
+impl HookPhase
+{
+	/// Comportement par défaut lorsqu'une fonction de cette phase échoue.
+	pub fn default_policy(&self) -> HookPolicy
+	{
+		match self {
+			| Self::Boot | Self::BeforeRun => HookPolicy::Abort,
+			| Self::AfterRun | Self::Error | Self::Shutdown => HookPolicy::Continue,
+		}
+	}
+}
+
+impl KernelHooks
+{
+	pub(crate) fn push_sync(
+		&mut self,
+		phase: HookPhase,
+		policy: HookPolicy,
+		hook: impl FnMut(&HookContext) -> Result<(), HookError> + Send + 'static,
+	)
+	{
+		self.hooks.push(KernelHook {
+			phase,
+			policy,
+			kind: KernelHookKind::Sync(Box::new(hook)),
+		});
+	}
+
+	pub(crate) fn push_async(
+		&mut self,
+		phase: HookPhase,
+		policy: HookPolicy,
+		hook: impl FnMut(&HookContext) -> HookFuture + Send + 'static,
+	)
+	{
+		self.hooks.push(KernelHook {
+			phase,
+			policy,
+			kind: KernelHookKind::Async(Box::new(hook)),
+		});
+	}
+
+	/// Exécute les fonctions synchrones d'une phase. Les fonctions
+	/// asynchrones sont ignorées et signalées.
+	pub(crate) fn execute(&mut self, phase: HookPhase, context: &HookContext) -> Result<(), KernelError>
+	{
+		for index in self.indexes(phase) {
+			let hook = &mut self.hooks[index];
+
+			let result = match &mut hook.kind {
+				| KernelHookKind::Sync(f) => f(context),
+				| KernelHookKind::Async(_) => {
+					context.signal.send_warning(format!(
+						"Une fonction asynchrone de la phase « {phase} » a été ignorée: l'application a été \
+						 démarrée de manière synchrone."
+					));
+					continue;
+				}
+			};
+
+			let policy = hook.policy;
+			self.handle(phase, policy, result, context)?;
+		}
+
+		Ok(())
+	}
+
+	/// Exécute les fonctions, synchrones et asynchrones, d'une phase.
+	pub(crate) async fn execute_async(&mut self, phase: HookPhase, context: &HookContext<'_>)
+		-> Result<(), KernelError>
+	{
+		for index in self.indexes(phase) {
+			let hook = &mut self.hooks[index];
+
+			let result = match &mut hook.kind {
+				| KernelHookKind::Sync(f) => f(context),
+				| KernelHookKind::Async(f) => f(context).await,
+			};
+
+			let policy = hook.policy;
+			self.handle_async(phase, policy, result, context).await?;
+		}
+
+		Ok(())
+	}
+
+	/// Signale l'erreur d'une fonction, exécute les fonctions de la phase
+	/// [HookPhase::Error] et applique le comportement de la fonction.
+	fn handle(
+		&mut self,
+		phase: HookPhase,
+		policy: HookPolicy,
+		result: Result<(), HookError>,
+		context: &HookContext,
+	) -> Result<(), KernelError>
+	{
+		let Err(reason) = result else {
+			return Ok(());
+		};
+
+		let err = KernelError::Hook { phase, reason };
+		self.report(&err, context);
+
+		match policy {
+			| HookPolicy::Abort => Err(err),
+			| HookPolicy::Continue => Ok(()),
+		}
+	}
+
+	/// Voir [KernelHooks::handle()]. Les fonctions asynchrones de la phase
+	/// [HookPhase::Error] sont également exécutées.
+	async fn handle_async(
+		&mut self,
+		phase: HookPhase,
+		policy: HookPolicy,
+		result: Result<(), HookError>,
+		context: &HookContext<'_>,
+	) -> Result<(), KernelError>
+	{
+		let Err(reason) = result else {
+			return Ok(());
+		};
+
+		let err = KernelError::Hook { phase, reason };
+		self.report_async(&err, context).await;
+
+		match policy {
+			| HookPolicy::Abort => Err(err),
+			| HookPolicy::Continue => Ok(()),
+		}
+	}
+
+	/// Signale une erreur et exécute les fonctions synchrones de la phase
+	/// [HookPhase::Error]. Les fonctions asynchrones sont ignorées et
+	/// signalées. Les erreurs de ces fonctions sont uniquement signalées.
+	pub(crate) fn report(&mut self, err: &KernelError, context: &HookContext)
+	{
+		context.signal.send_error(err);
+
+		if context.error.is_some() {
+			return;
+		}
+
+		let context = context.with_error(err);
+
+		for index in self.indexes(HookPhase::Error) {
+			let result = match &mut self.hooks[index].kind {
+				| KernelHookKind::Sync(f) => f(&context),
+				| KernelHookKind::Async(_) => {
+					context.signal.send_warning(format!(
+						"Une fonction asynchrone de la phase « {} » a été ignorée: l'application a été \
+						 démarrée de manière synchrone.",
+						HookPhase::Error
+					));
+					continue;
+				}
+			};
+
+			if let Err(reason) = result {
+				context.signal.send_error(KernelError::Hook {
+					phase: HookPhase::Error,
+					reason,
+				});
+			}
+		}
+	}
+
+	/// Voir [KernelHooks::report()]. Les fonctions asynchrones de la phase
+	/// [HookPhase::Error] sont également exécutées.
+	pub(crate) async fn report_async(&mut self, err: &KernelError, context: &HookContext<'_>)
+	{
+		context.signal.send_error(err);
+
+		if context.error.is_some() {
+			return;
+		}
+
+		let context = context.with_error(err);
+
+		for index in self.indexes(HookPhase::Error) {
+			let result = match &mut self.hooks[index].kind {
+				| KernelHookKind::Sync(f) => f(&context),
+				| KernelHookKind::Async(f) => f(&context).await,
+			};
+
+			if let Err(reason) = result {
+				context.signal.send_error(KernelError::Hook {
+					phase: HookPhase::Error,
+					reason,
+				});
+			}
+		}
+	}
+
+	/// Les indices des fonctions d'une phase, dans leur ordre d'exécution.
+	fn indexes(&self, phase: HookPhase) -> Vec<usize>
+	{
+		let indexes = self
+			.hooks
+			.iter()
+			.enumerate()
+			.filter(|(_, hook)| hook.phase == phase)
+			.map(|(index, _)| index);
+
+		if phase == HookPhase::Shutdown {
+			indexes.rev().collect()
+		} else {
+			indexes.collect()
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl fmt::Display for HookPhase
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		let phase = match self {
+			| Self::Boot => "boot",
+			| Self::BeforeRun => "before_run",
+			| Self::AfterRun => "after_run",
+			| Self::Error => "error",
+			| Self::Shutdown => "shutdown",
+		};
+		write!(f, "{phase}")
+	}
+}
@@ -18,6 +18,16 @@ pub trait ApplicationAdapterInterface
 	type Settings: ApplicationAdapterSettingsInterface;
 
 	fn new(settings: Self::Settings) -> Self;
+
+	/// Reçoit le jeton d'arrêt de l'application. Par défaut, ce jeton est
+	/// ignoré.
+	fn set_shutdown_token(&mut self, _: crate::process::ShutdownToken) {}
+
+	/// Reçoit le conteneur de services du kernel. Les services PEUVENT être
+	/// enregistrés après la création de l'application adapter (notamment par
+	/// les modules) : ils DOIVENT donc être résolus au plus tard dans la
+	/// fonction `run`. Par défaut, ce conteneur est ignoré.
+	fn set_services(&mut self, _: crate::ServiceContainer) {}
 }
 
 pub trait ApplicationAdapterSettingsInterface
@@ -25,6 +35,11 @@ pub trait ApplicationAdapterSettingsInterface
 	+ Default
 {
 	const FILENAME: &'static str;
+
+	/// Valide les paramètres après leur dé-sérialisation (voir
+	/// [ValidateConfig](crate::ValidateConfig)). Par défaut, aucune
+	/// validation n'est effectuée.
+	fn validate_settings(&self, _: &mut crate::ConfigViolations) {}
 }
 
 // -------------- //
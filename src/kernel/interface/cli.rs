@@ -21,5 +21,10 @@ pub trait ApplicationCLIInterface
 	+ Debug
 {
 	/// Récupère les arguments de la CLI.
+	///
+	/// Les arguments réservés au kernel (ex: `--process-mode`) NE SONT PAS
+	/// connus de la CLI de l'application : celle-ci DOIT analyser les
+	/// arguments de [application_args()](crate::process::application_args)
+	/// (ex: `Self::parse_from(application_args())` avec `clap`).
 	fn arguments() -> Self;
 }
@@ -14,7 +14,9 @@ use std::fmt::Debug;
 // Interface //
 // --------- //
 
-/// Interface pour la récupération des variables d'environnement.
+/// Interface pour la récupération des variables d'environnement. Les
+/// variables sont validées (voir [ApplicationEnvInterface::validate_env()])
+/// après leur dé-sérialisation.
 pub trait ApplicationEnvInterface
 	: Sized
 	+ Clone
@@ -25,20 +27,106 @@ pub trait ApplicationEnvInterface
 	/// répertoire des fichiers des variables d'environnement.
 	const FILENAME: &'static str;
 
-	/// Ajouter un suffixe au fichier d'environnement. Accès aux paramètres
-	/// du kernel.
-	fn with_suffix(_: &crate::settings::KernelSettings) -> impl ToString
+	/// Suffixe de mode des fichiers d'environnement `<FILENAME>.<suffix>` et
+	/// `<FILENAME>.<suffix>.local`. Accès aux paramètres du kernel.
+	///
+	/// Par défaut, le nom court du mode d'exécution (`dev`, `prod`, `test`),
+	/// sauf en mode [LOCAL](crate::process::ProcessMode::LOCAL) qui n'a pas de
+	/// fichier dédié (voir
+	/// [ApplicationEnvExtension::include_env_vars()](crate::ApplicationEnvExtension::include_env_vars)).
+	fn with_suffix(settings: &crate::settings::KernelSettings) -> impl ToString
 	{
-		String::default()
+		match settings.process_mode {
+			| crate::process::ProcessMode::LOCAL => "",
+			| process_mode => process_mode.short_name(),
+		}
+	}
+
+	/// Préfixe des variables d'environnement du processus `<PREFIX><FIELD>`
+	/// (voir [EnvLoadingStrategy](crate::settings::EnvLoadingStrategy)).
+	/// Accès aux paramètres du kernel.
+	///
+	/// Par défaut, le préfixe des paramètres du kernel (ex: `LEXA_APP_`). Pour
+	/// les variables d'environnement d'un espace de noms (voir
+	/// [Kernel::include_namespaced_env_vars()](crate::Kernel::include_namespaced_env_vars)),
+	/// ce préfixe est suivi du nom de l'espace de noms (ex: `LEXA_APP_DB_`,
+	/// voir [ApplicationEnvInterface::namespace()]).
+	fn with_prefix(settings: &crate::settings::KernelSettings) -> impl ToString
+	{
+		&settings.env.prefix
+	}
+
+	/// Nom de l'espace de noms des variables d'environnement, lorsqu'elles
+	/// sont incluses via
+	/// [Kernel::include_namespaced_env_vars()](crate::Kernel::include_namespaced_env_vars).
+	///
+	/// Par défaut, le nom du fichier d'environnement sans `.env.` (`.env.db`
+	/// donne `db`).
+	fn namespace() -> &'static str
+	{
+		Self::FILENAME
+			.strip_prefix(".env.")
+			.unwrap_or(Self::FILENAME.trim_start_matches('.'))
+	}
+
+	/// Valide les variables d'environnement après leur dé-sérialisation (voir
+	/// [ValidateConfig](crate::ValidateConfig)). Par défaut, aucune
+	/// validation n'est effectuée.
+	fn validate_env(&self, _: &mut crate::ConfigViolations) {}
+
+	/// Description des champs, utilisée pour générer les fichiers d'exemple
+	/// des variables d'environnement (voir
+	/// [ApplicationEnvExtension::write_env_examples()](crate::ApplicationEnvExtension::write_env_examples)).
+	/// Accès aux paramètres du kernel, dont le mode d'exécution de l'exemple
+	/// généré.
+	///
+	/// Par défaut, seuls les noms des champs sont décrits. Avec la
+	/// fonctionnalité `schema`, les types, valeurs par défaut, commentaires de
+	/// documentation et valeurs secrètes PEUVENT être décrits à partir du
+	/// schéma JSON (voir `env_fields_from_schema`).
+	fn describe_env(settings: &crate::settings::KernelSettings) -> Vec<crate::EnvField>
+	{
+		_ = settings;
+		crate::kernel::config::default_env_fields::<Self>()
 	}
 
 	/// Initialise la [structure de champs nommés](Self) en dé-sérialisant un
-	/// fichier d'environnement.
+	/// fichier d'environnement. Les variables `<FIELD>_FILE` désignent des
+	/// fichiers secrets (voir [SECRET_FILE_SUFFIX](crate::SECRET_FILE_SUFFIX)).
 	fn fetch_from_file(env_filepath: impl AsRef<std::path::Path>)
 		-> Result<Self, crate::KernelError>
 	where
 		Self: serde::de::DeserializeOwned,
 	{
-		Ok(lexa_env::from_file(env_filepath)?)
+		crate::kernel::config::fetch_env_file(&[env_filepath.as_ref()], None, None, Default::default())
+	}
+
+	/// Voir [ApplicationEnvInterface::fetch_from_file()], pour des fichiers
+	/// d'environnement fusionnés du moins prioritaire au plus prioritaire.
+	/// C'est cette fonction qu'utilise le kernel, en fonction de ses
+	/// paramètres :
+	///
+	///   - la stratégie de chargement (voir
+	///     [EnvLoadingStrategy](crate::settings::EnvLoadingStrategy)) : hormis
+	///     [FileOnly](crate::settings::EnvLoadingStrategy::FileOnly), les
+	///     variables du processus `<PREFIX><FIELD>` surchargent les fichiers
+	///     (voir [ApplicationEnvInterface::with_prefix()]) ;
+	///   - les fichiers du répertoire des secrets complètent les champs non
+	///     définis ;
+	///   - les permissions des fichiers secrets sont vérifiées.
+	fn fetch(env_filepaths: &[&std::path::Path], settings: &crate::settings::KernelSettings)
+		-> Result<Self, crate::KernelError>
+	where
+		Self: serde::de::DeserializeOwned,
+	{
+		let process_prefix = Self::with_prefix(settings).to_string();
+		let process_prefix = (settings.env.strategy != crate::settings::EnvLoadingStrategy::FileOnly)
+			.then_some(process_prefix.as_str());
+		crate::kernel::config::fetch_env_file(
+			env_filepaths,
+			process_prefix,
+			settings.directory.secrets(),
+			settings.config.secret_file_permissions,
+		)
 	}
 }
@@ -11,7 +11,9 @@
 mod adapter;
 mod cli;
 mod env;
+mod termination;
 
 pub use self::adapter::*;
 pub use self::cli::*;
 pub use self::env::*;
+pub use self::termination::*;
@@ -0,0 +1,71 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::process::ExitCode;
+
+use crate::KernelError;
+
+// --------- //
+// Interface //
+// --------- //
+
+/// Résultat de l'exécution d'une application adapter, converti par le kernel
+/// en code de sortie du processus.
+pub trait ApplicationTerminationInterface
+{
+	/// Convertit le résultat de l'exécution en [Result].
+	fn into_result(self) -> Result<(), KernelError>;
+
+	/// Code de sortie du processus. Voir [KernelError::exit_code()].
+	fn exit_code(self) -> ExitCode
+	where
+		Self: Sized,
+	{
+		match self.into_result() {
+			| Ok(()) => ExitCode::SUCCESS,
+			| Err(err) => err.exit_code(),
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl ApplicationTerminationInterface for ()
+{
+	fn into_result(self) -> Result<(), KernelError>
+	{
+		Ok(())
+	}
+}
+
+/// Code de sortie de l'application adapter : `0` est un succès.
+impl ApplicationTerminationInterface for u8
+{
+	fn into_result(self) -> Result<(), KernelError>
+	{
+		if self == 0 {
+			Ok(())
+		} else {
+			Err(KernelError::ExitStatus(self))
+		}
+	}
+}
+
+impl<E> ApplicationTerminationInterface for Result<(), E>
+where
+	E: Into<KernelError>,
+{
+	fn into_result(self) -> Result<(), KernelError>
+	{
+		self.map_err(Into::into)
+	}
+}
@@ -0,0 +1,583 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+
+use super::config::{fetch_config, is_missing_config, validate_config_with, ConfigValue, ConfigViolations};
+use crate::{HookContext, HookError, KernelError};
+
+// --------- //
+// Interface //
+// --------- //
+
+/// Module du kernel.
+///
+/// Un module est une extension, généralement fournie par une crate tierce,
+/// qui s'enregistre auprès du kernel. Les modules sont triés en fonction de
+/// leurs dépendances : un module est toujours enregistré et démarré après ses
+/// dépendances, et arrêté avant elles.
+///
+/// Chaque module possède son propre fichier de configuration, chargé via
+/// [fetch_config()] sous le nom du module ([KernelModule::NAME]). Lorsque ce
+/// fichier n'existe pas, la configuration par défaut est utilisée ; toute
+/// autre erreur (ex: un fichier inclus manquant) est retournée. La
+/// configuration est validée (voir [KernelModule::validate_config()]) avant
+/// l'enregistrement du module.
+pub trait KernelModule
+	: Send
+	+ 'static
+{
+	/// Structure de la configuration du module.
+	type Config: serde::de::DeserializeOwned + Default;
+
+	/// Nom unique du module. Ce nom est également le nom du fichier de
+	/// configuration du module.
+	const NAME: &'static str;
+
+	/// Noms des modules dont dépend ce module.
+	fn dependencies(&self) -> &'static [&'static str]
+	{
+		&[]
+	}
+
+	/// Valide la configuration du module (voir
+	/// [ValidateConfig](crate::ValidateConfig)). Par défaut, aucune
+	/// validation n'est effectuée.
+	fn validate_config(&self, _: &Self::Config, _: &mut ConfigViolations) {}
+
+	/// Enregistre le module avec sa configuration. Appelé avant la phase
+	/// [HookPhase::Boot](crate::HookPhase::Boot).
+	fn register(&mut self, context: &HookContext, config: Self::Config) -> Result<(), HookError>;
+
+	/// Démarre le module. Appelé lorsque tous les modules ont été
+	/// enregistrés.
+	fn boot(&mut self, _: &HookContext) -> Result<(), HookError>
+	{
+		Ok(())
+	}
+
+	/// Arrête le module. Appelé lors de la phase
+	/// [HookPhase::Shutdown](crate::HookPhase::Shutdown), dans l'ordre
+	/// inverse du démarrage, pour chaque module enregistré, y compris lorsque
+	/// le démarrage du kernel a échoué avant son appel à
+	/// [KernelModule::boot()].
+	fn shutdown(&mut self, _: &HookContext) -> Result<(), HookError>
+	{
+		Ok(())
+	}
+}
+
+/// Version « objet » de [KernelModule].
+trait AnyKernelModule
+	: Send
+{
+	fn name(&self) -> &'static str;
+
+	fn dependencies(&self) -> &'static [&'static str];
+
+	/// Enregistre le module. Retourne la valeur dont est issue sa
+	/// configuration, lorsqu'elle n'est pas la configuration par défaut.
+	fn register(&mut self, context: &HookContext) -> Result<Option<ConfigValue>, HookError>;
+
+	fn boot(&mut self, context: &HookContext) -> Result<(), HookError>;
+
+	fn shutdown(&mut self, context: &HookContext) -> Result<(), HookError>;
+}
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Registre des modules du kernel.
+#[derive(Default)]
+pub(crate) struct KernelModules
+{
+	modules: Vec<KernelModuleEntry>,
+}
+
+struct KernelModuleEntry
+{
+	module: Box<dyn AnyKernelModule>,
+	state: ModuleState,
+	/// Valeur dont est issue la configuration du module enregistré.
+	config: Option<ConfigValue>,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// État d'un module au cours du cycle de vie du kernel.
+#[derive(Copy, Clone)]
+#[derive(Debug)]
+#[derive(PartialEq, Eq)]
+enum ModuleState
+{
+	/// Le module n'est pas (ou plus) enregistré.
+	Idle,
+	/// Le module est enregistré, mais n'a pas (encore) démarré.
+	Registered,
+	/// Le module a démarré.
+	Booted,
+}
+
+/// État d'un module lors du tri topologique.
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+enum VisitState
+{
+	Unvisited,
+	Visiting,
+	Visited,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl KernelModules
+{
+	pub(crate) fn push(&mut self, module: impl KernelModule)
+	{
+		self.modules.push(KernelModuleEntry {
+			module: Box::new(module),
+			state: ModuleState::Idle,
+			config: None,
+		});
+	}
+
+	/// Configurations des modules enregistrés, par nom de module.
+	pub(crate) fn configs(&self) -> impl Iterator<Item = (&'static str, &ConfigValue)> + '_
+	{
+		self.modules
+			.iter()
+			.filter_map(|entry| Some((entry.module.name(), entry.config.as_ref()?)))
+	}
+
+	/// Trie les modules, puis les enregistre et les démarre dans l'ordre de
+	/// leurs dépendances. En cas d'erreur, les modules déjà enregistrés
+	/// DOIVENT être arrêtés via [KernelModules::stop()].
+	pub(crate) fn start(&mut self, context: &HookContext) -> Result<(), KernelError>
+	{
+		self.sort()?;
+
+		for entry in self.modules.iter_mut() {
+			let name = entry.module.name();
+			log::debug!("Enregistrement du module « {name} »");
+			entry.config = entry
+				.module
+				.register(context)
+				.map_err(|reason| KernelError::Module { name, phase: "register", reason })?;
+			entry.state = ModuleState::Registered;
+		}
+
+		for entry in self.modules.iter_mut() {
+			let name = entry.module.name();
+			log::debug!("Démarrage du module « {name} »");
+			entry
+				.module
+				.boot(context)
+				.map_err(|reason| KernelError::Module { name, phase: "boot", reason })?;
+			entry.state = ModuleState::Booted;
+		}
+
+		Ok(())
+	}
+
+	/// Arrête les modules enregistrés, démarrés ou non (lorsque le démarrage
+	/// a échoué en cours de route), dans l'ordre inverse du démarrage. Toutes
+	/// les erreurs sont retournées.
+	pub(crate) fn stop(&mut self, context: &HookContext) -> Vec<KernelError>
+	{
+		let mut errors = Vec::new();
+
+		for entry in self.modules.iter_mut().rev().filter(|entry| entry.state != ModuleState::Idle) {
+			let name = entry.module.name();
+			log::debug!("Arrêt du module « {name} »");
+			entry.state = ModuleState::Idle;
+			if let Err(reason) = entry.module.shutdown(context) {
+				errors.push(KernelError::Module { name, phase: "shutdown", reason });
+			}
+		}
+
+		errors
+	}
+
+	/// Tri topologique des modules. L'ordre d'ajout est conservé entre les
+	/// modules indépendants.
+	fn sort(&mut self) -> Result<(), KernelError>
+	{
+		let mut indexes: HashMap<&'static str, usize> = HashMap::with_capacity(self.modules.len());
+
+		for (index, entry) in self.modules.iter().enumerate() {
+			let name = entry.module.name();
+			if indexes.insert(name, index).is_some() {
+				return Err(KernelError::ModuleDuplicate(name));
+			}
+		}
+
+		for entry in &self.modules {
+			for dependency in entry.module.dependencies() {
+				if !indexes.contains_key(dependency) {
+					return Err(KernelError::ModuleDependency {
+						module: entry.module.name(),
+						dependency,
+					});
+				}
+			}
+		}
+
+		let mut order = Vec::with_capacity(self.modules.len());
+		let mut states = vec![VisitState::Unvisited; self.modules.len()];
+		let mut path = Vec::new();
+
+		for index in 0..self.modules.len() {
+			self.visit(index, &indexes, &mut states, &mut path, &mut order)?;
+		}
+
+		let mut modules: Vec<_> = std::mem::take(&mut self.modules).into_iter().map(Some).collect();
+		self.modules = order
+			.into_iter()
+			.filter_map(|index| modules[index].take())
+			.collect();
+
+		Ok(())
+	}
+
+	fn visit(
+		&self,
+		index: usize,
+		indexes: &HashMap<&'static str, usize>,
+		states: &mut [VisitState],
+		path: &mut Vec<&'static str>,
+		order: &mut Vec<usize>,
+	) -> Result<(), KernelError>
+	{
+		let module = &self.modules[index].module;
+
+		match states[index] {
+			| VisitState::Visited => return Ok(()),
+			| VisitState::Visiting => {
+				let start = path.iter().position(|name| *name == module.name()).unwrap_or_default();
+				let mut cycle = path[start..].to_vec();
+				cycle.push(module.name());
+				return Err(KernelError::ModuleCycle(cycle));
+			}
+			| VisitState::Unvisited => {}
+		}
+
+		states[index] = VisitState::Visiting;
+		path.push(module.name());
+
+		for dependency in module.dependencies() {
+			self.visit(indexes[dependency], indexes, states, path, order)?;
+		}
+
+		path.pop();
+		states[index] = VisitState::Visited;
+		order.push(index);
+
+		Ok(())
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<M> AnyKernelModule for M
+where
+	M: KernelModule,
+{
+	fn name(&self) -> &'static str
+	{
+		M::NAME
+	}
+
+	fn dependencies(&self) -> &'static [&'static str]
+	{
+		KernelModule::dependencies(self)
+	}
+
+	fn register(&mut self, context: &HookContext) -> Result<Option<ConfigValue>, HookError>
+	{
+		let (config, value) = match fetch_config(M::NAME, context.settings()) {
+			| Ok((config, value)) => (config, Some(value)),
+			| Err(err) if is_missing_config(&err) => (M::Config::default(), None),
+			| Err(err) => return Err(err.into()),
+		};
+		validate_config_with(M::NAME, |violations| KernelModule::validate_config(self, &config, violations))?;
+
+		KernelModule::register(self, context, config)?;
+		Ok(value)
+	}
+
+	fn boot(&mut self, context: &HookContext) -> Result<(), HookError>
+	{
+		KernelModule::boot(self, context)
+	}
+
+	fn shutdown(&mut self, context: &HookContext) -> Result<(), HookError>
+	{
+		KernelModule::shutdown(self, context)
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::path;
+	use std::sync::{Arc, Mutex};
+
+	use super::*;
+	use crate::kernel::service::ServiceContainer;
+	use crate::kernel::settings::KernelSettings;
+	use crate::logger::signal::LoggerSignal;
+	use crate::process::ProcessMode;
+
+	type Events = Arc<Mutex<Vec<String>>>;
+
+	#[derive(Default)]
+	#[derive(serde::Deserialize)]
+	struct Options
+	{
+		#[allow(dead_code)]
+		enabled: bool,
+	}
+
+	struct Module<const N: usize>
+	{
+		dependencies: &'static [&'static str],
+		fail_boot: bool,
+		events: Events,
+	}
+
+	macro_rules! module {
+		($($n:literal => $name:literal),*) => {
+			$(
+				impl KernelModule for Module<$n>
+				{
+					type Config = Options;
+
+					const NAME: &'static str = $name;
+
+					fn dependencies(&self) -> &'static [&'static str]
+					{
+						self.dependencies
+					}
+
+					fn register(&mut self, _: &HookContext, _: Self::Config) -> Result<(), HookError>
+					{
+						self.events.lock().unwrap().push(format!("register {}", Self::NAME));
+						Ok(())
+					}
+
+					fn boot(&mut self, _: &HookContext) -> Result<(), HookError>
+					{
+						if self.fail_boot {
+							return Err("boot".into());
+						}
+						self.events.lock().unwrap().push(format!("boot {}", Self::NAME));
+						Ok(())
+					}
+
+					fn shutdown(&mut self, _: &HookContext) -> Result<(), HookError>
+					{
+						self.events.lock().unwrap().push(format!("shutdown {}", Self::NAME));
+						Ok(())
+					}
+				}
+			)*
+		};
+	}
+
+	module!(0 => "db", 1 => "cache", 2 => "http");
+
+	fn module<const N: usize>(dependencies: &'static [&'static str], events: &Events) -> Module<N>
+	{
+		Module {
+			dependencies,
+			fail_boot: false,
+			events: Arc::clone(events),
+		}
+	}
+
+	fn settings(config_directory: &path::Path) -> KernelSettings
+	{
+		let mut settings = KernelSettings::new(config_directory, ProcessMode::LOCAL);
+		settings.directory.set_config_directory(config_directory);
+		settings
+	}
+
+	fn with_context<R>(settings: &KernelSettings, f: impl FnOnce(&HookContext) -> R) -> R
+	{
+		let signal = LoggerSignal::create("test", "0.0.0");
+		let services = ServiceContainer::default();
+		f(&HookContext::new("test", "0.0.0", settings, &signal, &services))
+	}
+
+	fn names(modules: &KernelModules) -> Vec<&'static str>
+	{
+		modules.modules.iter().map(|entry| entry.module.name()).collect()
+	}
+
+	#[test]
+	fn test_sort_dependencies_first()
+	{
+		let events = Events::default();
+		let mut modules = KernelModules::default();
+		modules.push(module::<2>(&["cache", "db"], &events));
+		modules.push(module::<1>(&["db"], &events));
+		modules.push(module::<0>(&[], &events));
+
+		modules.sort().unwrap();
+		assert_eq!(names(&modules), ["db", "cache", "http"]);
+	}
+
+	#[test]
+	fn test_sort_keeps_insertion_order()
+	{
+		let events = Events::default();
+		let mut modules = KernelModules::default();
+		modules.push(module::<1>(&[], &events));
+		modules.push(module::<0>(&[], &events));
+
+		modules.sort().unwrap();
+		assert_eq!(names(&modules), ["cache", "db"]);
+	}
+
+	#[test]
+	fn test_sort_duplicate()
+	{
+		let events = Events::default();
+		let mut modules = KernelModules::default();
+		modules.push(module::<0>(&[], &events));
+		modules.push(module::<0>(&[], &events));
+
+		assert!(matches!(modules.sort(), Err(KernelError::ModuleDuplicate("db"))));
+	}
+
+	#[test]
+	fn test_sort_missing_dependency()
+	{
+		let events = Events::default();
+		let mut modules = KernelModules::default();
+		modules.push(module::<1>(&["db"], &events));
+
+		assert!(matches!(
+			modules.sort(),
+			Err(KernelError::ModuleDependency {
+				module: "cache",
+				dependency: "db"
+			})
+		));
+	}
+
+	#[test]
+	fn test_sort_cycle()
+	{
+		let events = Events::default();
+		let mut modules = KernelModules::default();
+		modules.push(module::<0>(&["http"], &events));
+		modules.push(module::<1>(&["db"], &events));
+		modules.push(module::<2>(&["cache"], &events));
+
+		let Err(KernelError::ModuleCycle(cycle)) = modules.sort() else {
+			panic!("un cycle est attendu");
+		};
+		assert_eq!(cycle, ["db", "http", "cache", "db"]);
+	}
+
+	#[test]
+	fn test_start_and_stop_in_reverse_order()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		let events = Events::default();
+		let mut modules = KernelModules::default();
+		modules.push(module::<1>(&["db"], &events));
+		modules.push(module::<0>(&[], &events));
+
+		with_context(&settings(dir.path()), |context| {
+			modules.start(context).unwrap();
+			assert!(modules.stop(context).is_empty());
+			assert!(modules.stop(context).is_empty());
+		});
+
+		assert_eq!(
+			*events.lock().unwrap(),
+			["register db", "register cache", "boot db", "boot cache", "shutdown cache", "shutdown db"]
+		);
+	}
+
+	#[test]
+	fn test_stop_registered_modules_after_boot_failure()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		let events = Events::default();
+		let mut modules = KernelModules::default();
+		modules.push(module::<0>(&[], &events));
+		modules.push(Module::<1> {
+			fail_boot: true,
+			..module(&["db"], &events)
+		});
+		modules.push(module::<2>(&["cache"], &events));
+
+		with_context(&settings(dir.path()), |context| {
+			assert!(matches!(
+				modules.start(context),
+				Err(KernelError::Module {
+					name: "cache",
+					phase: "boot",
+					..
+				})
+			));
+			assert!(modules.stop(context).is_empty());
+		});
+
+		assert_eq!(
+			*events.lock().unwrap(),
+			[
+				"register db",
+				"register cache",
+				"register http",
+				"boot db",
+				"shutdown http",
+				"shutdown cache",
+				"shutdown db",
+			]
+		);
+	}
+
+	#[test]
+	fn test_register_default_config_only_when_missing()
+	{
+		let dir = tempfile::tempdir().unwrap();
+		let events = Events::default();
+		let settings = settings(dir.path());
+
+		let mut modules = KernelModules::default();
+		modules.push(module::<0>(&[], &events));
+		with_context(&settings, |context| modules.start(context).unwrap());
+
+		std::fs::write(dir.path().join("db.yml"), "$include: missing.yml\nenabled: true\n").unwrap();
+		let mut modules = KernelModules::default();
+		modules.push(module::<0>(&[], &events));
+		with_context(&settings, |context| {
+			assert!(matches!(
+				modules.start(context),
+				Err(KernelError::Module {
+					name: "db",
+					phase: "register",
+					..
+				})
+			));
+		});
+	}
+}
@@ -0,0 +1,309 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::marker::PhantomData;
+use std::{fmt, str};
+
+use serde::de::value::{
+	BorrowedStrDeserializer,
+	BytesDeserializer,
+	EnumAccessDeserializer,
+	MapAccessDeserializer,
+	SeqAccessDeserializer,
+};
+use serde::de::{EnumAccess, IntoDeserializer, MapAccess, SeqAccess, Visitor};
+use zeroize::Zeroize;
+
+use crate::REDACTED_VALUE;
+
+// -------- //
+// Constant //
+// -------- //
+
+/// Nom de la structure [Secret] transmis aux dé-sérialiseurs
+/// ([serde::Deserializer::deserialize_newtype_struct()]) : les
+/// dé-sérialiseurs du kernel enregistrent ainsi les chemins des valeurs
+/// secrètes.
+pub(crate) const SECRET_STRUCT_NAME: &str = "lexa_kernel::Secret";
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Valeur secrète (mot de passe, jeton, ...).
+///
+/// La valeur n'est accessible que via [Secret::expose()] : ses
+/// représentations [Debug](fmt::Debug) et [Display](fmt::Display) sont
+/// masquées, ce qui protège notamment les logs du kernel. La mémoire de la
+/// valeur est mise à zéro lorsque la structure est libérée.
+///
+/// La structure est dé-sérialisée et sérialisée comme sa valeur : la
+/// sérialisation expose donc la valeur (ex: lors de la création d'un
+/// fichier de configuration). Lors de sa dé-sérialisation, le chemin de la
+/// valeur est enregistré : la valeur est masquée dans la configuration
+/// effective (voir [Kernel::dump_config()](crate::Kernel::dump_config)),
+/// quel que soit le nom de la clé. Le chemin n'est pas enregistré lorsque la
+/// valeur est dé-sérialisée via une valeur intermédiaire (ex:
+/// `#[serde(flatten)]`, `#[serde(untagged)]`).
+pub struct Secret<T>
+where
+	T: Zeroize,
+{
+	value: T,
+}
+
+/// Visiteur de [Secret].
+struct SecretVisitor<T>(PhantomData<T>);
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<T> Secret<T>
+where
+	T: Zeroize,
+{
+	pub fn new(value: T) -> Self
+	{
+		Self { value }
+	}
+
+	/// Expose la valeur secrète.
+	pub fn expose(&self) -> &T
+	{
+		&self.value
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<T> Drop for Secret<T>
+where
+	T: Zeroize,
+{
+	fn drop(&mut self)
+	{
+		self.value.zeroize();
+	}
+}
+
+impl<T> From<T> for Secret<T>
+where
+	T: Zeroize,
+{
+	fn from(value: T) -> Self
+	{
+		Self::new(value)
+	}
+}
+
+impl<T> Clone for Secret<T>
+where
+	T: Zeroize + Clone,
+{
+	fn clone(&self) -> Self
+	{
+		Self::new(self.value.clone())
+	}
+}
+
+impl<T> Default for Secret<T>
+where
+	T: Zeroize + Default,
+{
+	fn default() -> Self
+	{
+		Self::new(T::default())
+	}
+}
+
+impl<T> fmt::Debug for Secret<T>
+where
+	T: Zeroize,
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "Secret({REDACTED_VALUE})")
+	}
+}
+
+impl<T> fmt::Display for Secret<T>
+where
+	T: Zeroize,
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "{REDACTED_VALUE}")
+	}
+}
+
+impl<T> str::FromStr for Secret<T>
+where
+	T: Zeroize + str::FromStr,
+{
+	type Err = T::Err;
+
+	fn from_str(value: &str) -> Result<Self, Self::Err>
+	{
+		value.parse().map(Self::new)
+	}
+}
+
+impl<'de, T> serde::Deserialize<'de> for Secret<T>
+where
+	T: Zeroize + serde::Deserialize<'de>,
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		deserializer.deserialize_newtype_struct(SECRET_STRUCT_NAME, SecretVisitor(PhantomData))
+	}
+}
+
+macro_rules! secret_visit {
+	($($method:ident: $ty:ty),* $(,)?) => {
+		$(
+			fn $method<E>(self, value: $ty) -> Result<Self::Value, E>
+			where
+				E: serde::de::Error,
+			{
+				T::deserialize(value.into_deserializer()).map(Secret::new)
+			}
+		)*
+	};
+}
+
+// NOTE: les dé-sérialiseurs qui ne distinguent pas les structures newtype
+//       (ex: `forward_to_deserialize_any!`) visitent directement la valeur.
+impl<'de, T> Visitor<'de> for SecretVisitor<T>
+where
+	T: Zeroize + serde::Deserialize<'de>,
+{
+	type Value = Secret<T>;
+
+	secret_visit! {
+		visit_bool: bool,
+		visit_i64: i64,
+		visit_i128: i128,
+		visit_u64: u64,
+		visit_u128: u128,
+		visit_f64: f64,
+		visit_char: char,
+		visit_str: &str,
+		visit_string: String,
+		visit_byte_buf: Vec<u8>,
+	}
+
+	fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "une valeur secrète")
+	}
+
+	fn visit_borrowed_str<E>(self, value: &'de str) -> Result<Self::Value, E>
+	where
+		E: serde::de::Error,
+	{
+		T::deserialize(BorrowedStrDeserializer::new(value)).map(Secret::new)
+	}
+
+	fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+	where
+		E: serde::de::Error,
+	{
+		T::deserialize(BytesDeserializer::new(value)).map(Secret::new)
+	}
+
+	fn visit_none<E>(self) -> Result<Self::Value, E>
+	where
+		E: serde::de::Error,
+	{
+		T::deserialize(().into_deserializer()).map(Secret::new)
+	}
+
+	fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		T::deserialize(deserializer).map(Secret::new)
+	}
+
+	fn visit_unit<E>(self) -> Result<Self::Value, E>
+	where
+		E: serde::de::Error,
+	{
+		T::deserialize(().into_deserializer()).map(Secret::new)
+	}
+
+	fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		T::deserialize(deserializer).map(Secret::new)
+	}
+
+	fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+	where
+		A: SeqAccess<'de>,
+	{
+		T::deserialize(SeqAccessDeserializer::new(seq)).map(Secret::new)
+	}
+
+	fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+	where
+		A: MapAccess<'de>,
+	{
+		T::deserialize(MapAccessDeserializer::new(map)).map(Secret::new)
+	}
+
+	fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+	where
+		A: EnumAccess<'de>,
+	{
+		T::deserialize(EnumAccessDeserializer::new(data)).map(Secret::new)
+	}
+}
+
+impl<T> serde::Serialize for Secret<T>
+where
+	T: Zeroize + serde::Serialize,
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		self.value.serialize(serializer)
+	}
+}
+
+#[cfg(feature = "schema")]
+impl<T> schemars::JsonSchema for Secret<T>
+where
+	T: Zeroize + schemars::JsonSchema,
+{
+	fn inline_schema() -> bool
+	{
+		true
+	}
+
+	fn schema_name() -> std::borrow::Cow<'static, str>
+	{
+		format!("Secret_{}", T::schema_name()).into()
+	}
+
+	fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema
+	{
+		let mut schema = generator.subschema_for::<T>();
+		schema.insert(String::from("writeOnly"), serde_json::Value::Bool(true));
+		schema
+	}
+}
@@ -0,0 +1,193 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::any::{self, Any, TypeId};
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+use crate::{HookError, KernelError};
+
+// ---- //
+// Type //
+// ---- //
+
+type AnyService = Arc<dyn Any + Send + Sync>;
+type ServiceFactory = Box<dyn Fn(&ServiceContainer) -> Result<AnyService, HookError> + Send + Sync>;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Conteneur de services, indexé par type.
+///
+/// Ce conteneur est partagé (clonable) entre le kernel, l'application
+/// adapter, les fonctions de cycle de vie et les modules. Un service PEUT
+/// être enregistré :
+///
+///   1. comme singleton : l'instance est donnée lors de l'enregistrement ;
+///   2. comme singleton paresseux : l'instance est construite lors de la
+///      première résolution, puis réutilisée ;
+///   3. comme fabrique : une nouvelle instance est construite à chaque
+///      résolution.
+///
+/// Les fonctions de construction reçoivent le conteneur afin de résoudre
+/// leurs propres dépendances.
+#[derive(Clone)]
+#[derive(Default)]
+pub struct ServiceContainer
+{
+	services: Arc<RwLock<HashMap<TypeId, Arc<ServiceEntry>>>>,
+}
+
+struct ServiceEntry
+{
+	name: &'static str,
+	kind: ServiceKind,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+enum ServiceKind
+{
+	Singleton(AnyService),
+	LazySingleton(OnceLock<AnyService>, ServiceFactory),
+	Factory(ServiceFactory),
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ServiceContainer
+{
+	/// Enregistre une instance de service. Un service déjà enregistré pour ce
+	/// type est remplacé.
+	pub fn singleton<T>(&self, service: T)
+	where
+		T: Send + Sync + 'static,
+	{
+		self.insert::<T>(ServiceKind::Singleton(Arc::new(service)));
+	}
+
+	/// Enregistre un service construit lors de sa première résolution.
+	///
+	/// NOTE: si deux threads résolvent le service en même temps, la fonction
+	/// de construction PEUT être appelée deux fois ; une seule instance est
+	/// conservée.
+	pub fn lazy_singleton<T, Err>(
+		&self,
+		factory: impl Fn(&ServiceContainer) -> Result<T, Err> + Send + Sync + 'static,
+	) where
+		T: Send + Sync + 'static,
+		Err: Into<HookError>,
+	{
+		self.insert::<T>(ServiceKind::LazySingleton(OnceLock::new(), Self::erase(factory)));
+	}
+
+	/// Enregistre une fabrique de service : une nouvelle instance est
+	/// construite à chaque résolution.
+	pub fn factory<T, Err>(&self, factory: impl Fn(&ServiceContainer) -> Result<T, Err> + Send + Sync + 'static)
+	where
+		T: Send + Sync + 'static,
+		Err: Into<HookError>,
+	{
+		self.insert::<T>(ServiceKind::Factory(Self::erase(factory)));
+	}
+
+	/// Est-ce qu'un service est enregistré pour ce type?
+	pub fn contains<T>(&self) -> bool
+	where
+		T: 'static,
+	{
+		self.services
+			.read()
+			.unwrap_or_else(|err| err.into_inner())
+			.contains_key(&TypeId::of::<T>())
+	}
+
+	/// Résout un service à partir de son type.
+	pub fn resolve<T>(&self) -> Result<Arc<T>, KernelError>
+	where
+		T: Send + Sync + 'static,
+	{
+		let entry = self
+			.services
+			.read()
+			.unwrap_or_else(|err| err.into_inner())
+			.get(&TypeId::of::<T>())
+			.cloned()
+			.ok_or(KernelError::ServiceNotFound(any::type_name::<T>()))?;
+
+		let service = match &entry.kind {
+			| ServiceKind::Singleton(service) => service.clone(),
+			| ServiceKind::LazySingleton(instance, factory) => {
+				if let Some(service) = instance.get() {
+					service.clone()
+				} else {
+					let service = self.build(entry.name, factory)?;
+					instance.get_or_init(|| service).clone()
+				}
+			}
+			| ServiceKind::Factory(factory) => self.build(entry.name, factory)?,
+		};
+
+		Ok(service
+			.downcast::<T>()
+			.expect("Le type du service correspond à sa clé"))
+	}
+
+	fn build(&self, name: &'static str, factory: &ServiceFactory) -> Result<AnyService, KernelError>
+	{
+		factory(self).map_err(|reason| KernelError::Service { name, reason })
+	}
+
+	fn insert<T>(&self, kind: ServiceKind)
+	where
+		T: 'static,
+	{
+		let entry = ServiceEntry {
+			name: any::type_name::<T>(),
+			kind,
+		};
+		self.services
+			.write()
+			.unwrap_or_else(|err| err.into_inner())
+			.insert(TypeId::of::<T>(), Arc::new(entry));
+	}
+
+	fn erase<T, Err>(factory: impl Fn(&ServiceContainer) -> Result<T, Err> + Send + Sync + 'static) -> ServiceFactory
+	where
+		T: Send + Sync + 'static,
+		Err: Into<HookError>,
+	{
+		Box::new(move |container| {
+			factory(container)
+				.map(|service| Arc::new(service) as AnyService)
+				.map_err(Into::into)
+		})
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl std::fmt::Debug for ServiceContainer
+{
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+	{
+		let services = self.services.read().unwrap_or_else(|err| err.into_inner());
+		f.debug_set()
+			.entries(services.values().map(|entry| entry.name))
+			.finish()
+	}
+}
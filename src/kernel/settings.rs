@@ -8,27 +8,40 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+use std::collections::HashSet;
 use std::path;
+use std::sync::Arc;
+use std::time::Duration;
 
 use crate::process::ProcessMode;
+use crate::{ConfigSource, KernelError, SecretFilePermissions};
 
 // --------- //
 // Structure //
 // --------- //
 
+#[derive(Clone)]
 pub struct KernelSettings
 {
 	/// Répertoires d'application.
 	pub directory: KernelSettingsDirectory,
+	/// Paramètres de chargement des fichiers de configuration.
+	pub config: KernelSettingsConfig,
+	/// Paramètres de chargement des variables d'environnement.
+	pub env: KernelSettingsEnv,
 	/// Quelle extension doit-on utiliser pour lire les fichiers de
-	/// configuration.
+	/// configuration, lorsqu'elle ne peut pas être détectée, ou pour les
+	/// créer.
 	pub loader_extension: lexa_fs::Extension,
+	/// Formats des fichiers de configuration détectés, par ordre de priorité.
+	pub loader_extensions: Vec<lexa_fs::Extension>,
 	/// Mode d’exécution de l'application.
 	pub process_mode: ProcessMode,
 	/// Affiche les informations de l'application dès le démarrage?
 	pub(super) startup_info: bool,
 }
 
+#[derive(Clone)]
 #[derive(Default)]
 pub struct KernelSettingsDirectory
 {
@@ -40,6 +53,71 @@ pub struct KernelSettingsDirectory
 	config: Option<path::PathBuf>,
 	/// Répertoire des variables d'environnement de l'application.
 	env: Option<path::PathBuf>,
+	/// Répertoire des fichiers secrets de l'application (ex:
+	/// `/run/secrets`).
+	secrets: Option<path::PathBuf>,
+}
+
+#[derive(Clone)]
+#[derive(Default)]
+pub struct KernelSettingsEnv
+{
+	/// Stratégie de chargement des variables d'environnement.
+	pub strategy: EnvLoadingStrategy,
+	/// Préfixe des variables d'environnement du processus qui définissent
+	/// les champs des variables d'environnement de l'application (ex:
+	/// `LEXA_APP_`). Une chaîne vide retient toutes les variables.
+	pub prefix: String,
+}
+
+#[derive(Clone)]
+pub struct KernelSettingsConfig
+{
+	/// Préfixe des variables d'environnement qui surchargent les clés des
+	/// configurations (ex: `LEXA_APP`). Une chaîne vide désactive les
+	/// surcharges.
+	pub env_prefix: String,
+	/// Configurations qui NE DOIVENT PAS être surchargées par les variables
+	/// d'environnement.
+	env_overrides_disabled: HashSet<String>,
+	/// Configurations dont les références `${...}` NE DOIVENT PAS être
+	/// interpolées.
+	interpolation_disabled: HashSet<String>,
+	/// Sources de configuration, par ordre de priorité croissante.
+	sources: Vec<Arc<dyn ConfigSource>>,
+	/// Motifs des clés dont les valeurs sont secrètes (ex: `*password*`),
+	/// masquées dans la configuration effective (voir
+	/// [Kernel::dump_config()](crate::Kernel::dump_config)).
+	pub secret_patterns: Vec<String>,
+	/// Comportement face aux fichiers secrets lisibles par tous les
+	/// utilisateurs du système (voir [read_secret_file()](crate::read_secret_file)).
+	pub secret_file_permissions: SecretFilePermissions,
+	/// Intervalle de vérification des fichiers des configurations
+	/// surveillées (voir [Kernel::watch_config()](crate::Kernel::watch_config)).
+	pub watch_interval: Duration,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Stratégie de chargement des variables d'environnement de l'application
+/// (voir [ApplicationEnvInterface::fetch()](crate::ApplicationEnvInterface::fetch)).
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum EnvLoadingStrategy
+{
+	/// Les fichiers d'environnement uniquement. Au moins l'un d'entre eux
+	/// DOIT exister.
+	#[default]
+	FileOnly,
+	/// Les variables d'environnement préfixées du processus uniquement.
+	ProcessOnly,
+	/// Les fichiers d'environnement, lorsqu'ils existent, surchargés par les
+	/// variables d'environnement préfixées du processus.
+	FileOverlaidByProcess,
 }
 
 // -------------- //
@@ -48,11 +126,12 @@ pub struct KernelSettingsDirectory
 
 impl KernelSettings
 {
-	/// Construit les paramètres du kernel.
-	pub fn new(dir: impl Into<path::PathBuf>) -> Self
+	/// Construit les paramètres du kernel pour un mode d'exécution donné.
+	///
+	/// Le mode d'exécution est généralement obtenu via
+	/// [ProcessMode::resolve()].
+	pub fn new(dir: impl Into<path::PathBuf>, process_mode: ProcessMode) -> Self
 	{
-		let process_mode = ProcessMode::from_rustcfg();
-
 		let application_directory = dir.into();
 
 		let config_directory = application_directory
@@ -66,21 +145,102 @@ impl KernelSettings
 			.then_some(application_directory.join("env"));
 
 		Self {
+			config: Default::default(),
+			env: Default::default(),
 			directory: KernelSettingsDirectory {
 				config: config_directory,
 				env: env_directory,
+				secrets: None,
 				project_root: application_directory.clone(),
 				application_root: application_directory,
 			},
 			loader_extension: lexa_fs::Extension::YAML,
+			loader_extensions: vec![
+				lexa_fs::Extension::YAML,
+				lexa_fs::Extension::JSON,
+				lexa_fs::Extension::TOML,
+			],
 			process_mode,
 			startup_info: true,
 		}
 	}
 }
 
+impl KernelSettingsConfig
+{
+	/// Intervalle par défaut de vérification des fichiers des configurations
+	/// surveillées.
+	pub const DEFAULT_WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+	/// Motifs par défaut des clés dont les valeurs sont secrètes.
+	pub const DEFAULT_SECRET_PATTERNS: [&'static str; 6] = [
+		"*password*",
+		"*passwd*",
+		"*secret*",
+		"*token*",
+		"*api_key*",
+		"*private_key*",
+	];
+
+	/// Est-ce que la configuration peut être surchargée par les variables
+	/// d'environnement?
+	pub fn has_env_overrides(&self, config_name: &str) -> bool
+	{
+		!self.env_prefix.is_empty() && !self.env_overrides_disabled.contains(config_name)
+	}
+
+	/// Désactive les surcharges par les variables d'environnement d'une
+	/// configuration.
+	pub fn disable_env_overrides(&mut self, config_name: impl Into<String>)
+	{
+		self.env_overrides_disabled.insert(config_name.into());
+	}
+
+	/// Est-ce que les références `${...}` de la configuration doivent être
+	/// interpolées?
+	pub fn has_interpolation(&self, config_name: &str) -> bool
+	{
+		!self.interpolation_disabled.contains(config_name)
+	}
+
+	/// Désactive l'interpolation des références `${...}` d'une
+	/// configuration.
+	pub fn disable_interpolation(&mut self, config_name: impl Into<String>)
+	{
+		self.interpolation_disabled.insert(config_name.into());
+	}
+
+	/// Sources de configuration, par ordre de priorité croissante.
+	pub fn sources(&self) -> impl Iterator<Item = &dyn ConfigSource>
+	{
+		self.sources.iter().map(AsRef::as_ref)
+	}
+
+	/// Ajoute une source de configuration, prioritaire sur les sources
+	/// précédentes.
+	pub fn add_source(&mut self, source: impl ConfigSource + 'static)
+	{
+		self.sources.push(Arc::new(source));
+	}
+}
+
 impl KernelSettingsDirectory
 {
+	const CONFIG: &'static str = "de la configuration";
+	const ENV: &'static str = "des variables d'environnement";
+
+	fn existing<'a>(name: &'static str, dir: Option<&'a path::Path>) -> Result<&'a path::Path, KernelError>
+	{
+		let dir = dir.ok_or(KernelError::DirectoryUndefined(name))?;
+		if !dir.is_dir() {
+			return Err(KernelError::DirectoryNotFound {
+				name,
+				path: dir.to_owned(),
+			});
+		}
+		Ok(dir)
+	}
+
 	/// Répertoire racine du projet.
 	pub fn project_root(&self) -> &path::Path
 	{
@@ -99,6 +259,13 @@ impl KernelSettingsDirectory
 		self.config.as_deref()
 	}
 
+	/// Répertoire de la configuration de l'application. Retourne une erreur
+	/// si aucun répertoire n'est défini ou si le répertoire n'existe pas.
+	pub fn try_config(&self) -> Result<&path::Path, KernelError>
+	{
+		Self::existing(Self::CONFIG, self.config.as_deref())
+	}
+
 	/// Répertoire de la configuration de l'application. Cette fonction PEUT
 	/// paniquer si aucun répertoire n'est trouvé.
 	pub fn config_sudo(&self) -> &path::Path
@@ -112,12 +279,26 @@ impl KernelSettingsDirectory
 		self.env.as_deref()
 	}
 
+	/// Répertoire des variables d'environnement de l'application. Retourne une
+	/// erreur si aucun répertoire n'est défini ou si le répertoire n'existe
+	/// pas.
+	pub fn try_env(&self) -> Result<&path::Path, KernelError>
+	{
+		Self::existing(Self::ENV, self.env.as_deref())
+	}
+
 	/// Répertoire des variables d'environnement de l'application. Cette
 	/// fonction PEUT paniquer si aucun répertoire n'est trouvé.
 	pub fn env_sudo(&self) -> &path::Path
 	{
 		self.env.as_deref().expect("Répertoire de variables d'environnement")
 	}
+
+	/// Répertoire des fichiers secrets de l'application.
+	pub fn secrets(&self) -> Option<&path::Path>
+	{
+		self.secrets.as_deref()
+	}
 }
 
 impl KernelSettingsDirectory
@@ -144,6 +325,20 @@ impl KernelSettingsDirectory
 		}
 	}
 
+	/// Voir [KernelSettingsDirectory::set_config_directory()]. Retourne une
+	/// erreur si le nouveau répertoire n'existe pas ; dans ce cas, le
+	/// répertoire actuel est conservé.
+	pub fn try_set_config_directory(&mut self, dir: impl Into<path::PathBuf>) -> Result<(), KernelError>
+	{
+		let previous = self.config.take();
+		self.set_config_directory(dir);
+		if let Err(err) = self.try_config() {
+			self.config = previous;
+			return Err(err);
+		}
+		Ok(())
+	}
+
 	/// Remplace le répertoire des variables d'environnement actuellement
 	/// définit par un nouveau.
 	pub fn set_env_directory(&mut self, dir: impl Into<path::PathBuf>)
@@ -159,4 +354,40 @@ impl KernelSettingsDirectory
 			self.env.replace(self.application_root.join(dir));
 		}
 	}
+
+	/// Remplace le répertoire des fichiers secrets actuellement définit par
+	/// un nouveau.
+	pub fn set_secrets_directory(&mut self, dir: impl Into<path::PathBuf>)
+	{
+		let dir: path::PathBuf = dir.into();
+
+		if dir.starts_with("/") {
+			self.secrets.replace(dir);
+		} else if dir.starts_with("~/") {
+			let dir_s = dir.to_str().unwrap().replace("~/", "");
+			self.secrets.replace(self.project_root.join(dir_s));
+		} else {
+			self.secrets.replace(self.application_root.join(dir));
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl Default for KernelSettingsConfig
+{
+	fn default() -> Self
+	{
+		Self {
+			env_prefix: Default::default(),
+			env_overrides_disabled: Default::default(),
+			interpolation_disabled: Default::default(),
+			sources: Default::default(),
+			secret_patterns: Self::DEFAULT_SECRET_PATTERNS.map(String::from).to_vec(),
+			secret_file_permissions: Default::default(),
+			watch_interval: Self::DEFAULT_WATCH_INTERVAL,
+		}
+	}
 }
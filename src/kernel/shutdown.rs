@@ -0,0 +1,162 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::sync::mpsc;
+use std::time::Duration;
+
+use super::hooks::{HookContext, HookPhase, KernelHooks};
+use super::module::KernelModules;
+use crate::logger::signal::LoggerSignal;
+use crate::process::{self, ShutdownToken};
+use crate::KernelError;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Gestion de l'arrêt de l'application.
+pub(crate) struct KernelShutdown
+{
+	/// Jeton d'arrêt partagé avec l'application adapter.
+	pub(crate) token: ShutdownToken,
+	/// Délai accordé à l'arrêt de l'application.
+	pub(crate) timeout: Duration,
+	/// Notifie la fin de l'arrêt au chien de garde.
+	completed: Option<mpsc::Sender<()>>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl KernelShutdown
+{
+	pub(crate) fn new() -> Self
+	{
+		Self {
+			token: ShutdownToken::new(),
+			timeout: process::SHUTDOWN_DEFAULT_TIMEOUT,
+			completed: None,
+		}
+	}
+
+	/// Installe les gestionnaires de signaux et le chien de garde qui force
+	/// l'arrêt du processus lorsque le délai d'arrêt est dépassé.
+	pub(crate) fn listen(&mut self, logger_signal: &LoggerSignal)
+	{
+		if let Err(err) = process::install_signal_handlers(&self.token) {
+			logger_signal.send_warning(format!(
+				"Les gestionnaires des signaux d'arrêt n'ont pas pu être installés. Raison « {err} »"
+			));
+		}
+
+		let (completed_tx, completed_rx) = mpsc::channel::<()>();
+		let token = self.token.clone();
+		let timeout = self.timeout;
+
+		let watchdog = std::thread::Builder::new()
+			.name(String::from("lexa-kernel-shutdown"))
+			.spawn(move || {
+				token.wait();
+
+				if let Err(mpsc::RecvTimeoutError::Timeout) = completed_rx.recv_timeout(timeout) {
+					eprintln!(
+						"L'arrêt de l'application a dépassé le délai de {timeout:?}, arrêt forcé du processus."
+					);
+					std::process::exit(process::SHUTDOWN_TIMEOUT_EXIT_CODE);
+				}
+			});
+
+		match watchdog {
+			| Ok(_) => {
+				self.completed.replace(completed_tx);
+			}
+			| Err(err) => {
+				logger_signal.send_warning(format!(
+					"Le délai d'arrêt de l'application ne pourra pas être respecté. Raison « {err} »"
+				));
+			}
+		}
+	}
+
+	/// Déclenche le jeton d'arrêt, exécute les fonctions de la phase
+	/// [HookPhase::Shutdown] dans l'ordre inverse de leur enregistrement,
+	/// puis arrête les modules.
+	pub(crate) fn terminate(
+		&mut self,
+		hooks: &mut KernelHooks,
+		modules: &mut KernelModules,
+		context: &HookContext,
+	) -> Result<(), KernelError>
+	{
+		self.token.trigger();
+		let result = hooks.execute(HookPhase::Shutdown, context);
+		let result = result.and(Self::stop_modules(hooks, modules, context));
+		self.complete();
+		result
+	}
+
+	/// Voir [KernelShutdown::terminate()]. Les fonctions asynchrones sont
+	/// également exécutées.
+	pub(crate) async fn terminate_async(
+		&mut self,
+		hooks: &mut KernelHooks,
+		modules: &mut KernelModules,
+		context: &HookContext<'_>,
+	) -> Result<(), KernelError>
+	{
+		self.token.trigger();
+		let result = hooks.execute_async(HookPhase::Shutdown, context).await;
+		let result = result.and(Self::stop_modules_async(hooks, modules, context).await);
+		self.complete();
+		result
+	}
+
+	/// Arrête les modules et signale leurs erreurs. Retourne la première
+	/// erreur.
+	fn stop_modules(hooks: &mut KernelHooks, modules: &mut KernelModules, context: &HookContext)
+		-> Result<(), KernelError>
+	{
+		let mut errors = modules.stop(context).into_iter();
+		let first = errors.next();
+
+		for err in first.iter().chain(errors.as_slice()) {
+			hooks.report(err, context);
+		}
+
+		first.map_or(Ok(()), Err)
+	}
+
+	/// Voir [KernelShutdown::stop_modules()]. Les fonctions asynchrones de la
+	/// phase [HookPhase::Error] sont également exécutées.
+	async fn stop_modules_async(
+		hooks: &mut KernelHooks,
+		modules: &mut KernelModules,
+		context: &HookContext<'_>,
+	) -> Result<(), KernelError>
+	{
+		let mut errors = modules.stop(context).into_iter();
+		let first = errors.next();
+
+		for err in first.iter().chain(errors.as_slice()) {
+			hooks.report_async(err, context).await;
+		}
+
+		first.map_or(Ok(()), Err)
+	}
+
+	/// Notifie la fin de l'arrêt au chien de garde.
+	fn complete(&mut self)
+	{
+		if let Some(completed) = self.completed.take() {
+			_ = completed.send(());
+		}
+	}
+}
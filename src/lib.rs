@@ -21,9 +21,46 @@ pub mod process;
 
 pub use lexa_fs::Extension as LoaderExtension;
 
+pub use self::kernel::command::{KernelCommand, KernelCommandError, CONFIG_CLI_FLAG, DRY_RUN_CLI_FLAG};
+#[cfg(feature = "schema")]
+pub use self::kernel::config::env_fields_from_schema;
+pub use self::kernel::config::{
+	read_secret_file,
+	render_env_example,
+	ConfigDump,
+	ConfigDumpFormat,
+	ConfigExplanation,
+	ConfigOrigin,
+	ConfigProvenance,
+	ConfigSource,
+	ConfigViolation,
+	ConfigViolations,
+	EmbeddedConfigSource,
+	EnvField,
+	EnvReport,
+	EnvViolation,
+	EnvViolationKind,
+	InterpolationError,
+	KeyPerFileConfigSource,
+	KeyValueFileConfigSource,
+	MemoryConfigSource,
+	SchemaMismatch,
+	SecretFilePermissions,
+	StdinConfigSource,
+	ValidateConfig,
+	WatchedConfig,
+	CONFIG_INCLUDE_DIRECTIVE,
+	ENV_EXAMPLE_SUFFIX,
+	REDACTED_VALUE,
+	SECRET_FILE_SUFFIX,
+};
 pub use self::kernel::error::KernelError;
 pub use self::kernel::extension::*;
+pub use self::kernel::hooks::{HookContext, HookError, HookFuture, HookPhase, HookPolicy};
 pub use self::kernel::interface::*;
+pub use self::kernel::module::KernelModule;
+pub use self::kernel::secret::Secret;
+pub use self::kernel::service::ServiceContainer;
 pub use self::kernel::Kernel;
 
 pub mod settings
@@ -32,6 +32,7 @@ pub type LoggerBuilderError = &'static str;
 #[derive(Clone)]
 #[derive(Default)]
 #[derive(serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct LoggerSettings
 {
 	/// Pré-réglage d'un logger.
@@ -61,6 +62,7 @@ pub struct LoggerSettings
 #[derive(Default)]
 #[derive(Copy, Clone)]
 #[derive(serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum LoggerSettingsPreset
 {
 	#[default]
@@ -71,6 +73,7 @@ pub enum LoggerSettingsPreset
 #[derive(Debug)]
 #[derive(Copy, Clone)]
 #[derive(serde::Deserialize, serde::Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum LoggerSettingsLevel
 {
 	DEBUG,
@@ -134,6 +137,20 @@ impl LoggerSettings
 // Implémentation // -> Interface
 // -------------- //
 
+impl crate::ValidateConfig for LoggerSettings
+{
+	fn validate(&self, violations: &mut crate::ConfigViolations)
+	{
+		for (index, target) in self.target_filters.iter().enumerate() {
+			violations.check(
+				format!("target_filters.{index}"),
+				!target.trim().is_empty(),
+				"le filtre ne peut pas être vide",
+			);
+		}
+	}
+}
+
 impl From<lexa_logger::Settings> for LoggerSettings
 {
 	fn from(ll_settings: lexa_logger::Settings) -> Self
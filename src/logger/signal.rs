@@ -41,6 +41,7 @@ pub(crate) struct LoggerReader
 	pub(crate) warning: mpsc::Receiver<String>,
 }
 
+#[derive(Clone)]
 pub(crate) struct LoggerWriter
 {
 	/// Gestion des erreurs.
@@ -79,6 +80,12 @@ impl LoggerSignal
 		Self(lrx, ltx)
 	}
 
+	/// Émetteur du signal, utilisable depuis un autre thread.
+	pub(crate) fn writer(&self) -> LoggerWriter
+	{
+		self.1.clone()
+	}
+
 	/// Stop le signal.
 	pub(crate) fn terminated(&self)
 	{
@@ -88,6 +95,13 @@ impl LoggerSignal
 
 	/// Émet une erreur critique (cela quitte le programme après l'émission).
 	pub fn send_critical(&self, msg: impl ToString) -> !
+	{
+		self.send_critical_with_exit_code(msg, 1)
+	}
+
+	/// Voir [LoggerSignal::send_critical()]. Le programme est quitté avec un
+	/// code de sortie donné.
+	pub fn send_critical_with_exit_code(&self, msg: impl ToString, exit_code: i32) -> !
 	{
 		self.1
 			.error
@@ -95,7 +109,7 @@ impl LoggerSignal
 			.expect("Impossible de logger l'erreur");
 		std::thread::sleep(std::time::Duration::from_millis(16));
 		self.terminated();
-		std::process::exit(1)
+		std::process::exit(exit_code)
 	}
 
 	/// Émet une erreur.
@@ -119,6 +133,16 @@ impl LoggerSignal
 	}
 }
 
+impl LoggerWriter
+{
+	/// Émet une erreur. Contrairement à [LoggerSignal::send_error()], cette
+	/// fonction ne panique pas si le signal a été stoppé.
+	pub(crate) fn send_error(&self, msg: impl ToString)
+	{
+		_ = self.error.send(msg.to_string());
+	}
+}
+
 impl LoggerReader
 {
 	/// Lis les messages d'avertissements, d'erreurs, etc... en tâche de fond.
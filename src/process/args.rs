@@ -0,0 +1,28 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::ffi::OsString;
+
+use super::ProcessMode;
+use crate::KernelCommand;
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Arguments de la CLI du processus (nom du programme compris), sans les
+/// arguments réservés au kernel (voir [ProcessMode::strip_args()] et
+/// [KernelCommand::strip_args()]). Ce sont
+/// ces arguments que la CLI de l'application DOIT analyser (voir
+/// [ApplicationCLIInterface](crate::ApplicationCLIInterface)).
+pub fn application_args() -> Vec<OsString>
+{
+	KernelCommand::strip_args(ProcessMode::strip_args(std::env::args_os()))
+}
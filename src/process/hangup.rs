@@ -0,0 +1,63 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Enregistrement d'un drapeau levé à la réception du signal SIGHUP.
+/// L'enregistrement est annulé lorsque cette structure est libérée.
+pub struct HangupRegistration
+{
+	#[cfg(unix)]
+	id: Option<signal_hook::SigId>,
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl Drop for HangupRegistration
+{
+	fn drop(&mut self)
+	{
+		#[cfg(unix)]
+		if let Some(id) = self.id.take() {
+			signal_hook::low_level::unregister(id);
+		}
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Lève le drapeau donné à chaque réception du signal SIGHUP, généralement
+/// utilisé pour demander le rechargement de la configuration.
+///
+/// NOTE: sur les plateformes non-Unix, aucun gestionnaire n'est installé ; le
+/// drapeau n'est jamais levé.
+pub fn register_hangup(flag: &Arc<AtomicBool>) -> std::io::Result<HangupRegistration>
+{
+	#[cfg(unix)]
+	{
+		let id = signal_hook::flag::register(signal_hook::consts::SIGHUP, Arc::clone(flag))?;
+		Ok(HangupRegistration { id: Some(id) })
+	}
+
+	#[cfg(not(unix))]
+	{
+		let _ = flag;
+		Ok(HangupRegistration {})
+	}
+}
@@ -8,6 +8,18 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+mod args;
+mod hangup;
 mod mode;
+mod shutdown;
 
-pub use self::mode::ProcessMode;
+pub use self::args::application_args;
+pub use self::hangup::{register_hangup, HangupRegistration};
+pub use self::mode::{ProcessMode, ProcessModeError, PROCESS_MODE_CLI_FLAG, PROCESS_MODE_ENV_VARIABLE};
+pub use self::shutdown::{
+	install_signal_handlers,
+	ShutdownFuture,
+	ShutdownToken,
+	SHUTDOWN_DEFAULT_TIMEOUT,
+	SHUTDOWN_TIMEOUT_EXIT_CODE,
+};
@@ -8,6 +8,22 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+use std::ffi::OsString;
+use std::{fmt, str};
+
+// -------- //
+// Constant //
+// -------- //
+
+/// Nom de la variable d'environnement qui définit le mode d'exécution.
+pub const PROCESS_MODE_ENV_VARIABLE: &str = "PROCESS_ENV";
+
+/// Nom de l'argument de la CLI qui définit le mode d'exécution.
+///
+/// Les formes `--process-mode <MODE>` et `--process-mode=<MODE>` sont
+/// acceptées.
+pub const PROCESS_MODE_CLI_FLAG: &str = "--process-mode";
+
 // ----------- //
 // Énumération //
 // ----------- //
@@ -22,9 +38,15 @@
 ///      messages/comportement du programme spécifique à certains modes ;
 ///   4. ...
 ///
-/// La valeur est définie grâce à la variable d'environnement `PROCESS_ENV`. La
-/// valeur par défaut de cette énumération est
-/// [LOCAL](ProcessMode::LOCAL).
+/// La valeur est résolue dans l'ordre suivant (voir
+/// [ProcessMode::resolve()]) :
+///
+///   1. l'argument de la CLI `--process-mode` ;
+///   2. la variable d'environnement `PROCESS_ENV` ;
+///   3. la configuration Rust (voir [ProcessMode::from_rustcfg()]).
+///
+/// Le kernel PEUT également forcer un mode via
+/// `Kernel#define_process_mode`.
 #[derive(Debug)]
 #[derive(Default)]
 #[derive(Copy, Clone)]
@@ -42,6 +64,22 @@ pub enum ProcessMode
 	TEST,
 }
 
+#[derive(Debug)]
+#[derive(PartialEq, Eq)]
+#[derive(thiserror::Error)]
+pub enum ProcessModeError
+{
+	/// La valeur ne correspond à aucun mode d'exécution.
+	#[error(
+		"le mode d'exécution « {0} » n'est pas valide, les valeurs acceptées \
+		 sont: local, dev, development, prod, production, test, testing."
+	)]
+	Unknown(String),
+	/// L'argument de la CLI a été donné sans valeur.
+	#[error("l'argument de la CLI « {} » attend une valeur.", PROCESS_MODE_CLI_FLAG)]
+	MissingCliValue,
+}
+
 // -------------- //
 // Implémentation //
 // -------------- //
@@ -61,4 +99,232 @@ impl ProcessMode
 			Self::PRODUCTION
 		}
 	}
+
+	/// Construit la structure à partir de la variable d'environnement
+	/// `PROCESS_ENV`, si elle est définie.
+	pub fn from_env() -> Option<Result<Self, ProcessModeError>>
+	{
+		let mode = std::env::var(PROCESS_MODE_ENV_VARIABLE).ok()?;
+		Some(mode.parse())
+	}
+
+	/// Construit la structure à partir de l'argument de la CLI
+	/// `--process-mode`, s'il est présent. Les arguments qui ne sont pas
+	/// valides en UTF-8 sont convertis avec perte.
+	pub fn from_cli_args() -> Option<Result<Self, ProcessModeError>>
+	{
+		Self::from_args(
+			std::env::args_os()
+				.skip(1)
+				.map(|arg| arg.to_string_lossy().into_owned()),
+		)
+	}
+
+	/// Construit la structure à partir d'une liste d'arguments. Les
+	/// arguments qui suivent `--` ne sont pas analysés.
+	pub fn from_args(args: impl IntoIterator<Item = impl AsRef<str>>) -> Option<Result<Self, ProcessModeError>>
+	{
+		let mut args = args.into_iter();
+
+		while let Some(arg) = args.next() {
+			let arg = arg.as_ref();
+
+			if arg == "--" {
+				break;
+			}
+
+			if arg == PROCESS_MODE_CLI_FLAG {
+				let Some(mode) = args.next() else {
+					return Some(Err(ProcessModeError::MissingCliValue));
+				};
+				return Some(mode.as_ref().parse());
+			}
+
+			if let Some(mode) = arg
+				.strip_prefix(PROCESS_MODE_CLI_FLAG)
+				.and_then(|rest| rest.strip_prefix('='))
+			{
+				return Some(mode.parse());
+			}
+		}
+
+		None
+	}
+
+	/// Retire l'argument de la CLI `--process-mode` (et sa valeur) d'une liste
+	/// d'arguments, afin que ces arguments puissent être analysés par la CLI
+	/// de l'application (voir
+	/// [ApplicationCLIInterface](crate::ApplicationCLIInterface)). Les
+	/// arguments qui suivent `--` sont conservés tels quels.
+	pub fn strip_args(args: impl IntoIterator<Item = impl Into<OsString>>) -> Vec<OsString>
+	{
+		let mut args = args.into_iter().map(Into::into);
+		let mut stripped_args = Vec::new();
+
+		while let Some(arg) = args.next() {
+			if arg == "--" {
+				stripped_args.push(arg);
+				stripped_args.extend(args);
+				break;
+			}
+
+			if arg == PROCESS_MODE_CLI_FLAG {
+				args.next();
+				continue;
+			}
+
+			let is_process_mode_flag = arg.to_str().is_some_and(|arg| {
+				arg.strip_prefix(PROCESS_MODE_CLI_FLAG)
+					.is_some_and(|rest| rest.starts_with('='))
+			});
+			if !is_process_mode_flag {
+				stripped_args.push(arg);
+			}
+		}
+
+		stripped_args
+	}
+
+	/// Résout le mode d'exécution: l'argument de la CLI, puis la variable
+	/// d'environnement, puis la configuration Rust.
+	pub fn resolve() -> Result<Self, ProcessModeError>
+	{
+		Self::from_cli_args()
+			.or_else(Self::from_env)
+			.unwrap_or_else(|| Ok(Self::from_rustcfg()))
+	}
+
+	/// Nom court du mode d'exécution, utilisé notamment pour les
+	/// sous-répertoires de configuration (`dev/`, `prod/`, `test/`).
+	pub fn short_name(&self) -> &'static str
+	{
+		match self {
+			| Self::LOCAL => "local",
+			| Self::DEVELOPMENT => "dev",
+			| Self::PRODUCTION => "prod",
+			| Self::TEST => "test",
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl str::FromStr for ProcessMode
+{
+	type Err = ProcessModeError;
+
+	fn from_str(mode: &str) -> Result<Self, Self::Err>
+	{
+		Ok(match mode.trim().to_ascii_lowercase().as_str() {
+			| "local" => Self::LOCAL,
+			| "dev" | "development" => Self::DEVELOPMENT,
+			| "prod" | "production" => Self::PRODUCTION,
+			| "test" | "testing" => Self::TEST,
+			| _ => return Err(ProcessModeError::Unknown(mode.to_owned())),
+		})
+	}
+}
+
+impl fmt::Display for ProcessMode
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		let mode = match self {
+			| Self::LOCAL => "local",
+			| Self::DEVELOPMENT => "development",
+			| Self::PRODUCTION => "production",
+			| Self::TEST => "test",
+		};
+		write!(f, "{mode}")
+	}
+}
+
+impl serde::Serialize for ProcessMode
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		serializer.collect_str(self)
+	}
+}
+
+impl<'de> serde::Deserialize<'de> for ProcessMode
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let mode = String::deserialize(deserializer)?;
+		mode.parse().map_err(serde::de::Error::custom)
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	#[test]
+	fn test_from_str()
+	{
+		assert_eq!("local".parse(), Ok(ProcessMode::LOCAL));
+		assert_eq!("dev".parse(), Ok(ProcessMode::DEVELOPMENT));
+		assert_eq!(" Development ".parse(), Ok(ProcessMode::DEVELOPMENT));
+		assert_eq!("prod".parse(), Ok(ProcessMode::PRODUCTION));
+		assert_eq!("PRODUCTION".parse(), Ok(ProcessMode::PRODUCTION));
+		assert_eq!("test".parse(), Ok(ProcessMode::TEST));
+		assert_eq!("testing".parse(), Ok(ProcessMode::TEST));
+		assert_eq!(
+			"staging".parse::<ProcessMode>(),
+			Err(ProcessModeError::Unknown("staging".to_owned()))
+		);
+	}
+
+	#[test]
+	fn test_from_args()
+	{
+		assert_eq!(ProcessMode::from_args(["x", "--process-mode", "dev"]), Some(Ok(ProcessMode::DEVELOPMENT)));
+		assert_eq!(ProcessMode::from_args(["--process-mode=prod", "x"]), Some(Ok(ProcessMode::PRODUCTION)));
+		assert_eq!(ProcessMode::from_args(["x", "y"]), None);
+		assert_eq!(ProcessMode::from_args(["--", "--process-mode", "dev"]), None);
+		assert_eq!(
+			ProcessMode::from_args(["--process-mode"]),
+			Some(Err(ProcessModeError::MissingCliValue))
+		);
+		assert_eq!(
+			ProcessMode::from_args(["--process-mode=staging"]),
+			Some(Err(ProcessModeError::Unknown("staging".to_owned())))
+		);
+		assert_eq!(ProcessMode::from_args(["--process-modes=dev"]), None);
+	}
+
+	#[test]
+	fn test_strip_args()
+	{
+		assert_eq!(
+			ProcessMode::strip_args(["app", "--process-mode", "dev", "x"]),
+			["app", "x"]
+		);
+		assert_eq!(ProcessMode::strip_args(["app", "x", "--process-mode=dev"]), ["app", "x"]);
+		assert_eq!(
+			ProcessMode::strip_args(["app", "--process-modes=dev"]),
+			["app", "--process-modes=dev"]
+		);
+		assert_eq!(
+			ProcessMode::strip_args(["app", "--", "--process-mode", "dev"]),
+			["app", "--", "--process-mode", "dev"]
+		);
+	}
+
+	#[test]
+	fn test_error_lists_every_alias()
+	{
+		let message = ProcessModeError::Unknown("x".to_owned()).to_string();
+		for alias in ["local", "dev", "development", "prod", "production", "test", "testing"] {
+			assert!(message.contains(alias), "{alias}");
+		}
+	}
 }
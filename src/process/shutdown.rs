@@ -0,0 +1,208 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+// -------- //
+// Constant //
+// -------- //
+
+/// Code de sortie du processus lorsque l'arrêt de l'application dépasse le
+/// délai imparti.
+pub const SHUTDOWN_TIMEOUT_EXIT_CODE: i32 = 124;
+
+/// Délai par défaut accordé à l'arrêt de l'application.
+pub const SHUTDOWN_DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Jeton d'arrêt de l'application.
+///
+/// Ce jeton est partagé (clonable) entre le kernel et l'application adapter.
+/// Il est déclenché lorsque le processus reçoit un signal d'arrêt (SIGINT,
+/// SIGTERM) ou lorsque le kernel termine l'application. Les tâches de fond
+/// DOIVENT l'écouter afin de s'arrêter proprement.
+#[derive(Clone)]
+#[derive(Default)]
+pub struct ShutdownToken
+{
+	inner: Arc<ShutdownTokenInner>,
+}
+
+#[derive(Default)]
+struct ShutdownTokenInner
+{
+	/// Est-ce que l'arrêt a été demandé?
+	triggered: AtomicBool,
+	/// Verrou utilisé pour l'attente bloquante.
+	lock: Mutex<()>,
+	/// Réveille les attentes bloquantes.
+	condvar: Condvar,
+	/// Réveille les attentes asynchrones.
+	wakers: Mutex<Vec<Waker>>,
+}
+
+/// Future qui se termine lorsque l'arrêt de l'application est demandé.
+pub struct ShutdownFuture
+{
+	token: ShutdownToken,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ShutdownToken
+{
+	/// Crée un nouveau jeton d'arrêt.
+	pub fn new() -> Self
+	{
+		Self::default()
+	}
+
+	/// Est-ce que l'arrêt de l'application a été demandé?
+	pub fn is_triggered(&self) -> bool
+	{
+		self.inner.triggered.load(Ordering::SeqCst)
+	}
+
+	/// Demande l'arrêt de l'application. Retourne `false` si l'arrêt avait
+	/// déjà été demandé.
+	pub fn trigger(&self) -> bool
+	{
+		if self.inner.triggered.swap(true, Ordering::SeqCst) {
+			return false;
+		}
+
+		{
+			let _guard = self.inner.lock.lock().unwrap_or_else(|err| err.into_inner());
+			self.inner.condvar.notify_all();
+		}
+
+		let wakers = std::mem::take(&mut *self.inner.wakers.lock().unwrap_or_else(|err| err.into_inner()));
+		wakers.into_iter().for_each(Waker::wake);
+
+		true
+	}
+
+	/// Bloque le thread courant jusqu'à ce que l'arrêt soit demandé.
+	pub fn wait(&self)
+	{
+		let mut guard = self.inner.lock.lock().unwrap_or_else(|err| err.into_inner());
+		while !self.is_triggered() {
+			guard = self.inner.condvar.wait(guard).unwrap_or_else(|err| err.into_inner());
+		}
+	}
+
+	/// Bloque le thread courant jusqu'à ce que l'arrêt soit demandé ou que
+	/// le délai soit écoulé. Retourne `true` si l'arrêt a été demandé.
+	pub fn wait_timeout(&self, timeout: Duration) -> bool
+	{
+		let guard = self.inner.lock.lock().unwrap_or_else(|err| err.into_inner());
+		let _ = self
+			.inner
+			.condvar
+			.wait_timeout_while(guard, timeout, |_| !self.is_triggered())
+			.unwrap_or_else(|err| err.into_inner());
+		self.is_triggered()
+	}
+
+	/// Future qui se termine lorsque l'arrêt de l'application est demandé.
+	/// Cette future ne dépend d'aucun runtime asynchrone.
+	pub fn cancelled(&self) -> ShutdownFuture
+	{
+		ShutdownFuture { token: self.clone() }
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl std::fmt::Debug for ShutdownToken
+{
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+	{
+		f.debug_struct("ShutdownToken")
+			.field("triggered", &self.is_triggered())
+			.finish()
+	}
+}
+
+impl Future for ShutdownFuture
+{
+	type Output = ();
+
+	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>
+	{
+		if self.token.is_triggered() {
+			return Poll::Ready(());
+		}
+
+		let mut wakers = self.token.inner.wakers.lock().unwrap_or_else(|err| err.into_inner());
+
+		// NOTE: l'arrêt a pu être demandé entre la première vérification et
+		//       l'acquisition du verrou.
+		if self.token.is_triggered() {
+			return Poll::Ready(());
+		}
+
+		if !wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+			wakers.push(cx.waker().clone());
+		}
+
+		Poll::Pending
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Installe les gestionnaires des signaux d'arrêt (SIGINT, SIGTERM) du
+/// processus. À la réception d'un de ces signaux, le jeton est déclenché.
+///
+/// NOTE: sur les plateformes non-Unix, aucun gestionnaire n'est installé ; le
+/// jeton PEUT toujours être déclenché manuellement.
+pub fn install_signal_handlers(token: &ShutdownToken) -> std::io::Result<()>
+{
+	#[cfg(unix)]
+	{
+		use signal_hook::consts::{SIGINT, SIGTERM};
+		use signal_hook::iterator::Signals;
+
+		let mut signals = Signals::new([SIGINT, SIGTERM])?;
+		let token = token.clone();
+
+		std::thread::Builder::new()
+			.name(String::from("lexa-kernel-signals"))
+			.spawn(move || {
+				for signal in signals.forever() {
+					log::debug!("Signal d'arrêt « {signal} » reçu.");
+					if !token.trigger() {
+						// NOTE: un deuxième signal force l'arrêt immédiat.
+						std::process::exit(128 + signal);
+					}
+				}
+			})?;
+	}
+
+	#[cfg(not(unix))]
+	let _ = token;
+
+	Ok(())
+}